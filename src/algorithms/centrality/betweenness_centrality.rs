@@ -0,0 +1,282 @@
+use crate::algorithms::{Traversal, BFS};
+use crate::traits::Storage;
+use crate::utils::MinPriority;
+use crate::V;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Shortest paths from a given source vertex.
+struct ShortestPaths<'a, V> {
+    /// Reachable vertices, in non-decreasing distance order.
+    order: Vec<&'a V>,
+    /// Predecessors of each reachable vertex in its shortest paths.
+    predecessors: HashMap<&'a V, Vec<&'a V>>,
+    /// Number of shortest paths to each reachable vertex.
+    sigma: HashMap<&'a V, f64>,
+}
+
+/// Compute the shortest paths from a given source vertex, with unit weights.
+fn unweighted<'a, T>(g: &'a T, s: &'a T::Vertex) -> ShortestPaths<'a, T::Vertex>
+where
+    T: Storage,
+{
+    // Visit the vertices reachable from the source vertex, in non-decreasing distance order.
+    let mut search = BFS::new(g, Some(s), T::adjacents_iter, Traversal::Tree);
+    let order: Vec<_> = search.by_ref().collect();
+    let distance = search.distance;
+    // Initialize the predecessors and the number of shortest paths.
+    let mut predecessors: HashMap<_, Vec<_>> = HashMap::new();
+    let mut sigma = HashMap::from([(s, 1.)]);
+    // Since any predecessor is visited first, the number of shortest paths is final once visited.
+    for &x in &order {
+        let k = sigma[x];
+        // Propagate the number of shortest paths to the next layer.
+        for y in g.adjacents_iter(x).filter(|y| distance[y] == distance[x] + 1) {
+            *sigma.entry(y).or_insert(0.) += k;
+            predecessors.entry(y).or_default().push(x);
+        }
+    }
+
+    ShortestPaths {
+        order,
+        predecessors,
+        sigma,
+    }
+}
+
+/// Compute the shortest paths from a given source vertex, with a given weight function.
+fn weighted<'a, T, F>(g: &'a T, s: &'a T::Vertex, w: &F) -> ShortestPaths<'a, T::Vertex>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    // Initialize the visit order.
+    let mut order = Vec::new();
+    // Initialize the distances, the predecessors and the number of shortest paths.
+    let mut distance = HashMap::from([(s, 0.)]);
+    let mut predecessors: HashMap<_, Vec<_>> = HashMap::new();
+    let mut sigma = HashMap::from([(s, 1.)]);
+    // Initialize the already visited set and the to-be-visited priority queue.
+    let mut visited = HashSet::new();
+    let mut queue = BinaryHeap::from([MinPriority(0., s)]);
+    // While there are still vertices to be visited.
+    while let Some(MinPriority(d, x)) = queue.pop() {
+        // If the vertex was already visited, skip its outdated entry.
+        if !visited.insert(x) {
+            continue;
+        }
+        order.push(x);
+        // Since weights are positive, the number of shortest paths is final once visited.
+        let k = sigma[x];
+        for y in g.adjacents_iter(x).filter(|y| !visited.contains(y)) {
+            // Compute the distance through the current vertex.
+            let e = d + w(x, y);
+            match distance.get(y) {
+                // If it is longer, then skip it.
+                Some(&f) if e > f => {}
+                // If it is as short, then add the shortest paths through the current vertex.
+                Some(&f) if e == f => {
+                    *sigma.get_mut(y).unwrap() += k;
+                    predecessors.get_mut(y).unwrap().push(x);
+                }
+                // Otherwise, replace the shortest paths.
+                _ => {
+                    distance.insert(y, e);
+                    sigma.insert(y, k);
+                    predecessors.insert(y, vec![x]);
+                    queue.push(MinPriority(e, y));
+                }
+            }
+        }
+    }
+
+    ShortestPaths {
+        order,
+        predecessors,
+        sigma,
+    }
+}
+
+/// Accumulate the pair dependencies on vertices and edges, as in Brandes[^1].
+///
+/// [^1]: [Brandes, U. (2001). A faster algorithm for betweenness centrality. Journal of Mathematical Sociology, 25(2), 163-177.](https://scholar.google.com/scholar?q=A+faster+algorithm+for+betweenness+centrality)
+///
+fn brandes<'a, T, S>(
+    g: &'a T,
+    f: S,
+) -> (
+    HashMap<&'a T::Vertex, f64>,
+    HashMap<(&'a T::Vertex, &'a T::Vertex), f64>,
+)
+where
+    T: Storage,
+    S: Fn(&'a T::Vertex) -> ShortestPaths<'a, T::Vertex>,
+{
+    // Get the graph order.
+    let n = g.order() as f64;
+    // Initialize the vertices and edges centralities.
+    let mut vertices: HashMap<_, _> = V!(g).map(|x| (x, 0.)).collect();
+    let mut edges: HashMap<_, _> = g.edges_iter().map(|e| (e, 0.)).collect();
+    // For each source vertex.
+    for s in V!(g) {
+        // Compute the shortest paths from the source vertex.
+        let p = f(s);
+        // Initialize the dependencies of the source vertex.
+        let mut delta: HashMap<_, _> = p.order.iter().map(|&x| (x, 0.)).collect();
+        // Accumulate the dependencies in non-increasing distance order.
+        for &y in p.order.iter().rev() {
+            for &x in p.predecessors.get(y).into_iter().flatten() {
+                // Compute the dependency on the edge.
+                let c = p.sigma[x] / p.sigma[y] * (1. + delta[y]);
+                *edges.get_mut(&(x, y)).unwrap() += c;
+                *delta.get_mut(x).unwrap() += c;
+            }
+            // The source vertex does not depend on itself.
+            if y != s {
+                *vertices.get_mut(y).unwrap() += delta[y];
+            }
+        }
+    }
+    // Normalize by the number of ordered pairs of vertices, excluding the vertex itself ...
+    if n > 2. {
+        vertices.values_mut().for_each(|c| *c /= (n - 1.) * (n - 2.));
+    }
+    // ... or including the endpoints of the edge.
+    if n > 1. {
+        edges.values_mut().for_each(|c| *c /= n * (n - 1.));
+    }
+
+    (vertices, edges)
+}
+
+/// Betweenness centrality of a graph.
+///
+/// The betweenness centrality $C_B$ of a vertex $X$ in a graph $G$ is defined as:
+///
+/// $$ C_B(X) = \frac{1}{(|\textbf{V}| - 1)(|\textbf{V}| - 2)} \sum_{S \neq X \neq T} \frac{\sigma(S, T | X)}{\sigma(S, T)} $$
+///
+/// with $\sigma(S, T)$ the number of shortest paths from $S$ to $T$ and $\sigma(S, T | X)$ the number of
+/// those passing through $X$. The sum ranges over ordered pairs, hence the normalization is the same for
+/// both directed and undirected graphs. The shortest paths are counted with unit weights, using BFS,
+/// as in Brandes[^1].
+///
+/// [^1]: [Brandes, U. (2001). A faster algorithm for betweenness centrality. Journal of Mathematical Sociology, 25(2), 163-177.](https://scholar.google.com/scholar?q=A+faster+algorithm+for+betweenness+centrality)
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a path graph.
+/// let g = Graph::from_edges([(0, 1), (1, 2)]);
+///
+/// // Compute the betweenness centrality.
+/// let c = betweenness_centrality(&g);
+///
+/// // Every shortest path between the endpoints passes through the middle vertex.
+/// assert_eq!(c[&1], 1.);
+/// assert_eq!(c[&0], 0.);
+/// ```
+///
+pub fn betweenness_centrality<T>(g: &T) -> HashMap<&T::Vertex, f64>
+where
+    T: Storage,
+{
+    brandes(g, |s| unweighted(g, s)).0
+}
+
+/// Weighted betweenness centrality of a graph.
+///
+/// Defined as its [unweighted variant][`betweenness_centrality`], where the length of the paths
+/// is given by the weight function, which is assumed to be positive.
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a triangle graph.
+/// let g = Graph::from_edges([(0, 1), (1, 2), (2, 0)]);
+///
+/// // Make the edge between `0` and `2` longer than the path through `1`.
+/// let w = |x: &i32, y: &i32| if x + y == 2 { 3. } else { 1. };
+///
+/// // Compute the weighted betweenness centrality.
+/// let c = weighted_betweenness_centrality(&g, w);
+///
+/// // Every shortest path between `0` and `2` passes through `1`.
+/// assert_eq!(c[&1], 1.);
+/// assert_eq!(c[&0], 0.);
+/// ```
+///
+pub fn weighted_betweenness_centrality<'a, T, F>(g: &'a T, w: F) -> HashMap<&'a T::Vertex, f64>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    brandes(g, |s| weighted(g, s, &w)).0
+}
+
+/// Edge betweenness centrality of a graph.
+///
+/// The edge betweenness centrality $C_B$ of an edge $(X, Y)$ in a graph $G$ is defined as:
+///
+/// $$ C_B(X, Y) = \frac{1}{|\textbf{V}| (|\textbf{V}| - 1)} \sum_{S \neq T} \frac{\sigma(S, T | (X, Y))}{\sigma(S, T)} $$
+///
+/// with $\sigma(S, T)$ the number of shortest paths from $S$ to $T$ and $\sigma(S, T | (X, Y))$ the number of
+/// those traversing $(X, Y)$ from $X$ to $Y$. For undirected graphs, each edge is returned in both directions,
+/// both with the same centrality, since each shortest path traversing it in one direction is the reverse of
+/// one traversing it in the other direction.
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a path graph.
+/// let g = Graph::from_edges([(0, 1), (1, 2)]);
+///
+/// // Compute the edge betweenness centrality.
+/// let c = edge_betweenness_centrality(&g);
+///
+/// // The edge from `0` to `1` is traversed by the paths from `0` to `1` and `2`.
+/// assert_eq!(c[&(&0, &1)], 2. / 6.);
+/// assert_eq!(c[&(&1, &0)], 2. / 6.);
+/// ```
+///
+pub fn edge_betweenness_centrality<T>(g: &T) -> HashMap<(&T::Vertex, &T::Vertex), f64>
+where
+    T: Storage,
+{
+    brandes(g, |s| unweighted(g, s)).1
+}
+
+/// Weighted edge betweenness centrality of a graph.
+///
+/// Defined as its [unweighted variant][`edge_betweenness_centrality`], where the length of the paths
+/// is given by the weight function, which is assumed to be positive.
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a triangle graph.
+/// let g = Graph::from_edges([(0, 1), (1, 2), (2, 0)]);
+///
+/// // Make the edge between `0` and `2` longer than the path through `1`.
+/// let w = |x: &i32, y: &i32| if x + y == 2 { 3. } else { 1. };
+///
+/// // Compute the weighted edge betweenness centrality.
+/// let c = weighted_edge_betweenness_centrality(&g, w);
+///
+/// // The longer edge is not traversed by any shortest path.
+/// assert_eq!(c[&(&0, &2)], 0.);
+/// ```
+///
+pub fn weighted_edge_betweenness_centrality<'a, T, F>(g: &'a T, w: F) -> HashMap<(&'a T::Vertex, &'a T::Vertex), f64>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    brandes(g, |s| weighted(g, s, &w)).1
+}
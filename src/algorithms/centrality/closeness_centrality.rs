@@ -0,0 +1,125 @@
+use crate::algorithms::{Traversal, BFS};
+use crate::traits::Storage;
+use crate::V;
+use std::collections::HashMap;
+
+/// Compute the sorted distances to each vertex, from each vertex that reaches it.
+///
+/// The distances are computed by a BFS from each source vertex, following the edges
+/// in their direction, i.e. as returned by [`Storage::adjacents_iter`].
+///
+fn distances_to<T>(g: &T) -> HashMap<&T::Vertex, Vec<usize>>
+where
+    T: Storage,
+{
+    // Initialize the distances of each vertex.
+    let mut distances: HashMap<_, Vec<_>> = V!(g).map(|x| (x, vec![])).collect();
+    // For each source vertex.
+    for x in V!(g) {
+        // Visit the vertices reachable from the source vertex.
+        let mut search = BFS::new(g, Some(x), T::adjacents_iter, Traversal::Tree);
+        search.by_ref().for_each(drop);
+        // Collect the distances from the source vertex, skipping the source vertex itself.
+        for (y, d) in search.distance.into_iter().filter(|&(y, _)| y != x) {
+            distances.get_mut(y).unwrap().push(d);
+        }
+    }
+    // Sort the distances, so that any later sum is deterministic.
+    for d in distances.values_mut() {
+        d.sort_unstable();
+    }
+
+    distances
+}
+
+/// Closeness centrality of a graph.
+///
+/// The closeness centrality $C_C$ of a vertex $X$ in a graph $G$ is defined as:
+///
+/// $$ C_C(X) = \frac{r - 1}{|\textbf{V}| - 1} \frac{r - 1}{\sum_{Y \neq X} d(Y, X)} $$
+///
+/// with $d(Y, X)$ the distance from $Y$ to $X$ and $r$ the number of vertices $Y$ that reach $X$,
+/// including $X$ itself, following the Wasserman and Faust[^1] extension to disconnected graphs.
+/// For directed graphs, the distances are computed towards the vertex, i.e. using in-coming edges.
+/// Vertices that are not reached by any other vertex have centrality zero.
+///
+/// [^1]: [Wasserman, S., & Faust, K. (1994). Social network analysis: Methods and applications. Cambridge University Press.](https://scholar.google.com/scholar?q=Social+network+analysis%3A+Methods+and+applications)
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a path graph.
+/// let g = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+///
+/// // Compute the closeness centrality.
+/// let c = closeness_centrality(&g);
+///
+/// // The center is the closest vertex to the others ...
+/// assert_eq!(c[&2], 4. / (1. + 2. + 1. + 2.));
+/// // ... while the endpoints are the farthest ones.
+/// assert_eq!(c[&0], 4. / (1. + 2. + 3. + 4.));
+/// ```
+///
+pub fn closeness_centrality<T>(g: &T) -> HashMap<&T::Vertex, f64>
+where
+    T: Storage,
+{
+    // Get the graph order.
+    let n = g.order();
+
+    distances_to(g)
+        .into_iter()
+        .map(|(x, d)| {
+            // Get the number of vertices that reach the vertex, excluding itself, ...
+            let r = d.len() as f64;
+            // ... and the sum of their distances.
+            let s = d.into_iter().sum::<usize>() as f64;
+            // Scale the inverse average distance by the fraction of reaching vertices.
+            let c = match s > 0. {
+                true => (r / s) * (r / (n - 1) as f64),
+                false => 0.,
+            };
+
+            (x, c)
+        })
+        .collect()
+}
+
+/// Harmonic centrality of a graph.
+///
+/// The harmonic centrality $C_H$ of a vertex $X$ in a graph $G$ is defined as:
+///
+/// $$ C_H(X) = \sum_{Y \neq X} \frac{1}{d(Y, X)} $$
+///
+/// with $d(Y, X)$ the distance from $Y$ to $X$ and $1 / \infty = 0$, as in Boldi and Vigna[^1].
+/// For directed graphs, the distances are computed towards the vertex, i.e. using in-coming edges.
+///
+/// [^1]: [Boldi, P., & Vigna, S. (2014). Axioms for centrality. Internet Mathematics, 10(3-4), 222-262.](https://scholar.google.com/scholar?q=Axioms+for+centrality)
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a path graph.
+/// let g = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+///
+/// // Compute the harmonic centrality.
+/// let c = harmonic_centrality(&g);
+///
+/// // Check the centrality of the center and of the endpoints.
+/// assert_eq!(c[&2], 1. + 1. / 2. + 1. + 1. / 2.);
+/// assert_eq!(c[&0], 1. + 1. / 2. + 1. / 3. + 1. / 4.);
+/// ```
+///
+pub fn harmonic_centrality<T>(g: &T) -> HashMap<&T::Vertex, f64>
+where
+    T: Storage,
+{
+    distances_to(g)
+        .into_iter()
+        .map(|(x, d)| (x, d.into_iter().map(|d| 1. / d as f64).sum()))
+        .collect()
+}
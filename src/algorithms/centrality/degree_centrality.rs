@@ -0,0 +1,108 @@
+use crate::traits::{Directed, Undirected};
+use crate::{Ch, Ne, Pa, V};
+use std::collections::HashMap;
+
+/// Normalize given degrees by the maximum possible degree.
+fn normalize<'a, V, F>(vertices: impl Iterator<Item = &'a V>, n: usize, f: F) -> HashMap<&'a V, f64>
+where
+    V: 'a + Eq + std::hash::Hash,
+    F: Fn(&'a V) -> usize,
+{
+    // A vertex can be adjacent to every other vertex at most, ...
+    match n {
+        // ... except if there are no other vertices.
+        0 | 1 => vertices.map(|x| (x, 1.)).collect(),
+        _ => vertices.map(|x| (x, f(x) as f64 / (n - 1) as f64)).collect(),
+    }
+}
+
+/// Degree centrality of an undirected graph.
+///
+/// The degree centrality $C_D$ of a vertex $X$ in an undirected graph $G$ is defined as:
+///
+/// $$ C_D(X) = \frac{|Ne(G, X)|}{|\textbf{V}| - 1} $$
+///
+/// i.e. the fraction of the other vertices it is adjacent to. Each vertex of a graph
+/// with a single vertex has centrality one.
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a star graph.
+/// let g = Graph::from_edges([(0, 1), (0, 2), (0, 3), (0, 4)]);
+///
+/// // Compute the degree centrality.
+/// let c = degree_centrality(&g);
+///
+/// // The center is adjacent to every other vertex ...
+/// assert_eq!(c[&0], 1.);
+/// // ... while the leaves are adjacent to the center only.
+/// assert_eq!(c[&1], 0.25);
+/// ```
+///
+pub fn degree_centrality<T>(g: &T) -> HashMap<&T::Vertex, f64>
+where
+    T: Undirected,
+{
+    normalize(V!(g), g.order(), |x| Ne!(g, x).count())
+}
+
+/// In-degree centrality of a directed graph.
+///
+/// The in-degree centrality $C_D^-$ of a vertex $X$ in a directed graph $G$ is defined as:
+///
+/// $$ C_D^-(X) = \frac{|Pa(G, X)|}{|\textbf{V}| - 1} $$
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build an in-star graph.
+/// let g = DiGraph::from_edges([(1, 0), (2, 0), (3, 0), (4, 0)]);
+///
+/// // Compute the in-degree centrality.
+/// let c = in_degree_centrality(&g);
+///
+/// // The center is the child of every other vertex.
+/// assert_eq!(c[&0], 1.);
+/// assert_eq!(c[&1], 0.);
+/// ```
+///
+pub fn in_degree_centrality<T>(g: &T) -> HashMap<&T::Vertex, f64>
+where
+    T: Directed,
+{
+    normalize(V!(g), g.order(), |x| Pa!(g, x).count())
+}
+
+/// Out-degree centrality of a directed graph.
+///
+/// The out-degree centrality $C_D^+$ of a vertex $X$ in a directed graph $G$ is defined as:
+///
+/// $$ C_D^+(X) = \frac{|Ch(G, X)|}{|\textbf{V}| - 1} $$
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build an in-star graph.
+/// let g = DiGraph::from_edges([(1, 0), (2, 0), (3, 0), (4, 0)]);
+///
+/// // Compute the out-degree centrality.
+/// let c = out_degree_centrality(&g);
+///
+/// // The center has no children.
+/// assert_eq!(c[&0], 0.);
+/// assert_eq!(c[&1], 0.25);
+/// ```
+///
+pub fn out_degree_centrality<T>(g: &T) -> HashMap<&T::Vertex, f64>
+where
+    T: Directed,
+{
+    normalize(V!(g), g.order(), |x| Ch!(g, x).count())
+}
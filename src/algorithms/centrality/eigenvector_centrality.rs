@@ -0,0 +1,73 @@
+use crate::linalg::dense::adjacency_matrix;
+use crate::traits::{Convert, Storage};
+use crate::types::Error;
+use crate::V;
+use ndarray::Array1;
+use std::collections::HashMap;
+
+/// Eigenvector centrality of a graph.
+///
+/// The eigenvector centrality $\textbf{x}$ of a graph $G$ is defined as the solution of:
+///
+/// $$ \textbf{A}^T \textbf{x} = \lambda \textbf{x} $$
+///
+/// with $\textbf{A}$ the [adjacency matrix][`crate::linalg::dense::adjacency_matrix`] and $\lambda$ its largest
+/// eigenvalue, as in Bonacich[^1]. For directed graphs, the centrality of a vertex depends on the centralities
+/// of its parents. The solution is computed by power iteration over $\textbf{A}^T + \textbf{I}$, which shares
+/// the same eigenvectors, while being aperiodic, and is normalized to unit Euclidean norm. The iteration stops
+/// when the $L_1$ norm of the update is less than $|\textbf{V}|$ times the given tolerance.
+///
+/// [^1]: [Bonacich, P. (1987). Power and centrality: A family of measures. American Journal of Sociology, 92(5), 1170-1182.](https://scholar.google.com/scholar?q=Power+and+centrality%3A+A+family+of+measures)
+///
+/// # Errors
+///
+/// The power iteration does not converge within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use approx::*;
+/// use grathe::prelude::*;
+///
+/// // Build a star graph.
+/// let g = Graph::from_edges([(0, 1), (0, 2), (0, 3), (0, 4)]);
+///
+/// // Compute the eigenvector centrality.
+/// let c = eigenvector_centrality(&g, 1e-9, 100).unwrap();
+///
+/// // The center is twice as central as the leaves.
+/// assert_relative_eq!(c[&0], 1. / f64::sqrt(2.), epsilon = 1e-6);
+/// assert_relative_eq!(c[&1], 1. / f64::sqrt(8.), epsilon = 1e-6);
+/// ```
+///
+pub fn eigenvector_centrality<T>(g: &T, tol: f64, max_iter: usize) -> Result<HashMap<&T::Vertex, f64>, Error<T::Vertex>>
+where
+    T: Convert + Storage,
+{
+    // Get the graph order.
+    let n = g.order();
+    // If the graph is null, then there is nothing to compute.
+    if n == 0 {
+        return Ok(Default::default());
+    }
+    // Get the adjacency matrix.
+    let a = adjacency_matrix(g).mapv(f64::from);
+    // Initialize the centrality vector uniformly.
+    let mut x = Array1::from_elem(n, 1. / n as f64);
+    for _ in 0..max_iter {
+        // Compute the next centrality vector, i.e. x := (A^T + I) x ...
+        let mut y = x.dot(&a) + &x;
+        // ... normalized to unit norm.
+        let norm = y.dot(&y).sqrt();
+        y /= norm;
+        // Compute the update norm.
+        let delta = (&y - &x).mapv(f64::abs).sum();
+        x = y;
+        // Check convergence.
+        if delta < n as f64 * tol {
+            return Ok(V!(g).zip(x).collect());
+        }
+    }
+
+    Err(Error::NotConverged(max_iter))
+}
@@ -0,0 +1,93 @@
+use crate::traits::Directed;
+use crate::types::Error;
+use crate::{Ch, Pa, V};
+use std::collections::HashMap;
+
+/// Normalize given scores to unit sum, if possible.
+fn normalize<V>(x: &mut HashMap<&V, f64>) {
+    let s: f64 = x.values().sum();
+    if s > 0. {
+        x.values_mut().for_each(|x| *x /= s);
+    }
+}
+
+/// Hubs and authorities of a directed graph.
+///
+/// The hub score $h$ and the authority score $a$ of the vertices of a directed graph $G$ are defined
+/// as the solution of:
+///
+/// $$ a(X) = \sum_{Y \in Pa(G, X)} h(Y) \qquad h(X) = \sum_{Y \in Ch(G, X)} a(Y) $$
+///
+/// i.e. a good hub points to good authorities, while a good authority is pointed by good hubs, as in Kleinberg[^1].
+/// The solution is computed by power iteration, starting from uniform hub scores, and both scores are normalized
+/// to unit sum. The iteration stops when the $L_1$ norm of the hub scores update is less than $|\textbf{V}|$
+/// times the given tolerance. Returns the hub scores and the authority scores, in this order.
+///
+/// [^1]: [Kleinberg, J. M. (1999). Authoritative sources in a hyperlinked environment. Journal of the ACM, 46(5), 604-632.](https://scholar.google.com/scholar?q=Authoritative+sources+in+a+hyperlinked+environment)
+///
+/// # Errors
+///
+/// The power iteration does not converge within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use approx::*;
+/// use grathe::prelude::*;
+///
+/// // Build a citation graph, where `0` and `1` cite both `2` and `3`.
+/// let g = DiGraph::from_edges([(0, 2), (0, 3), (1, 2), (1, 3)]);
+///
+/// // Compute the hubs and authorities.
+/// let (h, a) = hits(&g, 1e-9, 100).unwrap();
+///
+/// // The citing vertices are hubs ...
+/// assert_relative_eq!(h[&0], 0.5, epsilon = 1e-6);
+/// assert_relative_eq!(h[&2], 0., epsilon = 1e-6);
+/// // ... while the cited ones are authorities.
+/// assert_relative_eq!(a[&0], 0., epsilon = 1e-6);
+/// assert_relative_eq!(a[&2], 0.5, epsilon = 1e-6);
+/// ```
+///
+pub fn hits<T>(
+    g: &T,
+    tol: f64,
+    max_iter: usize,
+) -> Result<(HashMap<&T::Vertex, f64>, HashMap<&T::Vertex, f64>), Error<T::Vertex>>
+where
+    T: Directed,
+{
+    // Get the graph order.
+    let n = g.order();
+    // If the graph is null, then there is nothing to compute.
+    if n == 0 {
+        return Ok(Default::default());
+    }
+    // Compute the authority scores from given hub scores.
+    let authorities = |h: &HashMap<_, f64>| {
+        let mut a: HashMap<_, _> = V!(g).map(|x| (x, Pa!(g, x).map(|y| h[y]).sum())).collect();
+        normalize(&mut a);
+        a
+    };
+    // Initialize the hub scores uniformly.
+    let mut h: HashMap<_, _> = V!(g).map(|x| (x, 1. / n as f64)).collect();
+    for _ in 0..max_iter {
+        // Compute the authority scores, ...
+        let a = authorities(&h);
+        // ... then the next hub scores.
+        let mut k: HashMap<_, _> = V!(g).map(|x| (x, Ch!(g, x).map(|y| a[y]).sum())).collect();
+        normalize(&mut k);
+        // Compute the update norm.
+        let delta: f64 = h.iter().map(|(x, h)| (h - k[x]).abs()).sum();
+        h = k;
+        // Check convergence.
+        if delta < n as f64 * tol {
+            // Compute the authority scores of the final hub scores.
+            let a = authorities(&h);
+
+            return Ok((h, a));
+        }
+    }
+
+    Err(Error::NotConverged(max_iter))
+}
@@ -0,0 +1,86 @@
+use crate::linalg::dense::adjacency_matrix;
+use crate::traits::{Convert, Storage};
+use crate::types::Error;
+use crate::V;
+use ndarray::Array1;
+use std::collections::HashMap;
+
+/// Katz centrality of a graph.
+///
+/// The Katz centrality $\textbf{x}$ of a graph $G$ is defined as the solution of:
+///
+/// $$ \textbf{x} = \alpha \textbf{A}^T \textbf{x} + \beta \textbf{1} $$
+///
+/// with $\textbf{A}$ the [adjacency matrix][`crate::linalg::dense::adjacency_matrix`], $\alpha$ the attenuation
+/// factor and $\beta$ the base centrality, as in Katz[^1]. The solution exists if $\alpha$ is less than the inverse
+/// of the largest eigenvalue of $\textbf{A}$. The solution is computed by fixed-point iteration and is normalized to
+/// unit Euclidean norm. The iteration stops when the $L_1$ norm of the update is less than $|\textbf{V}|$ times
+/// the given tolerance.
+///
+/// [^1]: [Katz, L. (1953). A new status index derived from sociometric analysis. Psychometrika, 18(1), 39-43.](https://scholar.google.com/scholar?q=A+new+status+index+derived+from+sociometric+analysis)
+///
+/// # Errors
+///
+/// The fixed-point iteration does not converge within the given number of iterations,
+/// e.g. if the attenuation factor is too large.
+///
+/// # Examples
+///
+/// ```
+/// use approx::*;
+/// use grathe::prelude::*;
+///
+/// // Build a directed path graph.
+/// let g = DiGraph::from_edges([(0, 1), (1, 2)]);
+///
+/// // Compute the Katz centrality.
+/// let c = katz_centrality(&g, 0.5, 1., 1e-9, 100).unwrap();
+///
+/// // Each vertex gets its base centrality, plus the attenuated one of its parent.
+/// let (x, y, z) = (1., 1. + 0.5, 1. + 0.5 * 1.5);
+/// let norm = f64::sqrt(x * x + y * y + z * z);
+/// assert_relative_eq!(c[&0], x / norm, epsilon = 1e-6);
+/// assert_relative_eq!(c[&1], y / norm, epsilon = 1e-6);
+/// assert_relative_eq!(c[&2], z / norm, epsilon = 1e-6);
+/// ```
+///
+pub fn katz_centrality<T>(
+    g: &T,
+    alpha: f64,
+    beta: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<HashMap<&T::Vertex, f64>, Error<T::Vertex>>
+where
+    T: Convert + Storage,
+{
+    // Get the graph order.
+    let n = g.order();
+    // If the graph is null, then there is nothing to compute.
+    if n == 0 {
+        return Ok(Default::default());
+    }
+    // Get the adjacency matrix.
+    let a = adjacency_matrix(g).mapv(f64::from);
+    // Initialize the centrality vector.
+    let mut x = Array1::zeros(n);
+    for _ in 0..max_iter {
+        // Compute the next centrality vector, i.e. x := alpha * A^T x + beta.
+        let y = alpha * x.dot(&a) + beta;
+        // Compute the update norm.
+        let delta = (&y - &x).mapv(f64::abs).sum();
+        x = y;
+        // Check convergence.
+        if delta < n as f64 * tol {
+            // Normalize to unit norm, if possible.
+            let norm = x.dot(&x).sqrt();
+            if norm > 0. {
+                x /= norm;
+            }
+
+            return Ok(V!(g).zip(x).collect());
+        }
+    }
+
+    Err(Error::NotConverged(max_iter))
+}
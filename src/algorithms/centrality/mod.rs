@@ -0,0 +1,23 @@
+mod betweenness_centrality;
+pub use betweenness_centrality::{
+    betweenness_centrality, edge_betweenness_centrality, weighted_betweenness_centrality,
+    weighted_edge_betweenness_centrality,
+};
+
+mod closeness_centrality;
+pub use closeness_centrality::{closeness_centrality, harmonic_centrality};
+
+mod degree_centrality;
+pub use degree_centrality::{degree_centrality, in_degree_centrality, out_degree_centrality};
+
+mod eigenvector_centrality;
+pub use eigenvector_centrality::eigenvector_centrality;
+
+mod hits;
+pub use hits::hits;
+
+mod katz_centrality;
+pub use katz_centrality::katz_centrality;
+
+mod pagerank;
+pub use pagerank::pagerank;
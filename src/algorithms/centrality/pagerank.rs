@@ -0,0 +1,90 @@
+use crate::linalg::dense::adjacency_matrix;
+use crate::traits::{Convert, Storage};
+use crate::types::Error;
+use crate::V;
+use ndarray::{Array1, Axis};
+use std::collections::HashMap;
+
+/// PageRank of a graph.
+///
+/// The PageRank $\textbf{x}$ of a graph $G$ is defined as the stationary distribution of a random walk that,
+/// with probability $\alpha$, follows an out-going edge of the current vertex chosen uniformly at random and,
+/// otherwise, jumps to a vertex chosen uniformly at random, as in Page et al.[^1]. Vertices without out-going
+/// edges jump to a vertex chosen uniformly at random. That is:
+///
+/// $$ \textbf{x} = \alpha (\textbf{A}^T \textbf{D}^{-1} \textbf{x} + \frac{\textbf{d}^T \textbf{x}}{|\textbf{V}|} \textbf{1}) + \frac{1 - \alpha}{|\textbf{V}|} \textbf{1} $$
+///
+/// with $\textbf{A}$ the [adjacency matrix][`crate::linalg::dense::adjacency_matrix`], $\textbf{D}$ the diagonal
+/// matrix of the out-degrees, whose zeros are inverted to zeros, and $\textbf{d}$ the indicator vector of the vertices
+/// without out-going edges. For undirected graphs, each edge is followed in both directions. The solution is
+/// computed by power iteration and sums to one. The iteration stops when the $L_1$ norm of the update is less than
+/// $|\textbf{V}|$ times the given tolerance.
+///
+/// [^1]: [Page, L., Brin, S., Motwani, R., & Winograd, T. (1999). The PageRank citation ranking: Bringing order to the web. Stanford InfoLab.](https://scholar.google.com/scholar?q=The+PageRank+citation+ranking%3A+Bringing+order+to+the+web)
+///
+/// # Errors
+///
+/// The power iteration does not converge within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use approx::*;
+/// use grathe::prelude::*;
+///
+/// // Build a directed cycle.
+/// let g = DiGraph::from_edges([(0, 1), (1, 2), (2, 0)]);
+///
+/// // Compute the PageRank.
+/// let c = pagerank(&g, 0.85, 1e-9, 100).unwrap();
+///
+/// // The random walk visits each vertex equally often.
+/// assert_relative_eq!(c[&0], 1. / 3., epsilon = 1e-6);
+///
+/// // Build a directed star, whose leaves have no out-going edges.
+/// let g = DiGraph::from_edges([(0, 1), (0, 2)]);
+///
+/// // Compute the PageRank.
+/// let c = pagerank(&g, 0.85, 1e-9, 100).unwrap();
+///
+/// // The leaves are visited more often than the center.
+/// assert!(c[&1] > c[&0]);
+/// assert_relative_eq!(c.values().sum::<f64>(), 1., epsilon = 1e-6);
+/// ```
+///
+pub fn pagerank<T>(g: &T, alpha: f64, tol: f64, max_iter: usize) -> Result<HashMap<&T::Vertex, f64>, Error<T::Vertex>>
+where
+    T: Convert + Storage,
+{
+    // Get the graph order.
+    let n = g.order();
+    // If the graph is null, then there is nothing to compute.
+    if n == 0 {
+        return Ok(Default::default());
+    }
+    // Get the adjacency matrix.
+    let a = adjacency_matrix(g).mapv(f64::from);
+    // Compute the out-degrees.
+    let d = a.sum_axis(Axis(1));
+    // Initialize the centrality vector uniformly.
+    let mut x = Array1::from_elem(n, 1. / n as f64);
+    for _ in 0..max_iter {
+        // Compute the mass of the vertices without out-going edges.
+        let dangling: f64 = x.iter().zip(&d).filter(|(_, &d)| d == 0.).map(|(x, _)| x).sum();
+        // Spread the mass of each vertex over its out-going edges.
+        let s = ndarray::Zip::from(&x)
+            .and(&d)
+            .map_collect(|&x, &d| if d > 0. { x / d } else { 0. });
+        // Compute the next centrality vector.
+        let y = alpha * (s.dot(&a) + dangling / n as f64) + (1. - alpha) / n as f64;
+        // Compute the update norm.
+        let delta = (&y - &x).mapv(f64::abs).sum();
+        x = y;
+        // Check convergence.
+        if delta < n as f64 * tol {
+            return Ok(V!(g).zip(x).collect());
+        }
+    }
+
+    Err(Error::NotConverged(max_iter))
+}
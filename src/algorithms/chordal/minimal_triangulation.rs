@@ -0,0 +1,162 @@
+use crate::graphs::UndirectedAdjacencyListGraph;
+use crate::traits::{Storage, Undirected};
+use crate::types::EdgeList;
+use crate::utils::MinPriority;
+use crate::{Ne, V};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::vec::Vec;
+
+/// Minimal triangulation structure.
+///
+/// This structure contains the `fill_in` edges that make the given graph chordal,
+/// and the elimination `order` that is perfect for the triangulated graph.
+///
+pub struct MinimalTriangulation<'a, T>
+where
+    T: Undirected,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Perfect elimination ordering of the triangulated graph.
+    pub order: Vec<&'a T::Vertex>,
+    /// Set of fill-in edges, with the lowest endpoint first.
+    pub fill_in: EdgeList<&'a T::Vertex>,
+}
+
+impl<'a, T> MinimalTriangulation<'a, T>
+where
+    T: Undirected,
+{
+    /// Build a minimal triangulation structure.
+    ///
+    /// Build a minimal triangulation structure following the MCS-M[^1] algorithm, i.e. a maximum cardinality search
+    /// where a vertex is numbered and its weight is increased whenever it is reachable from the last numbered vertex
+    /// through a path of unnumbered vertices with lower weight. The resulting triangulation is minimal,
+    /// that is, no proper subset of the fill-in edges makes the graph chordal.
+    ///
+    /// [^1]: [Berry, A., Blair, J. R., Heggernes, P., & Peyton, B. W. (2004). Maximum cardinality search for computing minimal triangulations of graphs. Algorithmica, 39(4), 287-298.](https://scholar.google.com/scholar?q=Maximum+cardinality+search+for+computing+minimal+triangulations+of+graphs)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a chordless cycle of length five.
+    /// let g = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    /// assert!(!g.is_chordal());
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = MinimalTriangulation::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // Two chords are enough to triangulate the cycle.
+    /// assert_eq!(search.fill_in.len(), 2);
+    ///
+    /// // The triangulated graph is chordal.
+    /// assert!(search.triangulation().is_chordal());
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the elimination ordering.
+            order: Default::default(),
+            // Initialize the fill-in edges.
+            fill_in: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Initialize the weight of each vertex.
+        let mut weight: HashMap<_, _> = V!(self.graph).map(|x| (x, 0)).collect();
+        // Initialize the set of numbered vertices.
+        let mut numbered = HashSet::with_capacity(weight.len());
+        // Number the vertices from the last to the first.
+        for _ in 0..weight.len() {
+            // Select the unnumbered vertex with maximum weight, breaking ties by vertex order.
+            let x = V!(self.graph)
+                .filter(|x| !numbered.contains(x))
+                .fold(None, |z: Option<&T::Vertex>, y| match z {
+                    Some(z) if weight[z] >= weight[y] => Some(z),
+                    _ => Some(y),
+                })
+                .unwrap();
+            numbered.insert(x);
+            // Compute, for each unnumbered vertex, the minimum over the paths from the selected vertex
+            // of the maximum weight of the intermediate vertices, i.e. a bottleneck shortest path.
+            let mut bottleneck: HashMap<&T::Vertex, isize> = HashMap::new();
+            let mut queue = BinaryHeap::new();
+            // The neighbors are reachable without intermediate vertices.
+            for y in Ne!(self.graph, x).filter(|y| !numbered.contains(y)) {
+                bottleneck.insert(y, -1);
+                queue.push(MinPriority(-1, y));
+            }
+            // Initialize the vertices reached through lower weight vertices.
+            let mut reached = Vec::new();
+            while let Some(MinPriority(b, y)) = queue.pop() {
+                // Skip outdated entries.
+                if b > bottleneck[y] {
+                    continue;
+                }
+                // If the path has lower weight than the vertex, then the vertex is reached.
+                if b < weight[y] {
+                    reached.push(y);
+                }
+                // Relax the paths through the vertex.
+                let b = b.max(weight[y]);
+                for z in Ne!(self.graph, y).filter(|z| !numbered.contains(z)) {
+                    if bottleneck.get(z).is_none_or(|&c| b < c) {
+                        bottleneck.insert(z, b);
+                        queue.push(MinPriority(b, z));
+                    }
+                }
+            }
+            // Increase the weight of the reached vertices, adding a fill-in edge if not adjacent.
+            for y in reached {
+                *weight.get_mut(y).unwrap() += 1;
+                if !self.graph.has_edge(x, y).unwrap() {
+                    self.fill_in.insert((x.min(y), x.max(y)));
+                }
+            }
+            // The selected vertex comes after the unnumbered ones in the elimination ordering.
+            self.order.push(x);
+        }
+        // Reverse the numbering order to obtain the elimination ordering.
+        self.order.reverse();
+
+        self
+    }
+
+    /// Triangulated graph.
+    ///
+    /// Returns the given graph with the fill-in edges added.
+    ///
+    pub fn triangulation(&self) -> UndirectedAdjacencyListGraph<T::Vertex> {
+        UndirectedAdjacencyListGraph::new(
+            V!(self.graph).cloned(),
+            self.graph
+                .edges_iter()
+                .filter(|(x, y)| x <= y)
+                .chain(self.fill_in.iter().copied())
+                .map(|(x, y)| (x.clone(), y.clone())),
+        )
+    }
+}
+
+impl<'a, T> From<&'a T> for MinimalTriangulation<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
@@ -0,0 +1,2 @@
+mod minimal_triangulation;
+pub use minimal_triangulation::MinimalTriangulation;
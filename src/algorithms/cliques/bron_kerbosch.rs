@@ -0,0 +1,131 @@
+use crate::traits::Undirected;
+use crate::{Ne, V};
+use std::collections::{BTreeSet, HashMap};
+use std::vec::Vec;
+
+/// Bron-Kerbosch search structure.
+///
+/// This structure contains the maximal `cliques` of the graph.
+///
+pub struct BronKerbosch<'a, T>
+where
+    T: Undirected,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Neighbors of each vertex, without self-edges.
+    neighbors: HashMap<&'a T::Vertex, BTreeSet<&'a T::Vertex>>,
+    /// Vector of maximal cliques, in lexicographic order.
+    pub cliques: Vec<BTreeSet<&'a T::Vertex>>,
+}
+
+impl<'a, T> BronKerbosch<'a, T>
+where
+    T: Undirected,
+{
+    /// Build a Bron-Kerbosch search structure.
+    ///
+    /// Build a Bron-Kerbosch[^1] search structure, which enumerates the maximal cliques of a given graph,
+    /// i.e. the sets of pairwise adjacent vertices that are not contained in any larger one.
+    /// The search space is pruned by pivoting on the vertex with the most candidate neighbors, following Tomita et al.[^2].
+    ///
+    /// [^1]: [Bron, C., & Kerbosch, J. (1973). Algorithm 457: finding all cliques of an undirected graph. Communications of the ACM, 16(9), 575-577.](https://scholar.google.com/scholar?q=Algorithm+457%3A+finding+all+cliques+of+an+undirected+graph)
+    /// [^2]: [Tomita, E., Tanaka, A., & Takahashi, H. (2006). The worst-case time complexity for generating all maximal cliques and computational experiments. Theoretical Computer Science, 363(1), 28-42.](https://scholar.google.com/scholar?q=The+worst-case+time+complexity+for+generating+all+maximal+cliques+and+computational+experiments)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build two triangles sharing an edge, with a pendant vertex.
+    /// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = BronKerbosch::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // Maximal cliques are sorted in lexicographic order.
+    /// assert_eq!(
+    ///     search.cliques,
+    ///     [
+    ///         BTreeSet::from([&0, &1, &2]),
+    ///         BTreeSet::from([&1, &2, &3]),
+    ///         BTreeSet::from([&3, &4]),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the neighbors map.
+            neighbors: Default::default(),
+            // Initialize the vector of maximal cliques.
+            cliques: Default::default(),
+        }
+    }
+
+    fn expand(&mut self, r: &mut Vec<&'a T::Vertex>, mut p: BTreeSet<&'a T::Vertex>, mut x: BTreeSet<&'a T::Vertex>) {
+        // If there are neither candidates nor excluded vertices, then the clique is maximal.
+        if p.is_empty() && x.is_empty() {
+            self.cliques.push(r.iter().copied().collect());
+            return;
+        }
+        // Choose the pivot with the most neighbors among the candidates.
+        let u = p
+            .union(&x)
+            .max_by_key(|u| self.neighbors[*u].intersection(&p).count())
+            .copied()
+            .unwrap();
+        // For each candidate that is not a neighbor of the pivot.
+        let candidates: Vec<_> = p.difference(&self.neighbors[u]).copied().collect();
+        for v in candidates {
+            // Extend the clique with the candidate, restricting the sets to its neighbors.
+            let n = &self.neighbors[v];
+            let (q, y) = (
+                p.intersection(n).copied().collect(),
+                x.intersection(n).copied().collect(),
+            );
+            r.push(v);
+            self.expand(r, q, y);
+            r.pop();
+            // Move the candidate to the excluded vertices.
+            p.remove(v);
+            x.insert(v);
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Compute the neighbors of each vertex, skipping self-edges.
+        self.neighbors = V!(self.graph)
+            .map(|x| (x, Ne!(self.graph, x).filter(|&y| y != x).collect()))
+            .collect();
+        // If the graph is not null, expand the empty clique with every vertex as candidate.
+        if !self.neighbors.is_empty() {
+            self.expand(&mut Vec::new(), V!(self.graph).collect(), Default::default());
+        }
+        // Sort the maximal cliques.
+        self.cliques.sort();
+
+        self
+    }
+}
+
+impl<'a, T> From<&'a T> for BronKerbosch<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::traits::Undirected;
+use crate::types::Error;
+use crate::Ne;
+use std::collections::{BTreeSet, HashMap};
+use std::vec::Vec;
+
+/// Chordal cliques search structure.
+///
+/// This structure contains the perfect elimination `order` and the maximal `cliques` of a chordal graph.
+///
+pub struct ChordalCliques<'a, T>
+where
+    T: Undirected,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Perfect elimination ordering of the graph.
+    pub order: Vec<&'a T::Vertex>,
+    /// Vector of maximal cliques, in lexicographic order.
+    pub cliques: Vec<BTreeSet<&'a T::Vertex>>,
+}
+
+impl<'a, T> ChordalCliques<'a, T>
+where
+    T: Undirected,
+{
+    /// Build a chordal cliques search structure.
+    ///
+    /// Build a chordal cliques search structure, which extracts the maximal cliques of a chordal graph
+    /// from a [perfect elimination ordering](Undirected::perfect_elimination_ordering) in linear time[^1].
+    /// Each vertex together with its later neighbors forms a clique, which is maximal unless it is
+    /// contained in the clique of an earlier vertex.
+    ///
+    /// [^1]: [Fulkerson, D., & Gross, O. (1965). Incidence matrices and interval graphs. Pacific Journal of Mathematics, 15(3), 835-855.](https://scholar.google.com/scholar?q=Incidence+matrices+and+interval+graphs)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build two triangles sharing an edge, with a pendant vertex.
+    /// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = ChordalCliques::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run().unwrap();
+    ///
+    /// // Maximal cliques are sorted in lexicographic order.
+    /// assert_eq!(
+    ///     search.cliques,
+    ///     [
+    ///         BTreeSet::from([&0, &1, &2]),
+    ///         BTreeSet::from([&1, &2, &3]),
+    ///         BTreeSet::from([&3, &4]),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the elimination ordering.
+            order: Default::default(),
+            // Initialize the vector of maximal cliques.
+            cliques: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    /// # Errors
+    ///
+    /// The graph is not chordal.
+    ///
+    pub fn run(&mut self) -> Result<&Self, Error<T::Vertex>> {
+        // Compute the perfect elimination ordering, if any.
+        self.order = self.graph.perfect_elimination_ordering().ok_or(Error::NotChordal())?;
+        // Map each vertex to its position in the ordering.
+        let position: HashMap<_, _> = self.order.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Compute the candidate clique of each vertex, i.e. the vertex with its later neighbors.
+        let candidates: Vec<BTreeSet<_>> = self
+            .order
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| Ne!(self.graph, x).filter(|y| position[y] > i).chain([x]).collect())
+            .collect();
+        // Initialize the maximality flags.
+        let mut maximal = vec![true; candidates.len()];
+        // For each vertex with later neighbors ...
+        for (i, c) in candidates.iter().enumerate() {
+            // ... get the earliest of them, i.e. its parent.
+            if let Some(j) = c.iter().map(|y| position[y]).filter(|&j| j != i).min() {
+                // If the candidate of the parent has exactly one vertex less, then it is contained in the
+                // candidate of the vertex, hence it is not maximal.
+                if candidates[j].len() + 1 == c.len() {
+                    maximal[j] = false;
+                }
+            }
+        }
+        // Collect the maximal cliques.
+        self.cliques = candidates
+            .into_iter()
+            .zip(maximal)
+            .filter(|(_, m)| *m)
+            .map(|(c, _)| c)
+            .collect();
+        // Sort the maximal cliques.
+        self.cliques.sort();
+
+        Ok(self)
+    }
+}
+
+impl<'a, T> From<&'a T> for ChordalCliques<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
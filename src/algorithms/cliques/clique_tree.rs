@@ -0,0 +1,134 @@
+use super::ChordalCliques;
+use crate::algorithms::Kruskal;
+use crate::graphs::attributes::AttributesMap;
+use crate::graphs::UndirectedAdjacencyListGraph;
+use crate::traits::{Storage, Undirected, WithAttributes};
+use crate::types::Error;
+use std::collections::BTreeSet;
+use std::vec::Vec;
+
+/// Clique tree structure.
+///
+/// This structure contains the clique `tree` of a chordal graph, where each vertex is a maximal clique
+/// and each edge is labeled with the separator set of its endpoints, i.e. their intersection.
+///
+pub struct CliqueTree<'a, T>
+where
+    T: Undirected,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Clique tree, with separator sets as edge attributes.
+    pub tree: UndirectedAdjacencyListGraph<
+        BTreeSet<T::Vertex>,
+        AttributesMap<BTreeSet<T::Vertex>, (), (), BTreeSet<T::Vertex>>,
+    >,
+}
+
+impl<'a, T> CliqueTree<'a, T>
+where
+    T: Undirected,
+{
+    /// Build a clique tree structure.
+    ///
+    /// Build a clique tree (or junction tree) structure for a given chordal graph, i.e. a tree over its
+    /// maximal cliques such that the cliques containing any given vertex induce a connected subtree.
+    /// The tree is computed as a maximum weight spanning tree of the clique intersection graph[^1],
+    /// where the weight of an edge is the size of its separator set. If the graph is disconnected,
+    /// then a clique forest is returned.
+    ///
+    /// Non-chordal graphs can be made chordal using a [`MinimalTriangulation`](crate::algorithms::MinimalTriangulation).
+    ///
+    /// [^1]: [Jensen, F. V., & Jensen, F. (1994). Optimal junction trees. In Proceedings of the Tenth Conference on Uncertainty in Artificial Intelligence (pp. 360-366).](https://scholar.google.com/scholar?q=Optimal+junction+trees)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build two triangles sharing an edge, with a pendant vertex.
+    /// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = CliqueTree::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run().unwrap();
+    ///
+    /// // The clique tree is a path over the maximal cliques.
+    /// let (a, b, c) = (BTreeSet::from([0, 1, 2]), BTreeSet::from([1, 2, 3]), BTreeSet::from([3, 4]));
+    /// assert_eq!(search.tree.order(), 3);
+    /// assert_eq!(search.tree.size(), 2);
+    ///
+    /// // Separator sets are stored as edge attributes.
+    /// assert_eq!(search.tree.get_edge_attrs(&a, &b).unwrap(), &BTreeSet::from([1, 2]));
+    /// assert_eq!(search.tree.get_edge_attrs(&c, &b).unwrap(), &BTreeSet::from([3]));
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the clique tree.
+            tree: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    /// # Errors
+    ///
+    /// The graph is not chordal.
+    ///
+    pub fn run(&mut self) -> Result<&Self, Error<T::Vertex>> {
+        // Compute the maximal cliques.
+        let cliques: Vec<BTreeSet<T::Vertex>> = ChordalCliques::new(self.graph)
+            .run()?
+            .cliques
+            .iter()
+            .map(|c| c.iter().map(|&x| x.clone()).collect())
+            .collect();
+        // Build the clique intersection graph, i.e. cliques are adjacent if they share a vertex.
+        let intersections = UndirectedAdjacencyListGraph::<BTreeSet<T::Vertex>>::new(
+            cliques.iter().cloned(),
+            cliques
+                .iter()
+                .enumerate()
+                .flat_map(|(i, x)| cliques[i + 1..].iter().map(move |y| (x, y)))
+                .filter(|(x, y)| !x.is_disjoint(y))
+                .map(|(x, y)| (x.clone(), y.clone())),
+        );
+        // Compute a maximum weight spanning tree, i.e. a minimum one with negated separator sizes.
+        let tree = Kruskal::new(&intersections, |x, y| -(x.intersection(y).count() as f64))
+            .run()
+            .tree
+            .edges_iter()
+            .filter(|(x, y)| x < y)
+            .map(|(x, y)| (x.clone(), y.clone()))
+            .collect::<Vec<_>>();
+        // Build the clique tree.
+        self.tree = UndirectedAdjacencyListGraph::new(cliques, tree.iter().cloned());
+        // Label each edge with its separator set, in both directions.
+        for (x, y) in tree {
+            let s: BTreeSet<_> = x.intersection(&y).cloned().collect();
+            self.tree.set_edge_attrs(&x, &y, s.clone());
+            self.tree.set_edge_attrs(&y, &x, s);
+        }
+
+        Ok(self)
+    }
+}
+
+impl<'a, T> From<&'a T> for CliqueTree<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
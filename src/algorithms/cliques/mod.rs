@@ -0,0 +1,8 @@
+mod bron_kerbosch;
+pub use bron_kerbosch::BronKerbosch;
+
+mod chordal_cliques;
+pub use chordal_cliques::ChordalCliques;
+
+mod clique_tree;
+pub use clique_tree::CliqueTree;
@@ -0,0 +1,196 @@
+use super::{directed_triangles, triangles, TrianglePattern};
+use crate::traits::{Directed, Undirected};
+use crate::{Ch, Ne, Pa, V};
+use std::collections::{HashMap, HashSet};
+
+/// Local clustering coefficient of an undirected graph.
+///
+/// The local clustering coefficient $C$ of a vertex $X$ in an undirected graph $G$ is defined as:
+///
+/// $$ C(X) = \frac{2 T(X)}{d(X) (d(X) - 1)} $$
+///
+/// with $T(X)$ the number of [triangles][`triangles`] of $X$ and $d(X)$ its degree, as in Watts and Strogatz[^1],
+/// i.e. the fraction of pairs of its neighbors that are adjacent. Vertices with less than two neighbors have
+/// coefficient zero. Self-edges are ignored.
+///
+/// [^1]: [Watts, D. J., & Strogatz, S. H. (1998). Collective dynamics of 'small-world' networks. Nature, 393(6684), 440-442.](https://scholar.google.com/scholar?q=Collective+dynamics+of+small-world+networks)
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build two triangles sharing an edge.
+/// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)]);
+///
+/// // Compute the local clustering coefficients.
+/// let c = clustering(&g);
+///
+/// // The neighbors of `0` are adjacent ...
+/// assert_eq!(c[&0], 1.);
+/// // ... while two out of three pairs of neighbors of `1` are.
+/// assert_eq!(c[&1], 2. / 3.);
+/// ```
+///
+pub fn clustering<T>(g: &T) -> HashMap<&T::Vertex, f64>
+where
+    T: Undirected,
+{
+    triangles(g)
+        .into_iter()
+        .map(|(x, t)| {
+            // Get the degree, skipping self-edges.
+            let d = Ne!(g, x).filter(|&y| y != x).count();
+            // Compute the fraction of adjacent pairs of neighbors.
+            let c = match d {
+                0 | 1 => 0.,
+                _ => (2 * t) as f64 / (d * (d - 1)) as f64,
+            };
+
+            (x, c)
+        })
+        .collect()
+}
+
+/// Average clustering coefficient of an undirected graph.
+///
+/// The average clustering coefficient of an undirected graph $G$ is defined as the mean of
+/// its [local clustering coefficients][`clustering`]. The null graph has coefficient zero.
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a triangle with a pendant vertex.
+/// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (2, 3)]);
+///
+/// // Compute the average clustering coefficient.
+/// assert_eq!(average_clustering(&g), (1. + 1. + 1. / 3. + 0.) / 4.);
+/// ```
+///
+pub fn average_clustering<T>(g: &T) -> f64
+where
+    T: Undirected,
+{
+    // Sort the coefficients, so that the sum is deterministic.
+    let mut c: Vec<_> = clustering(g).into_values().collect();
+    c.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    match c.len() {
+        0 => 0.,
+        n => c.into_iter().sum::<f64>() / n as f64,
+    }
+}
+
+/// Transitivity of an undirected graph.
+///
+/// The transitivity $T$ of an undirected graph $G$ is defined as:
+///
+/// $$ T = \frac{3 \times \text{triangles}}{\text{connected triples}} $$
+///
+/// i.e. the fraction of paths of length two whose endpoints are adjacent. Graphs without
+/// paths of length two have transitivity zero. Self-edges are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a triangle with a pendant vertex.
+/// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (2, 3)]);
+///
+/// // There is one triangle and five paths of length two.
+/// assert_eq!(transitivity(&g), 3. / 5.);
+/// ```
+///
+pub fn transitivity<T>(g: &T) -> f64
+where
+    T: Undirected,
+{
+    // Count the triangles, once for each vertex.
+    let t: usize = triangles(g).into_values().sum();
+    // Count the paths of length two, once for each central vertex.
+    let p: usize = V!(g)
+        .map(|x| Ne!(g, x).filter(|&y| y != x).count())
+        .map(|d| d * d.saturating_sub(1) / 2)
+        .sum();
+
+    match p {
+        0 => 0.,
+        _ => t as f64 / p as f64,
+    }
+}
+
+/// Directed clustering coefficient of a directed graph.
+///
+/// The directed clustering coefficient $C$ of a vertex $X$ in a directed graph $G$ is defined as:
+///
+/// $$ C(X) = \frac{T(X)}{T_{\max}(X)} $$
+///
+/// with $T(X)$ the number of [directed triangles][`directed_triangles`] of $X$ matching the given pattern and
+/// $T_{\max}(X)$ the number of such triangles if every pair of vertices adjacent to $X$ was connected in both
+/// directions, as in Fagiolo[^1]. That is, given the in-degree $d^-$, the out-degree $d^+$, the total degree
+/// $d = d^- + d^+$ and the number of reciprocal edges $d^{\leftrightarrow}$ of $X$:
+///
+/// | Pattern | $T_{\max}(X)$ |
+/// |---|---|
+/// | [`Total`](TrianglePattern::Total) | $d (d - 1) - 2 d^{\leftrightarrow}$ |
+/// | [`Cycle`](TrianglePattern::Cycle) | $d^- d^+ - d^{\leftrightarrow}$ |
+/// | [`Middleman`](TrianglePattern::Middleman) | $d^- d^+ - d^{\leftrightarrow}$ |
+/// | [`In`](TrianglePattern::In) | $d^- (d^- - 1)$ |
+/// | [`Out`](TrianglePattern::Out) | $d^+ (d^+ - 1)$ |
+///
+/// Vertices without such triangles in the complete graph have coefficient zero. Self-edges are ignored.
+///
+/// [^1]: [Fagiolo, G. (2007). Clustering in complex directed networks. Physical Review E, 76(2), 026107.](https://scholar.google.com/scholar?q=Clustering+in+complex+directed+networks)
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a transitive triangle.
+/// let g = DiGraph::from_edges([(0, 1), (0, 2), (1, 2)]);
+///
+/// // Compute the directed clustering coefficients.
+/// let c = directed_clustering(&g, TrianglePattern::Total);
+///
+/// // Each vertex has one triangle out of the six with reciprocal edges.
+/// assert_eq!(c[&0], 1. / 2.);
+///
+/// // The source vertex is an out-triangle, whose out-degree is two.
+/// let c = directed_clustering(&g, TrianglePattern::Out);
+/// assert_eq!(c[&0], 1. / 2.);
+/// ```
+///
+pub fn directed_clustering<T>(g: &T, p: TrianglePattern) -> HashMap<&T::Vertex, f64>
+where
+    T: Directed,
+{
+    directed_triangles(g, p)
+        .into_iter()
+        .map(|(x, t)| {
+            // Get the children and the parents, skipping self-edges.
+            let ch: HashSet<_> = Ch!(g, x).filter(|&y| y != x).collect();
+            let pa: HashSet<_> = Pa!(g, x).filter(|&y| y != x).collect();
+            // Get the in-degree, out-degree, total degree and number of reciprocal edges.
+            let (i, o, r) = (pa.len(), ch.len(), ch.intersection(&pa).count());
+            let d = i + o;
+            // Compute the number of triangles with reciprocal edges.
+            let m = match p {
+                TrianglePattern::Total => d * d.saturating_sub(1) - 2 * r,
+                TrianglePattern::Cycle | TrianglePattern::Middleman => i * o - r,
+                TrianglePattern::In => i * i.saturating_sub(1),
+                TrianglePattern::Out => o * o.saturating_sub(1),
+            };
+            // Compute the fraction of matching triangles.
+            let c = match m {
+                0 => 0.,
+                _ => t as f64 / m as f64,
+            };
+
+            (x, c)
+        })
+        .collect()
+}
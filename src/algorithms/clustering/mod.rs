@@ -0,0 +1,25 @@
+/// Directed triangle pattern enumerator.
+///
+/// Each variant classifies the directed triangles around a vertex $X$, as in Fagiolo[^1].
+///
+/// [^1]: [Fagiolo, G. (2007). Clustering in complex directed networks. Physical Review E, 76(2), 026107.](https://scholar.google.com/scholar?q=Clustering+in+complex+directed+networks)
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrianglePattern {
+    /// Total variant, i.e. any of the following patterns.
+    Total,
+    /// Cycle variant, i.e. $X \rightarrow Y \rightarrow Z \rightarrow X$.
+    Cycle,
+    /// Middleman variant, i.e. $X \rightarrow Y$, $Z \rightarrow Y$ and $Z \rightarrow X$.
+    Middleman,
+    /// In variant, i.e. $Y \rightarrow X$, $Z \rightarrow X$ and $Y \rightarrow Z$.
+    In,
+    /// Out variant, i.e. $X \rightarrow Y$, $X \rightarrow Z$ and $Y \rightarrow Z$.
+    Out,
+}
+
+mod clustering_coefficient;
+pub use clustering_coefficient::{average_clustering, clustering, directed_clustering, transitivity};
+
+mod triangles;
+pub use triangles::{directed_triangles, total_triangles, triangles};
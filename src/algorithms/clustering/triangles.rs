@@ -0,0 +1,128 @@
+use super::TrianglePattern;
+use crate::traits::{Directed, Undirected};
+use crate::{Ch, Ne, Pa, V};
+use std::collections::{HashMap, HashSet};
+
+/// Triangles of an undirected graph.
+///
+/// Counts the triangles each vertex belongs to, i.e. the pairs of its neighbors that are adjacent.
+/// Self-edges are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build two triangles sharing an edge.
+/// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)]);
+///
+/// // Count the triangles of each vertex.
+/// let t = triangles(&g);
+///
+/// // The shared edge belongs to both triangles.
+/// assert_eq!(t[&0], 1);
+/// assert_eq!(t[&1], 2);
+/// ```
+///
+pub fn triangles<T>(g: &T) -> HashMap<&T::Vertex, usize>
+where
+    T: Undirected,
+{
+    // Get the neighbors of each vertex, skipping self-edges.
+    let neighbors: HashMap<_, HashSet<_>> = V!(g).map(|x| (x, Ne!(g, x).filter(|&y| y != x).collect())).collect();
+
+    neighbors
+        .iter()
+        .map(|(&x, n)| {
+            // Count the adjacent pairs of neighbors, each once.
+            let t = n
+                .iter()
+                .map(|&y| neighbors[y].iter().filter(|&z| y < *z && n.contains(z)).count())
+                .sum();
+
+            (x, t)
+        })
+        .collect()
+}
+
+/// Total triangles of an undirected graph.
+///
+/// Counts the triangles of the graph, i.e. the sets of three pairwise adjacent vertices.
+/// Self-edges are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a complete graph over four vertices.
+/// let g = Graph::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+///
+/// // Each triple of vertices is a triangle.
+/// assert_eq!(total_triangles(&g), 4);
+/// ```
+///
+pub fn total_triangles<T>(g: &T) -> usize
+where
+    T: Undirected,
+{
+    // Each triangle is counted once for each of its vertices.
+    triangles(g).values().sum::<usize>() / 3
+}
+
+/// Directed triangles of a directed graph.
+///
+/// Counts the directed triangles each vertex belongs to, according to the given pattern, as in Fagiolo[^1].
+/// Triangles are counted with multiplicity, i.e. once for each combination of edges, so that a triangle with
+/// reciprocal edges is counted more than once. Self-edges are ignored.
+///
+/// [^1]: [Fagiolo, G. (2007). Clustering in complex directed networks. Physical Review E, 76(2), 026107.](https://scholar.google.com/scholar?q=Clustering+in+complex+directed+networks)
+///
+/// # Examples
+///
+/// ```
+/// use grathe::prelude::*;
+///
+/// // Build a directed cycle.
+/// let g = DiGraph::from_edges([(0, 1), (1, 2), (2, 0)]);
+///
+/// // Count the directed triangles of each vertex.
+/// let t = directed_triangles(&g, TrianglePattern::Cycle);
+/// assert_eq!(t[&0], 1);
+///
+/// // There are no other patterns.
+/// let t = directed_triangles(&g, TrianglePattern::Out);
+/// assert_eq!(t[&0], 0);
+/// ```
+///
+pub fn directed_triangles<T>(g: &T, p: TrianglePattern) -> HashMap<&T::Vertex, usize>
+where
+    T: Directed,
+{
+    // Get the children of each vertex, skipping self-edges.
+    let children: HashMap<_, HashSet<_>> = V!(g).map(|x| (x, Ch!(g, x).filter(|&y| y != x).collect())).collect();
+    // Get the parents of each vertex, skipping self-edges.
+    let parents: HashMap<_, HashSet<_>> = V!(g).map(|x| (x, Pa!(g, x).filter(|&y| y != x).collect())).collect();
+    // Count the pairs (Y, Z) of distinct vertices in given sets, such that Y -> Z.
+    let count = |a: &HashSet<_>, b: &HashSet<_>| a.iter().map(|y| children[y].intersection(b).count()).sum();
+
+    V!(g)
+        .map(|x| {
+            let (pa, ch) = (&parents[x], &children[x]);
+            // Count the triangles matching the given pattern.
+            let t = match p {
+                TrianglePattern::Total => count(ch, pa) + count(pa, ch) + count(pa, pa) + count(ch, ch),
+                // X -> Y -> Z -> X.
+                TrianglePattern::Cycle => count(ch, pa),
+                // Z -> X -> Y, with Z -> Y.
+                TrianglePattern::Middleman => count(pa, ch),
+                // Y -> X, Z -> X, with Y -> Z.
+                TrianglePattern::In => count(pa, pa),
+                // X -> Y, X -> Z, with Y -> Z.
+                TrianglePattern::Out => count(ch, ch),
+            };
+
+            (x, t)
+        })
+        .collect()
+}
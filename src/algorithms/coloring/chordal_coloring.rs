@@ -0,0 +1,99 @@
+use super::greedy_coloring::first_fit;
+use crate::traits::Undirected;
+use crate::types::Error;
+use crate::Ne;
+use std::collections::{BTreeSet, HashMap};
+
+/// Chordal coloring structure.
+///
+/// This structure contains an optimal `coloring` of each vertex and the number of `colors` used.
+///
+pub struct ChordalColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Color of each vertex, in $[0, colors)$.
+    pub coloring: HashMap<&'a T::Vertex, usize>,
+    /// Number of colors used, i.e. the chromatic number.
+    pub colors: usize,
+}
+
+impl<'a, T> ChordalColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Build a chordal coloring structure.
+    ///
+    /// Build a chordal coloring structure, which colors a chordal graph optimally by greedily coloring
+    /// its vertices in the reverse of a [perfect elimination ordering](Undirected::perfect_elimination_ordering)[^1].
+    /// Since each vertex is colored after its later neighbors, which form a clique, the number of colors used
+    /// equals the size of the largest clique. Self-edges are ignored.
+    ///
+    /// [^1]: [Gavril, F. (1972). Algorithms for minimum coloring, maximum clique, minimum covering by cliques, and maximum independent set of a chordal graph. SIAM Journal on Computing, 1(2), 180-187.](https://scholar.google.com/scholar?q=Algorithms+for+minimum+coloring%2C+maximum+clique%2C+minimum+covering+by+cliques%2C+and+maximum+independent+set+of+a+chordal+graph)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build two triangles sharing an edge, with a pendant vertex.
+    /// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = ChordalColoring::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run().unwrap();
+    ///
+    /// // The largest clique is a triangle.
+    /// assert_eq!(search.colors, 3);
+    /// assert!(E!(g).all(|(x, y)| search.coloring[x] != search.coloring[y]));
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the coloring.
+            coloring: Default::default(),
+            // Initialize the number of colors.
+            colors: 0,
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    /// # Errors
+    ///
+    /// The graph is not chordal.
+    ///
+    pub fn run(&mut self) -> Result<&Self, Error<T::Vertex>> {
+        // Compute the perfect elimination ordering, if any.
+        let order = self.graph.perfect_elimination_ordering().ok_or(Error::NotChordal())?;
+        // Color the vertices in reverse order.
+        for x in order.into_iter().rev() {
+            let neighbors: BTreeSet<_> = Ne!(self.graph, x).filter(|&y| y != x).collect();
+            let c = first_fit(&neighbors, &self.coloring);
+            self.coloring.insert(x, c);
+        }
+        // Count the colors used.
+        self.colors = self.coloring.values().max().map_or(0, |c| c + 1);
+
+        Ok(self)
+    }
+}
+
+impl<'a, T> From<&'a T> for ChordalColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
@@ -0,0 +1,154 @@
+use super::greedy_coloring::first_fit;
+use super::{ColoringOrder, GreedyColoring};
+use crate::traits::Undirected;
+use crate::{Ne, V};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use std::vec::Vec;
+
+/// Exact coloring structure.
+///
+/// This structure contains an optimal `coloring` of each vertex and the `chromatic_number` of the graph.
+///
+pub struct ExactColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Neighbors of each vertex, without self-edges.
+    neighbors: HashMap<&'a T::Vertex, BTreeSet<&'a T::Vertex>>,
+    /// Lower bound on the chromatic number, i.e. the size of a clique.
+    lower_bound: usize,
+    /// Color of each vertex, in $[0, chromatic\_number)$.
+    pub coloring: HashMap<&'a T::Vertex, usize>,
+    /// Minimum number of colors.
+    pub chromatic_number: usize,
+}
+
+impl<'a, T> ExactColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Build an exact coloring structure.
+    ///
+    /// Build an exact coloring structure following a DSATUR-based branch-and-bound[^1] search, where the upper bound
+    /// is initialized with a [`DSatur`](ColoringOrder::DSatur) greedy coloring and the lower bound with a greedy clique.
+    /// The search takes exponential time in the worst case, hence it is suitable for small graphs only.
+    /// Self-edges are ignored.
+    ///
+    /// [^1]: [Brélaz, D. (1979). New methods to color the vertices of a graph. Communications of the ACM, 22(4), 251-256.](https://scholar.google.com/scholar?q=New+methods+to+color+the+vertices+of+a+graph)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a wheel with an odd rim.
+    /// let g = Graph::from_edges([
+    ///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+    ///     (5, 0), (5, 1), (5, 2), (5, 3), (5, 4)
+    /// ]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = ExactColoring::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The odd rim requires three colors and the hub a fourth one.
+    /// assert_eq!(search.chromatic_number, 4);
+    /// assert!(E!(g).all(|(x, y)| search.coloring[x] != search.coloring[y]));
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the neighbors map.
+            neighbors: Default::default(),
+            // Initialize the lower bound.
+            lower_bound: 0,
+            // Initialize the coloring.
+            coloring: Default::default(),
+            // Initialize the chromatic number.
+            chromatic_number: 0,
+        }
+    }
+
+    fn branch(&mut self, coloring: &mut HashMap<&'a T::Vertex, usize>, colors: usize) {
+        // If the current coloring cannot improve the best one, or the best one is optimal, then backtrack.
+        if colors >= self.chromatic_number || self.chromatic_number == self.lower_bound {
+            return;
+        }
+        // Select the uncolored vertex with maximum saturation, then maximum degree.
+        let x = V!(self.graph).filter(|x| !coloring.contains_key(x)).min_by_key(|x| {
+            let saturation: BTreeSet<_> = self.neighbors[x].iter().filter_map(|y| coloring.get(y)).collect();
+            (Reverse(saturation.len()), Reverse(self.neighbors[x].len()))
+        });
+        // If every vertex is colored, then a better coloring is found.
+        let x = match x {
+            Some(x) => x,
+            None => {
+                self.coloring = coloring.clone();
+                self.chromatic_number = colors;
+                return;
+            }
+        };
+        // Try each used color that is not in the neighborhood, then a new color.
+        for c in 0..=colors {
+            if self.neighbors[x].iter().any(|y| coloring.get(y) == Some(&c)) {
+                continue;
+            }
+            coloring.insert(x, c);
+            self.branch(coloring, colors.max(c + 1));
+            coloring.remove(x);
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Compute the neighbors of each vertex, skipping self-edges.
+        self.neighbors = V!(self.graph)
+            .map(|x| (x, Ne!(self.graph, x).filter(|&y| y != x).collect()))
+            .collect();
+        // Initialize the best coloring with a greedy one.
+        let mut greedy = GreedyColoring::new(self.graph, ColoringOrder::DSatur);
+        greedy.run();
+        self.coloring = greedy.coloring.clone();
+        self.chromatic_number = greedy.colors;
+        // Compute a lower bound with a greedy clique, adding vertices by non-increasing degree.
+        let mut order: Vec<_> = V!(self.graph).collect();
+        order.sort_by_key(|x| Reverse(self.neighbors[x].len()));
+        let mut clique: BTreeSet<&T::Vertex> = BTreeSet::new();
+        for x in order {
+            if clique.iter().all(|y| self.neighbors[x].contains(y)) {
+                clique.insert(x);
+            }
+        }
+        self.lower_bound = clique.len();
+        // Color the clique with distinct colors, which does not lose generality, then branch.
+        let mut coloring = HashMap::new();
+        for x in clique {
+            let c = first_fit(&self.neighbors[x], &coloring);
+            coloring.insert(x, c);
+        }
+        self.branch(&mut coloring, self.lower_bound);
+
+        self
+    }
+}
+
+impl<'a, T> From<&'a T> for ExactColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
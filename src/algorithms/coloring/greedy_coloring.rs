@@ -0,0 +1,182 @@
+use super::ColoringOrder;
+use crate::traits::Undirected;
+use crate::{Ne, V};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::vec::Vec;
+
+/// Greedy coloring structure.
+///
+/// This structure contains the `coloring` of each vertex and the number of `colors` used.
+///
+pub struct GreedyColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Order in which vertices are colored.
+    order: ColoringOrder,
+    /// Color of each vertex, in $[0, colors)$.
+    pub coloring: HashMap<&'a T::Vertex, usize>,
+    /// Number of colors used.
+    pub colors: usize,
+}
+
+impl<'a, T> GreedyColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Build a greedy coloring structure.
+    ///
+    /// Build a greedy coloring structure, where each vertex is assigned the smallest color that is not
+    /// used by its neighbors, visiting the vertices in the given order:
+    ///
+    /// - [`LargestFirst`](ColoringOrder::LargestFirst), following Welsh and Powell[^1],
+    /// - [`SmallestLast`](ColoringOrder::SmallestLast), following Matula and Beck[^2],
+    /// - [`DSatur`](ColoringOrder::DSatur), following Brélaz[^3].
+    ///
+    /// Ties are broken by vertex order. Self-edges are ignored.
+    ///
+    /// [^1]: [Welsh, D. J., & Powell, M. B. (1967). An upper bound for the chromatic number of a graph and its application to timetabling problems. The Computer Journal, 10(1), 85-86.](https://scholar.google.com/scholar?q=An+upper+bound+for+the+chromatic+number+of+a+graph+and+its+application+to+timetabling+problems)
+    /// [^2]: [Matula, D. W., & Beck, L. L. (1983). Smallest-last ordering and clustering and graph coloring algorithms. Journal of the ACM, 30(3), 417-427.](https://scholar.google.com/scholar?q=Smallest-last+ordering+and+clustering+and+graph+coloring+algorithms)
+    /// [^3]: [Brélaz, D. (1979). New methods to color the vertices of a graph. Communications of the ACM, 22(4), 251-256.](https://scholar.google.com/scholar?q=New+methods+to+color+the+vertices+of+a+graph)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a crown graph, where the vertex order is adversarial for a naive greedy coloring.
+    /// let g = Graph::from_edges([(0, 3), (0, 5), (1, 2), (1, 4), (2, 5), (3, 4)]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = GreedyColoring::from((&g, ColoringOrder::DSatur));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The cycle is bipartite, hence two colors are enough.
+    /// assert_eq!(search.colors, 2);
+    /// assert_eq!(
+    ///     search.coloring,
+    ///     HashMap::from([(&0, 0), (&1, 1), (&2, 0), (&3, 1), (&4, 0), (&5, 1)])
+    /// );
+    /// ```
+    ///
+    pub fn new(g: &'a T, order: ColoringOrder) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set coloring order.
+            order,
+            // Initialize the coloring.
+            coloring: Default::default(),
+            // Initialize the number of colors.
+            colors: 0,
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Compute the neighbors of each vertex, skipping self-edges.
+        let neighbors: HashMap<_, BTreeSet<_>> = V!(self.graph)
+            .map(|x| (x, Ne!(self.graph, x).filter(|&y| y != x).collect()))
+            .collect();
+        match self.order {
+            ColoringOrder::LargestFirst => {
+                // Sort the vertices by non-increasing degree, the sort is stable.
+                let mut order: Vec<_> = V!(self.graph).collect();
+                order.sort_by_key(|x| Reverse(neighbors[x].len()));
+                // Color the vertices in order.
+                for x in order {
+                    let c = first_fit(&neighbors[x], &self.coloring);
+                    self.coloring.insert(x, c);
+                }
+            }
+            ColoringOrder::SmallestLast => {
+                // Initialize the degree of each vertex in the remaining graph.
+                let mut degree: HashMap<_, _> = neighbors.iter().map(|(&x, n)| (x, n.len())).collect();
+                // Repeatedly remove a vertex with minimum degree.
+                let mut order = Vec::with_capacity(degree.len());
+                while let Some(x) = V!(self.graph)
+                    .filter(|x| degree.contains_key(x))
+                    .min_by_key(|x| degree[x])
+                {
+                    degree.remove(x);
+                    for y in neighbors[x].iter() {
+                        if let Some(d) = degree.get_mut(y) {
+                            *d -= 1;
+                        }
+                    }
+                    order.push(x);
+                }
+                // Color the vertices in reverse removal order.
+                for x in order.into_iter().rev() {
+                    let c = first_fit(&neighbors[x], &self.coloring);
+                    self.coloring.insert(x, c);
+                }
+            }
+            ColoringOrder::DSatur => {
+                // Initialize the colors in the neighborhood of each vertex.
+                let mut saturation: HashMap<_, HashSet<usize>> = V!(self.graph).map(|x| (x, HashSet::new())).collect();
+                // Initialize the degree of each vertex in the uncolored graph.
+                let mut degree: HashMap<_, _> = neighbors.iter().map(|(&x, n)| (x, n.len())).collect();
+                // Select the uncolored vertex with maximum saturation, then maximum uncolored degree.
+                while let Some(x) = V!(self.graph)
+                    .filter(|x| !self.coloring.contains_key(x))
+                    .min_by_key(|x| (Reverse(saturation[x].len()), Reverse(degree[x])))
+                {
+                    let c = first_fit(&neighbors[x], &self.coloring);
+                    self.coloring.insert(x, c);
+                    // Update the saturation and degree of its neighbors.
+                    for y in neighbors[x].iter() {
+                        saturation.get_mut(y).unwrap().insert(c);
+                        *degree.get_mut(y).unwrap() -= 1;
+                    }
+                }
+            }
+        }
+        // Count the colors used.
+        self.colors = self.coloring.values().max().map_or(0, |c| c + 1);
+
+        self
+    }
+}
+
+/// Smallest color not used by any colored neighbor.
+pub(crate) fn first_fit<'a, V>(neighbors: &BTreeSet<&'a V>, coloring: &HashMap<&'a V, usize>) -> usize
+where
+    V: Eq + std::hash::Hash,
+{
+    // Collect the colors of the neighbors.
+    let used: HashSet<_> = neighbors.iter().filter_map(|y| coloring.get(y)).collect();
+    // Get the smallest color not in the set.
+    (0..).find(|c| !used.contains(c)).unwrap()
+}
+
+impl<'a, T> From<&'a T> for GreedyColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph, with the [`DSatur`](ColoringOrder::DSatur) order.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g, ColoringOrder::DSatur)
+    }
+}
+
+impl<'a, T> From<(&'a T, ColoringOrder)> for GreedyColoring<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph and a coloring order.
+    ///
+    fn from((g, order): (&'a T, ColoringOrder)) -> Self {
+        Self::new(g, order)
+    }
+}
@@ -0,0 +1,18 @@
+/// Coloring order enumerator.
+pub enum ColoringOrder {
+    /// Largest-first variant, i.e. vertices are colored by non-increasing degree.
+    LargestFirst,
+    /// Smallest-last variant, i.e. vertices are colored in the reverse order of repeated minimum degree removal.
+    SmallestLast,
+    /// DSATUR variant, i.e. the vertex with the most distinct colors in its neighborhood is colored next.
+    DSatur,
+}
+
+mod chordal_coloring;
+pub use chordal_coloring::ChordalColoring;
+
+mod exact_coloring;
+pub use exact_coloring::ExactColoring;
+
+mod greedy_coloring;
+pub use greedy_coloring::GreedyColoring;
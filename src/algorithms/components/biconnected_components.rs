@@ -0,0 +1,202 @@
+use crate::algorithms::{Traversal, DFS};
+use crate::graphs::Graph;
+use crate::traits::{From as _, Storage};
+use crate::types::VertexIterator;
+use std::collections::{BTreeSet, HashMap};
+use std::vec::Vec;
+
+/// Biconnected components search structure.
+///
+/// A biconnected component (or *block*) is a maximal subgraph that remains connected after
+/// the removal of any one of its vertices. Blocks overlap in the articulation points
+/// (or *cut vertices*), whose removal increases the number of connected components.
+/// Likewise, a bridge is an edge whose removal increases the number of connected components.
+///
+pub struct BiconnectedComponents<'a, T>
+where
+    T: Storage,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Reachable vertices of distance one from given vertex.
+    reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Set of articulation points.
+    pub articulation_points: BTreeSet<&'a T::Vertex>,
+    /// Set of bridges, with the lowest endpoint first.
+    pub bridges: BTreeSet<(&'a T::Vertex, &'a T::Vertex)>,
+    /// Vector of biconnected components, with at least one edge, in discovery order.
+    pub components: Vec<BTreeSet<&'a T::Vertex>>,
+}
+
+impl<'a, T> BiconnectedComponents<'a, T>
+where
+    T: Storage,
+{
+    /// Build a *biconnected components* search structure.
+    ///
+    /// Build a *biconnected components* search structure following the Hopcroft-Tarjan[^1] algorithm,
+    /// which computes the lowest discovery time reachable from each vertex on top of a depth-first search.
+    ///
+    /// [^1]: [Hopcroft, J., & Tarjan, R. (1973). Algorithm 447: efficient algorithms for graph manipulation. Communications of the ACM, 16(6), 372-378.](https://scholar.google.com/scholar?q=Algorithm+447%3A+efficient+algorithms+for+graph+manipulation)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build an undirected graph, i.e. two triangles joined by a path.
+    /// let g = Graph::from_edges([
+    ///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 6), (6, 4)
+    /// ]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = BiconnectedComponents::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The vertices of the path are articulation points ...
+    /// assert_eq!(search.articulation_points, BTreeSet::from([&2, &3, &4]));
+    /// // ... and the edges of the path are bridges.
+    /// assert_eq!(search.bridges, BTreeSet::from([(&2, &3), (&3, &4)]));
+    ///
+    /// // Each triangle and each bridge is a block.
+    /// assert_eq!(
+    ///     search.components,
+    ///     [
+    ///         BTreeSet::from([&0, &1, &2]),
+    ///         BTreeSet::from([&2, &3]),
+    ///         BTreeSet::from([&3, &4]),
+    ///         BTreeSet::from([&4, &5, &6]),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    pub fn new(g: &'a T, f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set reachability function.
+            reachable: f,
+            // Initialize the set of articulation points.
+            articulation_points: Default::default(),
+            // Initialize the set of bridges.
+            bridges: Default::default(),
+            // Initialize the vector of biconnected components.
+            components: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Visit the graph in depth-first order.
+        let mut search = DFS::new(self.graph, None, self.reachable, Traversal::Forest);
+        // Collect the visit order, preserving the search structure.
+        let order: Vec<_> = search.by_ref().collect();
+        // Get the discovery time and the predecessor of each vertex.
+        let (discovery_time, predecessor) = (search.discovery_time, search.predecessor);
+        // Initialize the lowest discovery time reachable from each vertex.
+        let mut low: HashMap<&'a T::Vertex, usize> = Default::default();
+        // For each vertex, in reverse pre-order, i.e. any vertex after its descendants.
+        for &x in order.iter().rev() {
+            // Initialize the lowest discovery time with the one of the vertex.
+            let mut l = discovery_time[x];
+            // For each vertex reachable from the current one.
+            for y in (self.reachable)(self.graph, x) {
+                l = match predecessor.get(y) {
+                    // If it is a child in the DFS tree, inherit its lowest discovery time.
+                    Some(&z) if z == x => usize::min(l, low[y]),
+                    // Otherwise, it is a back edge (or the tree edge to the parent),
+                    // skip the latter only.
+                    _ if predecessor.get(x) == Some(&y) => l,
+                    _ => usize::min(l, discovery_time[y]),
+                };
+            }
+            low.insert(x, l);
+        }
+        // Initialize the number of children of each root vertex.
+        let mut roots: HashMap<&'a T::Vertex, usize> = Default::default();
+        // Initialize the block index of each non-root vertex.
+        let mut block: HashMap<&'a T::Vertex, usize> = Default::default();
+        // For each vertex, in pre-order, i.e. any vertex after its ancestors.
+        for &x in order.iter() {
+            // Get its parent, if any.
+            let y = match predecessor.get(x) {
+                Some(&y) => y,
+                None => continue,
+            };
+            // If the parent is a root, count its children.
+            if !predecessor.contains_key(y) {
+                *roots.entry(y).or_default() += 1;
+            }
+            // If the vertex cannot reach any proper ancestor of its parent ...
+            if low[x] >= discovery_time[y] {
+                // ... then the parent separates a new block, containing the tree edge.
+                block.insert(x, self.components.len());
+                self.components.push(BTreeSet::from([y, x]));
+                // A non-root parent is an articulation point.
+                if predecessor.contains_key(y) {
+                    self.articulation_points.insert(y);
+                }
+                // If the vertex cannot reach its parent either, the tree edge is a bridge.
+                if low[x] > discovery_time[y] {
+                    self.bridges.insert(if x < y { (x, y) } else { (y, x) });
+                }
+            // Otherwise, the vertex is in the same block of its parent.
+            } else {
+                let i = block[y];
+                block.insert(x, i);
+                self.components[i].insert(x);
+            }
+        }
+        // A root is an articulation point if it has more than one child.
+        self.articulation_points
+            .extend(roots.into_iter().filter(|&(_, n)| n > 1).map(|(x, _)| x));
+
+        self
+    }
+
+    /// Build the block-cut tree.
+    ///
+    /// Build the block-cut tree, that is the bipartite forest where each block is adjacent to the
+    /// articulation points it contains. The $i$-th vertex of the block-cut tree represents the
+    /// $i$-th block in `components`, while the $(b + j)$-th vertex represents the $j$-th
+    /// articulation point in `articulation_points`, where $b$ is the number of blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build an undirected graph, i.e. two triangles sharing a vertex.
+    /// let g = Graph::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+    ///
+    /// // Run the algorithm over said graph.
+    /// let mut search = BiconnectedComponents::from(&g);
+    /// search.run();
+    ///
+    /// // The shared vertex, i.e. `2`, is adjacent to both blocks.
+    /// assert_eq!(search.block_cut_tree(), Graph::from_edges([(0, 2), (1, 2)]));
+    /// ```
+    ///
+    pub fn block_cut_tree(&self) -> Graph {
+        // Get the number of blocks.
+        let b = self.components.len();
+        // Add a vertex for each block and for each articulation point.
+        let mut g = Graph::from_vertices(0..(b + self.articulation_points.len()) as i32);
+        // For each articulation point, add an edge to each block containing it.
+        for (j, x) in self.articulation_points.iter().enumerate() {
+            for (i, c) in self.components.iter().enumerate() {
+                if c.contains(x) {
+                    g.add_edge(&(i as i32), &((b + j) as i32)).ok();
+                }
+            }
+        }
+
+        g
+    }
+}
@@ -0,0 +1,121 @@
+use crate::traits::Storage;
+use crate::utils::DisjointSet;
+use crate::V;
+use std::collections::{BTreeSet, HashMap};
+use std::vec::Vec;
+
+/// Connected components search structure.
+///
+/// A connected component is a maximal set of vertices such that there is a path
+/// between any pair of vertices in it. For directed graphs, the direction of the
+/// edges is ignored, i.e. the *weakly* connected components are computed.
+///
+pub struct ConnectedComponents<'a, T>
+where
+    T: Storage,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Vector of connected components, sorted by their lowest vertex.
+    pub components: Vec<BTreeSet<&'a T::Vertex>>,
+    /// Index of the component of each vertex.
+    pub component: HashMap<&'a T::Vertex, usize>,
+}
+
+impl<'a, T> ConnectedComponents<'a, T>
+where
+    T: Storage,
+{
+    /// Build a *connected components* search structure.
+    ///
+    /// Build a *connected components* search structure based on a disjoint-set forest[^1],
+    /// where the edges of the graph are used to merge the sets.
+    ///
+    /// [^1]: [Tarjan, R. E. (1975). Efficiency of a good but not linear set union algorithm. Journal of the ACM, 22(2), 215-225.](https://scholar.google.com/scholar?q=Efficiency+of+a+good+but+not+linear+set+union+algorithm)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a directed graph.
+    /// let mut g = DiGraph::from_edges([(1, 0), (1, 2), (3, 4)]);
+    /// g.add_vertex(5).unwrap();
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = ConnectedComponents::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // Components are sorted by their lowest vertex,
+    /// // ignoring the direction of the edges.
+    /// assert_eq!(
+    ///     search.components,
+    ///     [
+    ///         BTreeSet::from([&0, &1, &2]),
+    ///         BTreeSet::from([&3, &4]),
+    ///         BTreeSet::from([&5]),
+    ///     ]
+    /// );
+    ///
+    /// // Each vertex is mapped to the index of its component.
+    /// assert_eq!(search.component[&2], 0);
+    /// assert_eq!(search.component[&5], 2);
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the vector of components.
+            components: Default::default(),
+            // Initialize the component map.
+            component: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = V!(self.graph).enumerate().map(|(i, x)| (x, i)).collect();
+        // Initialize each vertex as a singleton set.
+        let mut sets = DisjointSet::new(index.len());
+        // For each edge in the graph, merge the sets of its endpoints.
+        for (x, y) in self.graph.edges_iter() {
+            sets.union(index[x], index[y]);
+        }
+        // Map the root of each set to the index of its component.
+        let mut roots = HashMap::new();
+        // For each vertex in the graph, in order.
+        for (i, x) in V!(self.graph).enumerate() {
+            // Get the root of its set.
+            let j = sets.find(i);
+            // If the root has not been labelled yet, then the vertex is the lowest of a new component.
+            let k = *roots.entry(j).or_insert_with(|| {
+                self.components.push(Default::default());
+                self.components.len() - 1
+            });
+            // Label the vertex and add it to its component.
+            self.component.insert(x, k);
+            self.components[k].insert(x);
+        }
+
+        self
+    }
+}
+
+impl<'a, T> From<&'a T> for ConnectedComponents<'a, T>
+where
+    T: Storage,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
@@ -0,0 +1,11 @@
+mod biconnected_components;
+pub use biconnected_components::BiconnectedComponents;
+
+mod connected_components;
+pub use connected_components::ConnectedComponents;
+
+mod strongly_connected_components;
+pub use strongly_connected_components::StronglyConnectedComponents;
+
+/// Alias for strongly connected components.
+pub type SCC<'a, T> = StronglyConnectedComponents<'a, T>;
@@ -0,0 +1,183 @@
+use crate::graphs::DiGraph;
+use crate::traits::{Directed, From as _, Storage};
+use crate::V;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::vec::Vec;
+
+/// Strongly connected components search structure.
+///
+/// A strongly connected component is a maximal set of vertices such that
+/// there is a directed path between any (ordered) pair of vertices in it.
+///
+pub struct StronglyConnectedComponents<'a, T>
+where
+    T: Directed,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Vector of strongly connected components, in topological order.
+    pub components: Vec<BTreeSet<&'a T::Vertex>>,
+    /// Index of the component of each vertex.
+    pub component: HashMap<&'a T::Vertex, usize>,
+}
+
+impl<'a, T> StronglyConnectedComponents<'a, T>
+where
+    T: Directed,
+{
+    /// Build a *strongly connected components* search structure.
+    ///
+    /// Build a *strongly connected components* search structure following the Tarjan[^1] algorithm.
+    ///
+    /// [^1]: [Tarjan, R. (1972). Depth-first search and linear graph algorithms. SIAM journal on computing, 1(2), 146-160.](https://scholar.google.com/scholar?q=Depth-first+search+and+linear+graph+algorithms)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a directed graph.
+    /// let g = DiGraph::from_edges([
+    ///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)
+    /// ]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = SCC::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // Components are returned in topological order.
+    /// assert_eq!(
+    ///     search.components,
+    ///     [
+    ///         BTreeSet::from([&0, &1, &2]),
+    ///         BTreeSet::from([&3, &4]),
+    ///     ]
+    /// );
+    ///
+    /// // Each vertex is mapped to the index of its component.
+    /// assert_eq!(search.component[&1], 0);
+    /// assert_eq!(search.component[&4], 1);
+    ///
+    /// // The condensation graph is a DAG over the components indices.
+    /// assert_eq!(search.condensation(), DiGraph::from_edges([(0, 1)]));
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the vector of components.
+            components: Default::default(),
+            // Initialize the component map.
+            component: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Initialize the discovery index and the lowest reachable index of each vertex.
+        let (mut index, mut lowlink): (HashMap<_, usize>, HashMap<_, usize>) = Default::default();
+        // Initialize the stack of vertices of the components not yet completed.
+        let (mut stack, mut on_stack): (Vec<_>, HashSet<_>) = Default::default();
+        // For each vertex in the graph ...
+        for x in V!(self.graph) {
+            // ... that was not visited before ...
+            if index.contains_key(x) {
+                continue;
+            }
+            // ... visit its descendants in depth-first order.
+            let mut visit = vec![(x, self.graph.children_iter(x))];
+            // Set the discovery index of the root vertex.
+            index.insert(x, index.len());
+            lowlink.insert(x, index[x]);
+            stack.push(x);
+            on_stack.insert(x);
+            // While there are still vertices to visit.
+            while let Some((y, iter)) = visit.last_mut() {
+                let y = *y;
+                // If there is a child left to explore ...
+                if let Some(z) = iter.next() {
+                    // ... and it was not visited before ...
+                    if !index.contains_key(z) {
+                        // ... set its discovery index and visit it.
+                        index.insert(z, index.len());
+                        lowlink.insert(z, index[z]);
+                        stack.push(z);
+                        on_stack.insert(z);
+                        visit.push((z, self.graph.children_iter(z)));
+                    // Otherwise, if it is in the current stack, update the lowest reachable index.
+                    } else if on_stack.contains(z) {
+                        lowlink.insert(y, usize::min(lowlink[y], index[z]));
+                    }
+                    continue;
+                }
+                // The current vertex is completed, remove it from the visit.
+                visit.pop();
+                // Propagate the lowest reachable index to the parent vertex, if any.
+                if let Some((w, _)) = visit.last() {
+                    lowlink.insert(*w, usize::min(lowlink[*w], lowlink[y]));
+                }
+                // If the current vertex is the root of a component ...
+                if lowlink[y] == index[y] {
+                    // ... pop the component from the stack.
+                    let mut c = BTreeSet::new();
+                    while let Some(z) = stack.pop() {
+                        on_stack.remove(z);
+                        c.insert(z);
+                        if z == y {
+                            break;
+                        }
+                    }
+                    self.components.push(c);
+                }
+            }
+        }
+        // Components are discovered in reverse topological order.
+        self.components.reverse();
+        // Map each vertex to its component.
+        for (i, c) in self.components.iter().enumerate() {
+            for &x in c {
+                self.component.insert(x, i);
+            }
+        }
+
+        self
+    }
+
+    /// Build the condensation graph.
+    ///
+    /// Build the condensation graph, that is the directed acyclic graph obtained by
+    /// contracting each strongly connected component into a single vertex. The $i$-th
+    /// vertex of the condensation graph represents the $i$-th component in `components`.
+    ///
+    pub fn condensation(&self) -> DiGraph {
+        // Add a vertex for each component.
+        let mut g = DiGraph::from_vertices(0..self.components.len() as i32);
+        // For each edge between two different components, add an edge between them.
+        for (x, y) in self.graph.edges_iter() {
+            let (i, j) = (self.component[x], self.component[y]);
+            if i != j {
+                g.add_edge(&(i as i32), &(j as i32)).ok();
+            }
+        }
+
+        g
+    }
+}
+
+impl<'a, T> From<&'a T> for StronglyConnectedComponents<'a, T>
+where
+    T: Directed,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
@@ -0,0 +1,160 @@
+use crate::traits::Storage;
+use crate::types::VertexIterator;
+use crate::V;
+use std::collections::{HashMap, VecDeque};
+use std::vec::Vec;
+
+/// Find a cycle basis of an undirected graph.
+///
+/// The cycle basis is made of fundamental cycles, i.e. each cycle is obtained by adding
+/// a non-tree edge to a breadth-first spanning forest of the graph. Therefore, the
+/// number of cycles is equal to the cyclomatic number $|E| - |V| + c$, with $c$ the
+/// number of connected components.
+///
+pub struct CycleBasis<'a, T>
+where
+    T: Storage,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Reachable vertices of distance one from given vertex.
+    reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Depth of each vertex in the spanning forest.
+    depth: HashMap<&'a T::Vertex, usize>,
+    /// Predecessor of each vertex in the spanning forest.
+    pub predecessor: HashMap<&'a T::Vertex, &'a T::Vertex>,
+    /// Vector of fundamental cycles.
+    pub cycle_basis: Vec<Vec<&'a T::Vertex>>,
+}
+
+impl<'a, T> CycleBasis<'a, T>
+where
+    T: Storage,
+{
+    /// Build a *cycle basis* search structure.
+    ///
+    /// Build a *cycle basis* search structure which retains the fundamental cycles
+    /// found with respect to a breadth-first spanning forest[^1].
+    ///
+    /// [^1]: [Paton, K. (1969). An algorithm for finding a fundamental set of cycles of a graph. Communications of the ACM, 12(9), 514-518.](https://scholar.google.com/scholar?q=An+algorithm+for+finding+a+fundamental+set+of+cycles+of+a+graph)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build an undirected graph.
+    /// let g = Graph::from_edges([
+    ///     (0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (4, 5)
+    /// ]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = CycleBasis::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The cyclomatic number is |E| - |V| + c = 6 - 6 + 2 = 2,
+    /// // each cycle is closed by a non-tree edge.
+    /// assert_eq!(
+    ///     search.cycle_basis,
+    ///     [
+    ///         vec![&1, &0, &2, &1],
+    ///         vec![&2, &0, &1, &3, &2],
+    ///     ]
+    /// );
+    /// ```
+    ///
+    pub fn new(g: &'a T, f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set reachability function.
+            reachable: f,
+            // Initialize the depth map.
+            depth: Default::default(),
+            // Initialize the predecessor map.
+            predecessor: Default::default(),
+            // Initialize the vector of fundamental cycles.
+            cycle_basis: Default::default(),
+        }
+    }
+
+    fn fundamental_cycle(&self, x: &'a T::Vertex, y: &'a T::Vertex) -> Vec<&'a T::Vertex> {
+        // Initialize the two branches of the cycle.
+        let (mut p, mut q) = (vec![x], vec![y]);
+        // Get the depth of the endpoints.
+        let (mut i, mut j) = (self.depth[x], self.depth[y]);
+        // Climb the deepest branch until both branches are at the same depth.
+        while i > j {
+            p.push(self.predecessor[p[p.len() - 1]]);
+            i -= 1;
+        }
+        while j > i {
+            q.push(self.predecessor[q[q.len() - 1]]);
+            j -= 1;
+        }
+        // Climb both branches until the lowest common ancestor is reached.
+        while p[p.len() - 1] != q[q.len() - 1] {
+            p.push(self.predecessor[p[p.len() - 1]]);
+            q.push(self.predecessor[q[q.len() - 1]]);
+        }
+        // Remove the duplicated common ancestor.
+        q.pop();
+        // Join the two branches, from `x` to `y` through the common ancestor.
+        p.extend(q.into_iter().rev());
+        // Close the cycle.
+        p.push(x);
+
+        p
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Initialize the to-be-visited queue.
+        let mut queue: VecDeque<&'a T::Vertex> = Default::default();
+        // Initialize the vector of non-tree edges.
+        let mut edges: Vec<(&'a T::Vertex, &'a T::Vertex)> = Default::default();
+        // For each vertex in the graph, build a spanning tree of its component.
+        for x in V!(self.graph) {
+            // If the vertex has already been visited, skip it.
+            if self.depth.contains_key(x) {
+                continue;
+            }
+            // Set the vertex as root of a new spanning tree.
+            self.depth.insert(x, 0);
+            queue.push_back(x);
+            // Visit the component in breadth-first order.
+            while let Some(y) = queue.pop_front() {
+                // For each vertex reachable from the current vertex.
+                for z in (self.reachable)(self.graph, y) {
+                    // If the vertex has not been visited yet...
+                    if !self.depth.contains_key(z) {
+                        // ...then it is reached through a tree edge.
+                        self.depth.insert(z, self.depth[y] + 1);
+                        self.predecessor.insert(z, y);
+                        queue.push_back(z);
+                    // Else, if the edge is not the tree edge to the predecessor,
+                    // store it once, when visiting its lowest endpoint.
+                    } else if y <= z && self.predecessor.get(z) != Some(&y) && self.predecessor.get(y) != Some(&z) {
+                        edges.push((y, z));
+                    }
+                }
+            }
+        }
+        // For each non-tree edge, build its fundamental cycle.
+        for (x, y) in edges {
+            // A self-edge is a cycle by itself.
+            let c = match x == y {
+                true => vec![x, x],
+                false => self.fundamental_cycle(x, y),
+            };
+            self.cycle_basis.push(c);
+        }
+
+        self
+    }
+}
@@ -0,0 +1,297 @@
+use super::CycleBasis;
+use crate::traits::Storage;
+use crate::types::VertexIterator;
+use crate::utils::MinPriority;
+use crate::V;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet};
+use std::vec::Vec;
+
+/// Find a minimum weight cycle basis of an undirected graph.
+///
+/// A cycle basis is a minimum weight cycle basis if the sum of the weights
+/// of its cycles is minimum among all the cycle bases of the graph, where the
+/// weight of a cycle is the sum of the weights of its edges.
+///
+pub struct MinimumCycleBasis<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Reachable vertices of distance one from given vertex.
+    reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Weight of a given edge.
+    weight: F,
+    /// Vector of cycles in the basis, sorted by weight.
+    pub cycle_basis: Vec<Vec<&'a T::Vertex>>,
+    /// Total weight of the cycle basis.
+    pub total_weight: f64,
+}
+
+impl<'a, T, F> MinimumCycleBasis<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a *minimum cycle basis* search structure.
+    ///
+    /// Build a *minimum cycle basis* search structure for a given graph and weight function,
+    /// following the de Pina[^1] algorithm: each cycle is the shortest one having an odd
+    /// intersection with a support vector over the non-tree edges, support vectors are
+    /// then updated to be orthogonal to the cycles already found.
+    ///
+    /// [^1]: [De Pina, J. C. (1995). Applications of shortest path methods. PhD thesis, University of Amsterdam.](https://scholar.google.com/scholar?q=Applications+of+shortest+path+methods+de+Pina)
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative weight is encountered during the search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::UndirectedAdjacencyListGraph;
+    ///
+    /// // Build an undirected graph with weights as edge attributes.
+    /// let mut g = UndirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (1, 2), (2, 3), (0, 3), (0, 2)
+    /// ]);
+    /// for (x, y, w) in [(0, 1, 1.), (1, 2, 1.), (2, 3, 1.), (0, 3, 1.), (0, 2, 10.)] {
+    ///     g.set_edge_attrs(&x, &y, w);
+    ///     g.set_edge_attrs(&y, &x, w);
+    /// }
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = MinimumCycleBasis::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The outer square is cheaper than any triangle,
+    /// // hence only one triangle is in the basis.
+    /// assert_eq!(
+    ///     search.cycle_basis,
+    ///     [
+    ///         vec![&0, &1, &2, &3, &0],
+    ///         vec![&0, &1, &2, &0],
+    ///     ]
+    /// );
+    /// assert_eq!(search.total_weight, 16.);
+    /// ```
+    ///
+    pub fn new(g: &'a T, f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>, w: F) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set reachability function.
+            reachable: f,
+            // Set weight function.
+            weight: w,
+            // Initialize the vector of cycles in the basis.
+            cycle_basis: Default::default(),
+            // Initialize the total weight of the basis.
+            total_weight: 0.,
+        }
+    }
+
+    fn shortest_odd_cycle(
+        &self,
+        s: &HashSet<(&'a T::Vertex, &'a T::Vertex)>,
+    ) -> (BTreeSet<(&'a T::Vertex, &'a T::Vertex)>, f64) {
+        // Initialize the best cycle found so far.
+        let (mut cycle, mut best) = (BTreeSet::new(), f64::INFINITY);
+        // Any cycle with an odd intersection with the support vector contains
+        // at least one of its edges, hence it passes through one of its endpoints.
+        let sources: BTreeSet<_> = s.iter().flat_map(|&(x, y)| [x, y]).collect();
+        // For each candidate source vertex.
+        for x in sources {
+            // Search the shortest path from `(x, false)` to `(x, true)` in the signed graph,
+            // where the parity flag is flipped each time an edge of the support vector is traversed.
+            let mut queue = BinaryHeap::from([MinPriority(0., (x, false))]);
+            let mut distance = HashMap::from([((x, false), 0.)]);
+            let mut predecessor: HashMap<(&T::Vertex, bool), (&T::Vertex, bool)> = HashMap::new();
+            // While there are still vertices to visit.
+            while let Some(MinPriority(d, (y, p))) = queue.pop() {
+                // If the current path is not shorter than the best cycle, stop the search.
+                if d >= best {
+                    break;
+                }
+                // If the current entry is stale, skip it.
+                if d > distance[&(y, p)] {
+                    continue;
+                }
+                // If the target has been reached, store the odd cycle.
+                if (y, p) == (x, true) {
+                    // Walk back the path, keeping the edges traversed an odd number of times.
+                    let mut walk = BTreeSet::new();
+                    let mut z = (y, p);
+                    while let Some(&w) = predecessor.get(&z) {
+                        let (u, v) = (z.0, w.0);
+                        let e = if u <= v { (u, v) } else { (v, u) };
+                        if !walk.remove(&e) {
+                            walk.insert(e);
+                        }
+                        z = w;
+                    }
+                    // Extract a simple odd cycle from the closed walk.
+                    cycle = Self::simple_odd_cycle(walk, s);
+                    best = d;
+                    break;
+                }
+                // For each vertex reachable from the current vertex.
+                for z in (self.reachable)(self.graph, y) {
+                    // Skip self-edges, which cannot shorten the path.
+                    if z == y {
+                        continue;
+                    }
+                    // Compute the edge weight.
+                    let w = (self.weight)(y, z);
+                    // Assert that the weight is non-negative.
+                    assert!(w >= 0., "negative weight found on edge `({:?}, {:?})`", y, z);
+                    // Flip the parity if the edge is in the support vector.
+                    let e = if y <= z { (y, z) } else { (z, y) };
+                    let q = p ^ s.contains(&e);
+                    // Relax the edge.
+                    if distance.get(&(z, q)).is_none_or(|&t| d + w < t) {
+                        distance.insert((z, q), d + w);
+                        predecessor.insert((z, q), (y, p));
+                        queue.push(MinPriority(d + w, (z, q)));
+                    }
+                }
+            }
+        }
+
+        (cycle, best)
+    }
+
+    /// Extract a simple cycle with odd intersection with the support vector from a closed walk.
+    ///
+    /// Zero-weight edges allow the shortest closed walk to pass twice through the same vertex, in which case
+    /// its edge set is the disjoint union of several simple cycles. At least one of them has odd intersection
+    /// with the support vector and, since weights are non-negative, it has the same weight as the whole walk.
+    ///
+    fn simple_odd_cycle(
+        walk: BTreeSet<(&'a T::Vertex, &'a T::Vertex)>,
+        s: &HashSet<(&'a T::Vertex, &'a T::Vertex)>,
+    ) -> BTreeSet<(&'a T::Vertex, &'a T::Vertex)> {
+        // Compute the neighbors of each vertex in the edge set of the walk, where each vertex has even degree.
+        let mut neighbors: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for &(x, y) in walk.iter() {
+            neighbors.entry(x).or_default().insert(y);
+            neighbors.entry(y).or_default().insert(x);
+        }
+        // Initialize the current trail, together with the position of each of its vertices.
+        let (mut trail, mut position) = (Vec::new(), HashMap::new());
+        loop {
+            // If the trail is empty, start it from the lowest vertex with unused edges.
+            if trail.is_empty() {
+                let x = *neighbors.iter().find(|(_, n)| !n.is_empty()).unwrap().0;
+                position.insert(x, 0);
+                trail.push(x);
+            }
+            // Move along an unused edge, removing it.
+            let y = trail[trail.len() - 1];
+            let z = match neighbors[y].first() {
+                Some(&z) => z,
+                // If the trail is reduced to a vertex without unused edges, start a new one.
+                None => {
+                    position.clear();
+                    trail.clear();
+                    continue;
+                }
+            };
+            neighbors.get_mut(y).unwrap().remove(z);
+            neighbors.get_mut(z).unwrap().remove(y);
+            // If the vertex is new, then extend the trail.
+            let i = match position.get(z) {
+                Some(&i) => i,
+                None => {
+                    position.insert(z, trail.len());
+                    trail.push(z);
+                    continue;
+                }
+            };
+            // Otherwise, a simple cycle is closed, get its edge set.
+            let cycle: BTreeSet<_> = trail[i..]
+                .iter()
+                .zip(trail[i + 1..].iter().chain([&z]))
+                .map(|(&u, &v)| if u <= v { (u, v) } else { (v, u) })
+                .collect();
+            // If the cycle has odd intersection with the support vector, return it.
+            if cycle.iter().filter(|e| s.contains(e)).count() % 2 == 1 {
+                return cycle;
+            }
+            // Otherwise, remove the cycle from the trail.
+            for x in trail.drain(i + 1..) {
+                position.remove(x);
+            }
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Build a spanning forest of the graph.
+        let mut forest = CycleBasis::new(self.graph, self.reachable);
+        forest.run();
+        // Initialize the vector of non-tree edges.
+        let mut edges: Vec<(&'a T::Vertex, &'a T::Vertex)> = Default::default();
+        // Initialize the vector of weighted cycles.
+        let mut cycles: Vec<(f64, Vec<&'a T::Vertex>)> = Default::default();
+        // For each edge of the graph, considered once with its lowest endpoint first.
+        for x in V!(self.graph) {
+            for y in (self.reachable)(self.graph, x).filter(|&y| x <= y) {
+                // A self-edge is a cycle by itself, which is part of any cycle basis.
+                if x == y {
+                    cycles.push(((self.weight)(x, x), vec![x, x]));
+                // Otherwise, if it is not a tree edge, store it.
+                } else if forest.predecessor.get(y) != Some(&x) && forest.predecessor.get(x) != Some(&y) {
+                    edges.push((x, y));
+                }
+            }
+        }
+        // Initialize the support vectors as the unit vectors over the non-tree edges.
+        let mut supports: Vec<HashSet<_>> = edges.into_iter().map(|e| HashSet::from([e])).collect();
+        // For each support vector.
+        for i in 0..supports.len() {
+            // Find the shortest cycle with odd intersection with the support vector.
+            let (c, w) = self.shortest_odd_cycle(&supports[i]);
+            // Update the following support vectors to be orthogonal to the cycle.
+            let (head, tail) = supports.split_at_mut(i + 1);
+            for s in tail.iter_mut() {
+                if c.iter().filter(|e| s.contains(e)).count() % 2 == 1 {
+                    *s = s.symmetric_difference(&head[i]).cloned().collect();
+                }
+            }
+            // Convert the edge set into a closed sequence of vertices.
+            let mut neighbors: HashMap<_, Vec<_>> = HashMap::new();
+            for &(x, y) in c.iter() {
+                neighbors.entry(x).or_default().push(y);
+                neighbors.entry(y).or_default().push(x);
+            }
+            // Start from the lowest vertex, moving towards its lowest neighbor.
+            let x = c.iter().next().unwrap().0;
+            let mut cycle = vec![x, *neighbors[x].iter().min().unwrap()];
+            // Walk the cycle until the starting vertex is reached again.
+            while cycle[cycle.len() - 1] != x {
+                let (y, z) = (cycle[cycle.len() - 2], cycle[cycle.len() - 1]);
+                cycle.push(*neighbors[z].iter().find(|&&w| w != y).unwrap());
+            }
+            // Store the cycle.
+            cycles.push((w, cycle));
+        }
+        // Sort the cycles by weight, breaking ties lexicographically.
+        cycles.sort_by(|(a, c), (b, d)| a.partial_cmp(b).unwrap_or(Ordering::Equal).then_with(|| c.cmp(d)));
+        // Store the total weight.
+        self.total_weight = cycles.iter().map(|(w, _)| w).sum();
+        // Store the cycles.
+        self.cycle_basis = cycles.into_iter().map(|(_, c)| c).collect();
+
+        self
+    }
+}
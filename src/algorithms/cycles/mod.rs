@@ -0,0 +1,5 @@
+mod cycle_basis;
+pub use cycle_basis::CycleBasis;
+
+mod minimum_cycle_basis;
+pub use minimum_cycle_basis::MinimumCycleBasis;
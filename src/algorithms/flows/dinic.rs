@@ -0,0 +1,221 @@
+use super::ResidualNetwork;
+use crate::traits::Directed;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::vec::Vec;
+
+/// Dinic maximum flow structure.
+///
+/// This structure contains the `max_flow` value, the per-edge `flow` map and
+/// the `min_cut` partition, where the capacity of each edge is given by a user-supplied closure.
+///
+pub struct Dinic<'a, T, F>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Capacity of a given edge.
+    capacity: F,
+    /// Source vertex.
+    source: &'a T::Vertex,
+    /// Target vertex.
+    target: &'a T::Vertex,
+    /// Value of the maximum flow.
+    pub max_flow: f64,
+    /// Flow of each edge.
+    pub flow: HashMap<(&'a T::Vertex, &'a T::Vertex), f64>,
+    /// Minimum cut, i.e. the source side and the target side of the partition.
+    pub min_cut: (BTreeSet<&'a T::Vertex>, BTreeSet<&'a T::Vertex>),
+}
+
+impl<'a, T, F> Dinic<'a, T, F>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a Dinic maximum flow structure.
+    ///
+    /// Build a Dinic[^1] maximum flow structure for a given graph, source and target vertices,
+    /// and capacity function. The flow is augmented by blocking flows over the level graph
+    /// of the residual network.
+    ///
+    /// [^1]: [Dinic, E. A. (1970). Algorithm for solution of a problem of maximum flow in networks with power estimation. Soviet Math. Doklady, 11, 1277-1280.](https://scholar.google.com/scholar?q=Algorithm+for+solution+of+a+problem+of+maximum+flow+in+networks+with+power+estimation)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source vertex or the target vertex are not in the graph, if they are the same vertex,
+    /// or if a negative capacity is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::DirectedAdjacencyListGraph;
+    ///
+    /// // Build a directed graph with capacities as edge attributes.
+    /// let mut g = DirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (0, 2), (1, 2), (1, 3), (2, 3)
+    /// ]);
+    /// g.set_edge_attrs(&0, &1, 3.);
+    /// g.set_edge_attrs(&0, &2, 2.);
+    /// g.set_edge_attrs(&1, &2, 1.);
+    /// g.set_edge_attrs(&1, &3, 1.);
+    /// g.set_edge_attrs(&2, &3, 4.);
+    ///
+    /// // Build the search object over said graph, from `0` to `3`.
+    /// let mut search = Dinic::from((&g, &0, &3, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The maximum flow is four ...
+    /// assert_eq!(search.max_flow, 4.);
+    /// // ... and the minimum cut separates `0` and `1` from the rest.
+    /// assert_eq!(search.min_cut, (BTreeSet::from([&0, &1]), BTreeSet::from([&2, &3])));
+    /// ```
+    ///
+    pub fn new(g: &'a T, s: &'a T::Vertex, t: &'a T::Vertex, c: F) -> Self {
+        // Assert that source and target vertices are in graph.
+        assert!(g.has_vertex(s) && g.has_vertex(t));
+        // Assert that source and target vertices are different.
+        assert!(s != t);
+
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set capacity function.
+            capacity: c,
+            // Set source vertex.
+            source: s,
+            // Set target vertex.
+            target: t,
+            // Initialize the maximum flow value.
+            max_flow: 0.,
+            // Initialize the flow map.
+            flow: Default::default(),
+            // Initialize the minimum cut.
+            min_cut: Default::default(),
+        }
+    }
+
+    /// Push a blocking flow over the level graph of the residual network.
+    ///
+    /// The augmenting paths are searched depth-first with an explicit stack, so that
+    /// long paths do not overflow the call stack.
+    ///
+    fn blocking_flow(
+        &self,
+        residual: &mut ResidualNetwork<'a, T::Vertex>,
+        level: &HashMap<&'a T::Vertex, usize>,
+        next: &mut HashMap<&'a T::Vertex, Vec<&'a T::Vertex>>,
+    ) -> f64 {
+        // Initialize the pushed flow value.
+        let mut flow = 0.;
+        // Initialize the current path with the source vertex.
+        let mut path = vec![self.source];
+        // While the current path is not empty.
+        while let Some(&x) = path.last() {
+            // If the target vertex is reached, then push flow along the current path.
+            if x == self.target {
+                // Get the residual capacity of each edge of the path.
+                let r: Vec<_> = path.windows(2).map(|e| residual.residual(e[0], e[1])).collect();
+                // Find the first bottleneck edge.
+                let (i, d) = r
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .fold((0, f64::INFINITY), |(i, d), (j, e)| if e < d { (j, e) } else { (i, d) });
+                // Push the bottleneck amount along the path.
+                for e in path.windows(2) {
+                    residual.push(e[0], e[1], d);
+                }
+                flow += d;
+                // Retreat to the tail of the bottleneck edge, which is now saturated.
+                path.truncate(i + 1);
+                continue;
+            }
+            // Find the next admissible vertex not yet exhausted in the current phase.
+            let mut advanced = false;
+            while let Some(&y) = next.get(x).and_then(|n| n.last()) {
+                // If the edge goes to the next level and it is not saturated, then advance.
+                if level.get(y) == Some(&(level[x] + 1)) && residual.residual(x, y) > 0. {
+                    path.push(y);
+                    advanced = true;
+                    break;
+                }
+                // Otherwise, the edge is exhausted for the current phase.
+                next.get_mut(x).unwrap().pop();
+            }
+            // If the current vertex is a dead end, then retreat ...
+            if !advanced {
+                path.pop();
+                // ... and exhaust the edge leading to it, if any.
+                if let Some(&w) = path.last() {
+                    next.get_mut(w).unwrap().pop();
+                }
+            }
+        }
+
+        flow
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Build the residual network.
+        let mut residual = ResidualNetwork::new(self.graph, &self.capacity);
+        // Repeat until the target vertex is not reachable in the residual network.
+        loop {
+            // Compute the level of each vertex in breadth-first order.
+            let mut level = HashMap::from([(self.source, 0)]);
+            let mut queue = VecDeque::from([self.source]);
+            while let Some(x) = queue.pop_front() {
+                for &y in residual.adjacents[x].iter() {
+                    if !level.contains_key(y) && residual.residual(x, y) > 0. {
+                        level.insert(y, level[x] + 1);
+                        queue.push_back(y);
+                    }
+                }
+            }
+            // If the target vertex is not reachable, the flow is maximum.
+            if !level.contains_key(self.target) {
+                break;
+            }
+            // Initialize the edges yet to explore for each vertex, in reverse order.
+            let mut next: HashMap<_, Vec<_>> = residual
+                .adjacents
+                .iter()
+                .map(|(&x, y)| (x, y.iter().rev().copied().collect()))
+                .collect();
+            // Push flow along augmenting paths until a blocking flow is found.
+            self.max_flow += self.blocking_flow(&mut residual, &level, &mut next);
+        }
+        // Compute the minimum cut.
+        self.min_cut = residual.min_cut(self.source);
+        // Store the flow map.
+        self.flow = residual.flow;
+
+        self
+    }
+}
+
+impl<'a, T, F> From<(&'a T, &'a T::Vertex, &'a T::Vertex, F)> for Dinic<'a, T, F>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Builds a search object from a given graph, with source and target vertices and a capacity function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source vertex or the target vertex are not in the graph.
+    ///
+    fn from((g, s, t, c): (&'a T, &'a T::Vertex, &'a T::Vertex, F)) -> Self {
+        Self::new(g, s, t, c)
+    }
+}
@@ -0,0 +1,174 @@
+use super::ResidualNetwork;
+use crate::traits::Directed;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+/// Edmonds-Karp maximum flow structure.
+///
+/// This structure contains the `max_flow` value, the per-edge `flow` map and
+/// the `min_cut` partition, where the capacity of each edge is given by a user-supplied closure.
+///
+pub struct EdmondsKarp<'a, T, F>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Capacity of a given edge.
+    capacity: F,
+    /// Source vertex.
+    source: &'a T::Vertex,
+    /// Target vertex.
+    target: &'a T::Vertex,
+    /// Value of the maximum flow.
+    pub max_flow: f64,
+    /// Flow of each edge.
+    pub flow: HashMap<(&'a T::Vertex, &'a T::Vertex), f64>,
+    /// Minimum cut, i.e. the source side and the target side of the partition.
+    pub min_cut: (BTreeSet<&'a T::Vertex>, BTreeSet<&'a T::Vertex>),
+}
+
+impl<'a, T, F> EdmondsKarp<'a, T, F>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build an Edmonds-Karp maximum flow structure.
+    ///
+    /// Build an Edmonds-Karp[^1] maximum flow structure for a given graph, source and target vertices,
+    /// and capacity function. The flow is augmented along shortest paths in the residual network.
+    ///
+    /// [^1]: [Edmonds, J., & Karp, R. M. (1972). Theoretical improvements in algorithmic efficiency for network flow problems. Journal of the ACM, 19(2), 248-264.](https://scholar.google.com/scholar?q=Theoretical+improvements+in+algorithmic+efficiency+for+network+flow+problems)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source vertex or the target vertex are not in the graph, if they are the same vertex,
+    /// or if a negative capacity is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::DirectedAdjacencyListGraph;
+    ///
+    /// // Build a directed graph with capacities as edge attributes.
+    /// let mut g = DirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (0, 2), (1, 2), (1, 3), (2, 3)
+    /// ]);
+    /// g.set_edge_attrs(&0, &1, 3.);
+    /// g.set_edge_attrs(&0, &2, 2.);
+    /// g.set_edge_attrs(&1, &2, 1.);
+    /// g.set_edge_attrs(&1, &3, 1.);
+    /// g.set_edge_attrs(&2, &3, 4.);
+    ///
+    /// // Build the search object over said graph, from `0` to `3`.
+    /// let mut search = EdmondsKarp::from((&g, &0, &3, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The maximum flow is four ...
+    /// assert_eq!(search.max_flow, 4.);
+    /// // ... which saturates the cut edges `(0, 2)`, `(1, 2)` and `(1, 3)` ...
+    /// assert_eq!(search.flow[&(&0, &2)], 2.);
+    /// assert_eq!(search.flow[&(&1, &2)], 1.);
+    /// assert_eq!(search.flow[&(&1, &3)], 1.);
+    /// // ... while the edge `(0, 1)` carries two units out of three.
+    /// assert_eq!(search.flow[&(&0, &1)], 2.);
+    /// // The minimum cut separates `0` and `1` from the rest.
+    /// assert_eq!(search.min_cut, (BTreeSet::from([&0, &1]), BTreeSet::from([&2, &3])));
+    /// ```
+    ///
+    pub fn new(g: &'a T, s: &'a T::Vertex, t: &'a T::Vertex, c: F) -> Self {
+        // Assert that source and target vertices are in graph.
+        assert!(g.has_vertex(s) && g.has_vertex(t));
+        // Assert that source and target vertices are different.
+        assert!(s != t);
+
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set capacity function.
+            capacity: c,
+            // Set source vertex.
+            source: s,
+            // Set target vertex.
+            target: t,
+            // Initialize the maximum flow value.
+            max_flow: 0.,
+            // Initialize the flow map.
+            flow: Default::default(),
+            // Initialize the minimum cut.
+            min_cut: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Build the residual network.
+        let mut residual = ResidualNetwork::new(self.graph, &self.capacity);
+        // Repeat until no augmenting path is found.
+        loop {
+            // Search for a shortest augmenting path in breadth-first order.
+            let mut predecessor = HashMap::new();
+            let mut queue = VecDeque::from([self.source]);
+            while let Some(x) = queue.pop_front() {
+                // Stop as soon as the target vertex is reached.
+                if x == self.target {
+                    break;
+                }
+                for &y in residual.adjacents[x].iter() {
+                    if y != self.source && !predecessor.contains_key(y) && residual.residual(x, y) > 0. {
+                        predecessor.insert(y, x);
+                        queue.push_back(y);
+                    }
+                }
+            }
+            // If the target vertex is not reachable, the flow is maximum.
+            if !predecessor.contains_key(self.target) {
+                break;
+            }
+            // Compute the bottleneck capacity along the path.
+            let (mut d, mut y) = (f64::INFINITY, self.target);
+            while let Some(&x) = predecessor.get(y) {
+                d = f64::min(d, residual.residual(x, y));
+                y = x;
+            }
+            // Augment the flow along the path.
+            let mut y = self.target;
+            while let Some(&x) = predecessor.get(y) {
+                residual.push(x, y, d);
+                y = x;
+            }
+            // Update the flow value.
+            self.max_flow += d;
+        }
+        // Compute the minimum cut.
+        self.min_cut = residual.min_cut(self.source);
+        // Store the flow map.
+        self.flow = residual.flow;
+
+        self
+    }
+}
+
+impl<'a, T, F> From<(&'a T, &'a T::Vertex, &'a T::Vertex, F)> for EdmondsKarp<'a, T, F>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Builds a search object from a given graph, with source and target vertices and a capacity function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source vertex or the target vertex are not in the graph.
+    ///
+    fn from((g, s, t, c): (&'a T, &'a T::Vertex, &'a T::Vertex, F)) -> Self {
+        Self::new(g, s, t, c)
+    }
+}
@@ -0,0 +1,261 @@
+use crate::algorithms::BellmanFord;
+use crate::traits::Directed;
+use crate::types::Error;
+use crate::utils::MinPriority;
+use crate::V;
+use std::collections::{BinaryHeap, HashMap};
+use std::vec::Vec;
+
+/// Minimum cost flow structure.
+///
+/// This structure contains the per-edge `flow` map and its `total_cost`, where the capacity
+/// and the cost of each edge, together with the supply of each vertex, are given by user-supplied closures.
+/// A positive supply denotes a source of flow, while a negative supply denotes a demand.
+///
+pub struct MinimumCostFlow<'a, T, F, G, H>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+    G: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+    H: Fn(&'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Capacity of a given edge.
+    capacity: F,
+    /// Cost of a given edge, per unit of flow.
+    cost: G,
+    /// Supply of a given vertex.
+    supply: H,
+    /// Flow of each edge.
+    pub flow: HashMap<(&'a T::Vertex, &'a T::Vertex), f64>,
+    /// Total cost of the flow.
+    pub total_cost: f64,
+}
+
+impl<'a, T, F, G, H> MinimumCostFlow<'a, T, F, G, H>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+    G: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+    H: Fn(&'a T::Vertex) -> f64,
+{
+    /// Build a minimum cost flow structure.
+    ///
+    /// Build a minimum cost flow structure for a given graph, capacity, cost and supply functions,
+    /// following the successive shortest paths algorithm with vertex potentials[^1]: the flow is
+    /// augmented along shortest paths from an excess vertex to a deficit vertex, which are found
+    /// by Dijkstra over non-negative reduced costs.
+    ///
+    /// [^1]: [Edmonds, J., & Karp, R. M. (1972). Theoretical improvements in algorithmic efficiency for network flow problems. Journal of the ACM, 19(2), 248-264.](https://scholar.google.com/scholar?q=Theoretical+improvements+in+algorithmic+efficiency+for+network+flow+problems)
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative capacity is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::DirectedAdjacencyListGraph;
+    ///
+    /// // Build a transportation problem, with supplies as vertex attributes
+    /// // and (capacity, cost) pairs as edge attributes.
+    /// let mut g = DirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), f64, (f64, f64)>>::from_edges([
+    ///     (0, 2), (0, 3), (1, 2), (1, 3)
+    /// ]);
+    /// g.set_vertex_attrs(&0, 3.);
+    /// g.set_vertex_attrs(&1, 2.);
+    /// g.set_vertex_attrs(&2, -2.);
+    /// g.set_vertex_attrs(&3, -3.);
+    /// g.set_edge_attrs(&0, &2, (3., 1.));
+    /// g.set_edge_attrs(&0, &3, (3., 4.));
+    /// g.set_edge_attrs(&1, &2, (2., 2.));
+    /// g.set_edge_attrs(&1, &3, (2., 1.));
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = MinimumCostFlow::from((
+    ///     &g,
+    ///     |x, y| g.get_edge_attrs(x, y).unwrap().0,
+    ///     |x, y| g.get_edge_attrs(x, y).unwrap().1,
+    ///     |x| *g.get_vertex_attrs(x).unwrap(),
+    /// ));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run().unwrap();
+    ///
+    /// // The cheapest way to satisfy the demands costs eight ...
+    /// assert_eq!(search.total_cost, 8.);
+    /// // ... where `1` sends all its supply to `3`.
+    /// assert_eq!(search.flow[&(&0, &2)], 2.);
+    /// assert_eq!(search.flow[&(&0, &3)], 1.);
+    /// assert_eq!(search.flow[&(&1, &2)], 0.);
+    /// assert_eq!(search.flow[&(&1, &3)], 2.);
+    /// ```
+    ///
+    pub fn new(g: &'a T, f: F, c: G, s: H) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set capacity function.
+            capacity: f,
+            // Set cost function.
+            cost: c,
+            // Set supply function.
+            supply: s,
+            // Initialize the flow map.
+            flow: Default::default(),
+            // Initialize the total cost.
+            total_cost: 0.,
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    /// # Errors
+    ///
+    /// If the supplies cannot be routed to the demands, then returns an infeasible flow error.
+    /// If a cycle with negative cost and positive capacity exists, then returns a negative cycle error.
+    ///
+    pub fn run(&mut self) -> Result<&Self, Error<T::Vertex>> {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(self.graph).collect();
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Get the edge set.
+        let edges: Vec<_> = self.graph.edges_iter().collect();
+        // Initialize the residual arcs, where the $2i$-th arc is the $i$-th edge
+        // and the $(2i + 1)$-th arc is its reverse, with zero capacity and opposite cost.
+        let (mut head, mut residual, mut cost) = (Vec::new(), Vec::new(), Vec::new());
+        // Initialize the outgoing arcs of each vertex.
+        let mut arcs = vec![Vec::new(); vertices.len()];
+        for &(x, y) in edges.iter() {
+            // Get the edge capacity.
+            let c = (self.capacity)(x, y);
+            // Assert the capacity is non-negative.
+            assert!(c >= 0., "negative capacity found for edge ({:?}, {:?})", x, y);
+            // Get the edge cost.
+            let w = (self.cost)(x, y);
+            // Add the forward arc.
+            arcs[index[x]].push(head.len());
+            head.push(index[y]);
+            residual.push(c);
+            cost.push(w);
+            // Add the reverse arc.
+            arcs[index[y]].push(head.len());
+            head.push(index[x]);
+            residual.push(0.);
+            cost.push(-w);
+        }
+        // Initialize the potentials with the shortest distances from a virtual source, considering
+        // only edges with positive capacity. This makes the reduced costs non-negative.
+        let mut potential: Vec<f64> = {
+            let w = |x, y| match (self.capacity)(x, y) > 0. {
+                true => (self.cost)(x, y),
+                false => f64::INFINITY,
+            };
+            let mut search = BellmanFord::new(self.graph, None, T::children_iter, w);
+            let search = search.run()?;
+            vertices.iter().map(|x| search.distance[x]).collect()
+        };
+        // Initialize the excess of each vertex with its supply.
+        let mut excess: Vec<f64> = vertices.iter().map(|&x| (self.supply)(x)).collect();
+        // While there is a vertex with positive excess.
+        while excess.iter().any(|&e| e > 0.) {
+            // Initialize the distances and the predecessor arcs.
+            let mut distance = vec![f64::INFINITY; vertices.len()];
+            let mut predecessor: Vec<Option<usize>> = vec![None; vertices.len()];
+            // Initialize the to-be-visited queue with every excess vertex.
+            let mut queue = BinaryHeap::new();
+            for (i, &e) in excess.iter().enumerate() {
+                if e > 0. {
+                    distance[i] = 0.;
+                    queue.push(MinPriority(0., i));
+                }
+            }
+            // Search the shortest paths with respect to the reduced costs.
+            while let Some(MinPriority(d, i)) = queue.pop() {
+                // If the current entry is stale, skip it.
+                if d > distance[i] {
+                    continue;
+                }
+                // For each outgoing arc with positive residual capacity.
+                for &a in arcs[i].iter().filter(|&&a| residual[a] > 0.) {
+                    // Compute the reduced cost, clamping rounding errors.
+                    let w = f64::max(cost[a] + potential[i] - potential[head[a]], 0.);
+                    // Relax the arc.
+                    if d + w < distance[head[a]] {
+                        distance[head[a]] = d + w;
+                        predecessor[head[a]] = Some(a);
+                        queue.push(MinPriority(d + w, head[a]));
+                    }
+                }
+            }
+            // Select the closest deficit vertex, if any.
+            let t = match (0..vertices.len())
+                .filter(|&i| excess[i] < 0. && distance[i] < f64::INFINITY)
+                .min_by(|&i, &j| distance[i].total_cmp(&distance[j]))
+            {
+                Some(t) => t,
+                // If no deficit vertex is reachable, the remaining excess cannot be routed.
+                None => break,
+            };
+            // Update the potentials, keeping the reduced costs non-negative.
+            for (p, d) in potential.iter_mut().zip(distance.iter()) {
+                *p += f64::min(*d, distance[t]);
+            }
+            // Compute the bottleneck capacity along the path, from the deficit vertex back to an excess vertex.
+            let (mut d, mut i) = (-excess[t], t);
+            while let Some(a) = predecessor[i] {
+                d = f64::min(d, residual[a]);
+                i = head[a ^ 1];
+            }
+            d = f64::min(d, excess[i]);
+            // Augment the flow along the path.
+            let (s, mut i) = (i, t);
+            while let Some(a) = predecessor[i] {
+                residual[a] -= d;
+                residual[a ^ 1] += d;
+                i = head[a ^ 1];
+            }
+            // Update the excesses of the endpoints.
+            excess[s] -= d;
+            excess[t] += d;
+        }
+        // If there is some excess or deficit left, then the flow is infeasible.
+        let (e, f): (f64, f64) = (
+            excess.iter().filter(|&&e| e > 0.).sum(),
+            excess.iter().filter(|&&e| e < 0.).map(|e| -e).sum(),
+        );
+        if e > 0. || f > 0. {
+            return Err(Error::InfeasibleFlow(f64::max(e, f)));
+        }
+        // Store the flow of each edge, i.e. the residual capacity of its reverse arc.
+        self.flow = edges
+            .iter()
+            .enumerate()
+            .map(|(i, &e)| (e, residual[2 * i + 1]))
+            .collect();
+        // Compute the total cost.
+        self.total_cost = edges.iter().map(|&(x, y)| self.flow[&(x, y)] * (self.cost)(x, y)).sum();
+
+        Ok(self)
+    }
+}
+
+impl<'a, T, F, G, H> From<(&'a T, F, G, H)> for MinimumCostFlow<'a, T, F, G, H>
+where
+    T: Directed,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+    G: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+    H: Fn(&'a T::Vertex) -> f64,
+{
+    /// Builds a search object from a given graph, with capacity, cost and supply functions.
+    ///
+    fn from((g, f, c, s): (&'a T, F, G, H)) -> Self {
+        Self::new(g, f, c, s)
+    }
+}
@@ -0,0 +1,11 @@
+mod dinic;
+pub use dinic::Dinic;
+
+mod edmonds_karp;
+pub use edmonds_karp::EdmondsKarp;
+
+mod minimum_cost_flow;
+pub use minimum_cost_flow::MinimumCostFlow;
+
+mod residual_network;
+pub(crate) use residual_network::ResidualNetwork;
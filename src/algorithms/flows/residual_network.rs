@@ -0,0 +1,122 @@
+use crate::traits::Storage;
+use crate::types::Vertex;
+use crate::V;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+/// Residual network of a flow network.
+///
+/// The residual capacity of a pair of vertices $(X, Y)$ is $c(X, Y) - f(X, Y) + f(Y, X)$,
+/// i.e. the flow can be either increased along $(X, Y)$ or cancelled along $(Y, X)$.
+///
+pub(crate) struct ResidualNetwork<'a, V>
+where
+    V: Vertex,
+{
+    /// Capacity of each edge.
+    pub capacity: HashMap<(&'a V, &'a V), f64>,
+    /// Flow of each edge.
+    pub flow: HashMap<(&'a V, &'a V), f64>,
+    /// Vertices adjacent to each vertex in the underlying undirected graph.
+    pub adjacents: HashMap<&'a V, BTreeSet<&'a V>>,
+}
+
+impl<'a, V> ResidualNetwork<'a, V>
+where
+    V: Vertex,
+{
+    /// Build the residual network of a given graph with zero flow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative capacity is found.
+    ///
+    pub fn new<T, F>(g: &'a T, c: F) -> Self
+    where
+        T: Storage<Vertex = V>,
+        F: Fn(&'a V, &'a V) -> f64,
+    {
+        // Initialize the adjacency map with every vertex.
+        let mut adjacents: HashMap<_, BTreeSet<_>> = V!(g).map(|x| (x, Default::default())).collect();
+        // Initialize the capacity map.
+        let mut capacity = HashMap::with_capacity(g.size());
+        // For each edge in the graph.
+        for (x, y) in g.edges_iter() {
+            // Get the edge capacity.
+            let w = c(x, y);
+            // Assert the capacity is non-negative.
+            assert!(w >= 0., "negative capacity found for edge ({:?}, {:?})", x, y);
+            // Set the edge capacity.
+            capacity.insert((x, y), w);
+            // Set the endpoints as adjacent in both directions.
+            adjacents.get_mut(x).unwrap().insert(y);
+            adjacents.get_mut(y).unwrap().insert(x);
+        }
+        // Initialize the flow map with zero flow.
+        let flow = capacity.keys().map(|&e| (e, 0.)).collect();
+
+        Self {
+            capacity,
+            flow,
+            adjacents,
+        }
+    }
+
+    /// Residual capacity of a given pair of vertices.
+    pub fn residual(&self, x: &'a V, y: &'a V) -> f64 {
+        // Get the forward residual capacity, if any.
+        let forward = match self.capacity.get(&(x, y)) {
+            Some(c) => c - self.flow[&(x, y)],
+            None => 0.,
+        };
+        // Get the backward flow that can be cancelled, if any.
+        let backward = self.flow.get(&(y, x)).copied().unwrap_or(0.);
+
+        forward + backward
+    }
+
+    /// Push a given amount of flow from a vertex to another one.
+    pub fn push(&mut self, x: &'a V, y: &'a V, d: f64) {
+        // Cancel the backward flow first, if any.
+        let d = match self.flow.get_mut(&(y, x)) {
+            Some(f) => {
+                let r = f64::min(*f, d);
+                *f -= r;
+                d - r
+            }
+            None => d,
+        };
+        // Increase the forward flow with the remaining amount.
+        if d > 0. {
+            *self.flow.get_mut(&(x, y)).unwrap() += d;
+        }
+    }
+
+    /// Compute the minimum cut with respect to a given source vertex.
+    ///
+    /// Returns the vertices reachable from the source vertex in the residual network,
+    /// and the remaining ones.
+    ///
+    pub fn min_cut(&self, s: &'a V) -> (BTreeSet<&'a V>, BTreeSet<&'a V>) {
+        // Initialize the source side of the cut.
+        let mut source = BTreeSet::from([s]);
+        // Initialize the to-be-visited queue.
+        let mut queue = VecDeque::from([s]);
+        // Visit the residual network in breadth-first order.
+        while let Some(x) = queue.pop_front() {
+            for &y in self.adjacents[x].iter() {
+                if self.residual(x, y) > 0. && source.insert(y) {
+                    queue.push_back(y);
+                }
+            }
+        }
+        // Get the sink side of the cut.
+        let target = self
+            .adjacents
+            .keys()
+            .filter(|x| !source.contains(*x))
+            .copied()
+            .collect();
+
+        (source, target)
+    }
+}
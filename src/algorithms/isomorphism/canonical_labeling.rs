@@ -0,0 +1,262 @@
+use crate::traits::Storage;
+use crate::types::EdgeList;
+use crate::utils::DisjointSet;
+use crate::V;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::vec::Vec;
+
+/// Ordered partition of the vertex set, i.e. a sequence of cells.
+type Partition = Vec<Vec<usize>>;
+
+/// Canonical labeling structure.
+///
+/// This structure contains the `labeling` and the `canonical` edge list of a given graph,
+/// such that two graphs of the same order are isomorphic if and only if their canonical edge lists are equal.
+///
+pub struct CanonicalLabeling<'a, T>
+where
+    T: Storage,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Out-going adjacency of each vertex, including self-edges.
+    out: Vec<HashSet<usize>>,
+    /// In-coming adjacency of each vertex, including self-edges.
+    inc: Vec<HashSet<usize>>,
+    /// Best labeling found so far, as the label of each vertex.
+    best: Option<Vec<usize>>,
+    /// Automorphisms found so far, as the image of each vertex.
+    automorphisms: Vec<Vec<usize>>,
+    /// Canonical label of each vertex.
+    pub labeling: HashMap<&'a T::Vertex, usize>,
+    /// Canonical edge list, i.e. the edge list of the graph relabeled by the canonical labeling.
+    pub canonical: EdgeList<usize>,
+}
+
+impl<'a, T> CanonicalLabeling<'a, T>
+where
+    T: Storage,
+{
+    /// Build a canonical labeling search.
+    ///
+    /// Build a canonical labeling search over a given graph, following the individualization-refinement
+    /// scheme of nauty[^1] and bliss[^2]. The vertex set is partitioned into cells by iterated degree refinement,
+    /// then the search tree is explored by individualizing each vertex of the first non-singleton cell in turn.
+    /// Each leaf of the search tree is a discrete partition, i.e. a labeling, and the canonical labeling is the one
+    /// yielding the lexicographically smallest edge list. Subtrees that are equivalent under the automorphisms
+    /// found so far are pruned.
+    ///
+    /// Both directed and undirected graphs are supported, including self-edges. Since the edges of undirected
+    /// graphs are returned in both directions, so are the edges of the canonical edge list.
+    ///
+    /// [^1]: [McKay, B. D., & Piperno, A. (2014). Practical graph isomorphism, II. Journal of Symbolic Computation, 60, 94-112.](https://scholar.google.com/scholar?q=Practical+graph+isomorphism%2C+II)
+    ///
+    /// [^2]: [Junttila, T., & Kaski, P. (2007). Engineering an efficient canonical labeling tool for large and sparse graphs. In 2007 Proceedings of the Ninth Workshop on Algorithm Engineering and Experiments (ALENEX) (pp. 135-149).](https://scholar.google.com/scholar?q=Engineering+an+efficient+canonical+labeling+tool+for+large+and+sparse+graphs)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build two relabeled copies of a path.
+    /// let g = Graph::from_edges([(0, 1), (1, 2), (2, 3)]);
+    /// let h = Graph::from_edges([(7, 5), (5, 9), (9, 3)]);
+    ///
+    /// // Build the search objects over said graphs.
+    /// let mut search_g = CanonicalLabeling::from(&g);
+    /// let mut search_h = CanonicalLabeling::from(&h);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search_g.run();
+    /// search_h.run();
+    ///
+    /// // Isomorphic graphs share the same canonical edge list.
+    /// assert_eq!(search_g.canonical, search_h.canonical);
+    ///
+    /// // Build a star, which has the same order and size.
+    /// let k = Graph::from_edges([(0, 1), (0, 2), (0, 3)]);
+    /// let mut search_k = CanonicalLabeling::from(&k);
+    /// search_k.run();
+    ///
+    /// // Non-isomorphic graphs have different canonical edge lists.
+    /// assert_ne!(search_g.canonical, search_k.canonical);
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the out-going adjacency.
+            out: Default::default(),
+            // Initialize the in-coming adjacency.
+            inc: Default::default(),
+            // Initialize the best labeling.
+            best: None,
+            // Initialize the automorphisms.
+            automorphisms: Default::default(),
+            // Initialize the canonical labeling.
+            labeling: Default::default(),
+            // Initialize the canonical edge list.
+            canonical: Default::default(),
+        }
+    }
+
+    /// Refine a given partition until it is equitable.
+    ///
+    /// Each cell is split according to the number of out-going and in-coming neighbors of its vertices
+    /// in every cell, with the resulting cells ordered by said counts. Since the split depends only
+    /// on the structure of the graph, the refinement commutes with relabeling.
+    ///
+    fn refine(&self, p: &mut Partition) {
+        loop {
+            // Map each vertex to its cell.
+            let mut cell = vec![0; self.out.len()];
+            for (i, c) in p.iter().enumerate() {
+                for &x in c {
+                    cell[x] = i;
+                }
+            }
+            // Split each cell by the neighbor counts of its vertices.
+            let mut q = Partition::with_capacity(p.len());
+            for c in p.iter() {
+                let mut split: BTreeMap<_, Vec<_>> = BTreeMap::new();
+                for &x in c {
+                    let mut counts = BTreeMap::new();
+                    for &y in &self.out[x] {
+                        *counts.entry((cell[y], false)).or_insert(0) += 1;
+                    }
+                    for &y in &self.inc[x] {
+                        *counts.entry((cell[y], true)).or_insert(0) += 1;
+                    }
+                    split.entry(counts.into_iter().collect::<Vec<_>>()).or_default().push(x);
+                }
+                q.extend(split.into_values());
+            }
+            // If no cell has been split, then the partition is equitable.
+            if q.len() == p.len() {
+                break;
+            }
+            *p = q;
+        }
+    }
+
+    /// Compute the edge list induced by a given labeling.
+    fn relabel(&self, l: &[usize]) -> EdgeList<usize> {
+        self.out
+            .iter()
+            .enumerate()
+            .flat_map(|(x, ys)| ys.iter().map(move |&y| (l[x], l[y])))
+            .collect()
+    }
+
+    /// Explore the search tree rooted in a given partition, with a given sequence of individualized vertices.
+    fn search(&mut self, mut p: Partition, prefix: &mut Vec<usize>) {
+        // Refine the partition.
+        self.refine(&mut p);
+        // Get the first non-singleton cell, if any.
+        let i = match p.iter().position(|c| c.len() > 1) {
+            Some(i) => i,
+            // Otherwise, the partition is discrete, hence a leaf.
+            None => return self.leaf(&p),
+        };
+        // Individualize each vertex of the target cell.
+        let mut explored: Vec<usize> = Vec::new();
+        for &x in &p[i] {
+            // Compute the orbits of the automorphisms that fix the individualized vertices.
+            let mut orbits = DisjointSet::new(self.out.len());
+            for a in self.automorphisms.iter().filter(|a| prefix.iter().all(|&y| a[y] == y)) {
+                for (y, &z) in a.iter().enumerate() {
+                    orbits.union(y, z);
+                }
+            }
+            // If the vertex is equivalent to an explored one, then its subtree is equivalent too.
+            let r = orbits.find(x);
+            if explored.iter().any(|&y| orbits.find(y) == r) {
+                continue;
+            }
+            // Split the target cell, individualizing the vertex first.
+            let mut q = Partition::with_capacity(p.len() + 1);
+            q.extend(p[..i].iter().cloned());
+            q.push(vec![x]);
+            q.push(p[i].iter().copied().filter(|&y| y != x).collect());
+            q.extend(p[i + 1..].iter().cloned());
+            // Explore the subtree.
+            prefix.push(x);
+            self.search(q, prefix);
+            prefix.pop();
+            explored.push(x);
+        }
+    }
+
+    /// Compare the labeling of a given discrete partition against the best one.
+    fn leaf(&mut self, p: &Partition) {
+        // Label each vertex with the position of its cell.
+        let mut l = vec![0; self.out.len()];
+        for (i, c) in p.iter().enumerate() {
+            l[c[0]] = i;
+        }
+        // Compare the induced edge list with the best one.
+        let e = self.relabel(&l);
+        match &self.best {
+            // If the edge lists are equal, then the labelings differ by an automorphism.
+            Some(b) if e == self.canonical => {
+                let mut inverse = vec![0; b.len()];
+                for (x, &i) in b.iter().enumerate() {
+                    inverse[i] = x;
+                }
+                let a: Vec<_> = l.iter().map(|&i| inverse[i]).collect();
+                if a.iter().enumerate().any(|(x, &y)| x != y) {
+                    self.automorphisms.push(a);
+                }
+            }
+            // If the edge list is greater, then discard it.
+            Some(_) if e > self.canonical => {}
+            // Otherwise, set it as the best one.
+            _ => {
+                self.best = Some(l);
+                self.canonical = e;
+            }
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(self.graph).collect();
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Compute the adjacency of each vertex.
+        self.out = vec![HashSet::new(); vertices.len()];
+        self.inc = vec![HashSet::new(); vertices.len()];
+        for (x, y) in self.graph.edges_iter() {
+            self.out[index[x]].insert(index[y]);
+            self.inc[index[y]].insert(index[x]);
+        }
+        // Explore the search tree, starting from the unit partition, if any.
+        let p = match vertices.is_empty() {
+            true => vec![],
+            false => vec![(0..vertices.len()).collect()],
+        };
+        self.search(p, &mut vec![]);
+        // Map each vertex to its canonical label.
+        if let Some(b) = self.best.take() {
+            self.labeling = vertices.into_iter().zip(b).collect();
+        }
+
+        self
+    }
+}
+
+impl<'a, T> From<&'a T> for CanonicalLabeling<'a, T>
+where
+    T: Storage,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
@@ -0,0 +1,11 @@
+mod canonical_labeling;
+pub use canonical_labeling::CanonicalLabeling;
+
+mod motif_count;
+pub use motif_count::MotifCount;
+
+mod vf2;
+pub use vf2::VF2;
+
+mod weisfeiler_lehman;
+pub use weisfeiler_lehman::WeisfeilerLehman;
@@ -0,0 +1,251 @@
+use crate::traits::Storage;
+use crate::types::EdgeList;
+use crate::V;
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::vec::Vec;
+
+/// Motif count structure.
+///
+/// This structure contains the number of occurrences of each `motifs` of a given size,
+/// i.e. of each connected graph with the given number of vertices, up to isomorphism.
+///
+pub struct MotifCount<'a, T>
+where
+    T: Storage,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Number of vertices of each motif.
+    size: usize,
+    /// Whether the graph is undirected.
+    undirected: bool,
+    /// Number of induced occurrences of each motif, where each motif is given
+    /// as its canonical edge list over the vertices in $[0, size)$.
+    pub motifs: BTreeMap<EdgeList<usize>, usize>,
+}
+
+impl<'a, T> MotifCount<'a, T>
+where
+    T: Storage,
+{
+    /// Build a motif count structure for directed graphs.
+    ///
+    /// Build a motif count structure, which counts the connected induced subgraphs of a given graph with three
+    /// or four vertices, grouped by isomorphism class. The subgraphs are enumerated with the ESU[^1] algorithm,
+    /// while the isomorphism classes are identified by their canonical edge lists, i.e. the lexicographically
+    /// smallest edge lists among the relabelings of the vertices. Self-edges are ignored.
+    ///
+    /// Every motif is reported, even if it does not occur in the graph, e.g. there are 13 directed motifs
+    /// with three vertices and 199 with four.
+    ///
+    /// [^1]: [Wernicke, S. (2006). Efficient detection of network motifs. IEEE/ACM Transactions on Computational Biology and Bioinformatics, 3(4), 347-359.](https://scholar.google.com/scholar?q=Efficient+detection+of+network+motifs)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the motif size is neither three nor four.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a directed graph with a feed-forward loop.
+    /// let g = DiGraph::from_edges([(0, 1), (1, 2), (0, 2), (2, 3)]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = MotifCount::from((&g, 3));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // Every directed motif with three vertices is reported.
+    /// assert_eq!(search.motifs.len(), 13);
+    ///
+    /// // The feed-forward loop occurs once.
+    /// assert_eq!(search.motifs[&BTreeSet::from([(0, 1), (0, 2), (1, 2)])], 1);
+    /// ```
+    ///
+    pub fn new(g: &'a T, k: usize) -> Self {
+        // Assert motif size is supported.
+        assert!(k == 3 || k == 4, "motif size must be either three or four");
+
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set motif size.
+            size: k,
+            // Set the graph as directed.
+            undirected: false,
+            // Initialize the motifs counts.
+            motifs: Default::default(),
+        }
+    }
+
+    /// Build a motif count structure for undirected graphs.
+    ///
+    /// Build a motif count structure, where the edges are undirected and each canonical edge list
+    /// contains each edge once, with the lowest endpoint first. There are 2 undirected motifs
+    /// with three vertices and 6 with four.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the motif size is neither three nor four.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build two triangles sharing an edge.
+    /// let g = Graph::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = MotifCount::from((&g, 3));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // There are two induced paths and two triangles.
+    /// assert_eq!(search.motifs[&BTreeSet::from([(0, 1), (0, 2)])], 2);
+    /// assert_eq!(search.motifs[&BTreeSet::from([(0, 1), (0, 2), (1, 2)])], 2);
+    /// ```
+    ///
+    pub fn new_undirected(g: &'a T, k: usize) -> Self {
+        Self {
+            // Set the graph as undirected.
+            undirected: true,
+            // Initialize the remaining fields.
+            ..Self::new(g, k)
+        }
+    }
+
+    /// Vertex pairs that can be joined by an edge, in order.
+    fn pairs(&self) -> Vec<(usize, usize)> {
+        (0..self.size)
+            .cartesian_product(0..self.size)
+            .filter(|(a, b)| match self.undirected {
+                true => a < b,
+                false => a != b,
+            })
+            .collect()
+    }
+
+    /// Canonical edge list of a given edge set, encoded as a bitmask over the vertex pairs.
+    fn canonical(&self, pairs: &[(usize, usize)], mask: usize) -> EdgeList<usize> {
+        (0..self.size)
+            .permutations(self.size)
+            .map(|p| {
+                pairs
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| mask >> i & 1 == 1)
+                    .map(|(_, &(a, b))| match self.undirected {
+                        true => (p[a].min(p[b]), p[a].max(p[b])),
+                        false => (p[a], p[b]),
+                    })
+                    .collect()
+            })
+            .min()
+            .unwrap()
+    }
+
+    /// Check whether a given edge set, encoded as a bitmask over the vertex pairs, is (weakly) connected.
+    fn is_connected(&self, pairs: &[(usize, usize)], mask: usize) -> bool {
+        let mut visited = BTreeSet::from([0]);
+        let mut stack = vec![0];
+        while let Some(a) = stack.pop() {
+            for (_, &(b, c)) in pairs.iter().enumerate().filter(|(i, _)| mask >> i & 1 == 1) {
+                for (x, y) in [(b, c), (c, b)] {
+                    if x == a && visited.insert(y) {
+                        stack.push(y);
+                    }
+                }
+            }
+        }
+
+        visited.len() == self.size
+    }
+
+    /// Extend a given connected vertex set with the vertices in its extension set, following the ESU algorithm.
+    fn extend<F>(&self, neighbors: &[BTreeSet<usize>], v: usize, s: &mut Vec<usize>, ext: Vec<usize>, f: &mut F)
+    where
+        F: FnMut(&[usize]),
+    {
+        // If the vertex set is complete, then report it.
+        if s.len() == self.size {
+            f(s);
+            return;
+        }
+        // For each vertex in the extension set, in order.
+        for (i, &w) in ext.iter().enumerate() {
+            // Extend the remaining extension set with the exclusive neighbors of the vertex,
+            // i.e. the ones that are greater than the root and not adjacent to the vertex set.
+            let exclusive: Vec<_> = neighbors[w]
+                .iter()
+                .copied()
+                .filter(|&u| u > v && !s.contains(&u) && s.iter().all(|&x| !neighbors[x].contains(&u)))
+                .filter(|u| !ext[i + 1..].contains(u))
+                .collect();
+            let mut next = ext[i + 1..].to_vec();
+            next.extend(exclusive);
+            s.push(w);
+            self.extend(neighbors, v, s, next, f);
+            s.pop();
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(self.graph).collect();
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Compute the adjacency of each vertex, skipping self-edges.
+        let mut adjacents = vec![HashSet::new(); vertices.len()];
+        let mut neighbors = vec![BTreeSet::new(); vertices.len()];
+        for (x, y) in self.graph.edges_iter().filter(|(x, y)| x != y) {
+            let (i, j) = (index[x], index[y]);
+            adjacents[i].insert(j);
+            neighbors[i].insert(j);
+            neighbors[j].insert(i);
+        }
+        // Get the vertex pairs.
+        let pairs = self.pairs();
+        // Initialize the count of every connected motif.
+        let mut canonical = HashMap::new();
+        let masks: Vec<_> = (0..1 << pairs.len())
+            .filter(|&m| self.is_connected(&pairs, m))
+            .collect();
+        for mask in masks {
+            let c = self.canonical(&pairs, mask);
+            canonical.insert(mask, c.clone());
+            self.motifs.insert(c, 0);
+        }
+        // Enumerate the connected vertex sets, rooted in their lowest vertex.
+        let mut counts = HashMap::new();
+        for v in 0..vertices.len() {
+            let ext = neighbors[v].iter().copied().filter(|&u| u > v).collect();
+            self.extend(&neighbors, v, &mut vec![v], ext, &mut |s: &[usize]| {
+                // Encode the induced edge set as a bitmask over the vertex pairs.
+                let mask = pairs
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &(a, b))| adjacents[s[a]].contains(&s[b]))
+                    .fold(0, |m, (i, _)| m | 1 << i);
+                *counts.entry(mask).or_insert(0) += 1;
+            });
+        }
+        // Group the counts by isomorphism class.
+        for (mask, n) in counts {
+            *self.motifs.get_mut(&canonical[&mask]).unwrap() += n;
+        }
+
+        self
+    }
+}
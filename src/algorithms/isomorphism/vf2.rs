@@ -0,0 +1,527 @@
+use crate::traits::Storage;
+use crate::V;
+use std::collections::{HashMap, HashSet};
+use std::iter::FusedIterator;
+use std::vec::Vec;
+
+/// Matching state of one of the two graphs.
+struct State<'a, V> {
+    /// Vertices of the graph, in order.
+    vertices: Vec<&'a V>,
+    /// Out-going adjacency of each vertex, including self-edges.
+    out: Vec<HashSet<usize>>,
+    /// In-coming adjacency of each vertex, including self-edges.
+    inc: Vec<HashSet<usize>>,
+    /// Neighbors of each vertex, regardless of direction and without self-edges.
+    neighbors: Vec<Vec<usize>>,
+    /// Vertex of the other graph each vertex is mapped to, if any.
+    core: Vec<Option<usize>>,
+    /// Depth at which each vertex entered the terminal set, i.e. the unmapped neighbors of the mapped vertices.
+    terminal: Vec<usize>,
+    /// Number of mapped vertices.
+    depth: usize,
+}
+
+impl<'a, V> State<'a, V> {
+    fn new<T>(g: &'a T) -> Self
+    where
+        T: Storage<Vertex = V>,
+        V: crate::types::Vertex,
+    {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(g).collect();
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Compute the adjacency of each vertex.
+        let (mut out, mut inc) = (
+            vec![HashSet::new(); vertices.len()],
+            vec![HashSet::new(); vertices.len()],
+        );
+        for (x, y) in g.edges_iter() {
+            out[index[x]].insert(index[y]);
+            inc[index[y]].insert(index[x]);
+        }
+        let neighbors = (0..vertices.len())
+            .map(|i| {
+                let mut n: Vec<_> = out[i].union(&inc[i]).copied().filter(|&j| j != i).collect();
+                n.sort_unstable();
+                n
+            })
+            .collect();
+
+        Self {
+            core: vec![None; vertices.len()],
+            terminal: vec![0; vertices.len()],
+            depth: 0,
+            vertices,
+            out,
+            inc,
+            neighbors,
+        }
+    }
+
+    fn has_edge(&self, x: usize, y: usize) -> bool {
+        self.out[x].contains(&y)
+    }
+
+    /// Count the unmapped neighbors of a vertex, both inside and outside the terminal set.
+    fn lookahead(&self, x: usize) -> (usize, usize) {
+        self.neighbors[x]
+            .iter()
+            .filter(|&&y| self.core[y].is_none())
+            .fold((0, 0), |(t, n), &y| match self.terminal[y] > 0 {
+                true => (t + 1, n),
+                false => (t, n + 1),
+            })
+    }
+
+    /// Get the next vertex to be mapped, i.e. the lowest one in the terminal set, if any, or the lowest unmapped one.
+    fn next_vertex(&self) -> Option<usize> {
+        let mut unmapped = (0..self.vertices.len()).filter(|&x| self.core[x].is_none());
+        let first = unmapped.clone().next();
+        unmapped.find(|&x| self.terminal[x] > 0).or(first)
+    }
+
+    fn push(&mut self, x: usize, y: usize) {
+        // Map the vertex.
+        self.core[x] = Some(y);
+        self.depth += 1;
+        // Extend the terminal set with its neighbors.
+        let d = self.depth;
+        for &z in self.neighbors[x].iter().chain([&x]) {
+            if self.terminal[z] == 0 {
+                self.terminal[z] = d;
+            }
+        }
+    }
+
+    fn pop(&mut self, x: usize) {
+        // Restore the terminal set.
+        let d = self.depth;
+        for t in self.terminal.iter_mut().filter(|t| **t == d) {
+            *t = 0;
+        }
+        // Unmap the vertex.
+        self.depth -= 1;
+        self.core[x] = None;
+    }
+}
+
+/// Matching problem.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Problem {
+    /// Graph isomorphism, i.e. a bijection preserving both adjacency and non-adjacency.
+    Isomorphism,
+    /// Induced subgraph isomorphism, i.e. an injection preserving both adjacency and non-adjacency.
+    InducedSubgraph,
+    /// Subgraph isomorphism (or monomorphism), i.e. an injection preserving adjacency.
+    Subgraph,
+}
+
+/// Search frame, i.e. a vertex of the first graph and its candidates in the second graph.
+struct Frame {
+    /// Vertex of the first graph.
+    x: usize,
+    /// Candidate vertices of the second graph.
+    candidates: Vec<usize>,
+    /// Index of the next candidate to be checked.
+    next: usize,
+    /// Candidate currently mapped, if any.
+    mapped: Option<usize>,
+}
+
+/// VF2 search structure.
+///
+/// This structure is an iterator over the isomorphisms between two graphs, where each isomorphism
+/// is a map from the vertices of the first graph to the vertices of the second one.
+/// The first graph can also be matched against the subgraphs of the second one, see
+/// [`new_subgraph`](VF2::new_subgraph) and [`new_induced_subgraph`](VF2::new_induced_subgraph).
+///
+pub struct VF2<'a, T, F, G>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> bool,
+    G: Fn((&'a T::Vertex, &'a T::Vertex), (&'a T::Vertex, &'a T::Vertex)) -> bool,
+{
+    /// Matching state of the first graph.
+    first: State<'a, T::Vertex>,
+    /// Matching state of the second graph.
+    second: State<'a, T::Vertex>,
+    /// Matching problem.
+    problem: Problem,
+    /// Vertex compatibility predicate.
+    vertex_match: F,
+    /// Edge compatibility predicate.
+    edge_match: G,
+    /// Search stack.
+    stack: Vec<Frame>,
+    /// Whether the search has started.
+    started: bool,
+}
+
+impl<'a, T, F, G> VF2<'a, T, F, G>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> bool,
+    G: Fn((&'a T::Vertex, &'a T::Vertex), (&'a T::Vertex, &'a T::Vertex)) -> bool,
+{
+    /// Build a VF2 iterator.
+    ///
+    /// Build a VF2[^1] iterator over the isomorphisms between two given graphs, i.e. the bijections between
+    /// their vertex sets that preserve both adjacency and non-adjacency. Both directed and undirected graphs
+    /// are supported, including self-edges.
+    ///
+    /// Matched vertices and edges must also satisfy the given predicates, which allows to compare their
+    /// attributes, if any. Edge pairs are given in the same direction, i.e. an edge $(X, Y)$ of the first graph
+    /// is compared with the edge $(f(X), f(Y))$ of the second graph.
+    ///
+    /// [^1]: [Cordella, L. P., Foggia, P., Sansone, C., & Vento, M. (2004). A (sub)graph isomorphism algorithm for matching large graphs. IEEE Transactions on Pattern Analysis and Machine Intelligence, 26(10), 1367-1372.](https://scholar.google.com/scholar?q=A+%28sub%29graph+isomorphism+algorithm+for+matching+large+graphs)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::UndirectedAdjacencyListGraph;
+    ///
+    /// // Build two paths with labelled vertices.
+    /// type G = UndirectedAdjacencyListGraph<i32, AttributesMap<i32, (), char, ()>>;
+    /// let mut g = G::from_edges([(0, 1), (1, 2)]);
+    /// let mut h = G::from_edges([(5, 3), (3, 4)]);
+    /// for (x, c) in [(0, 'a'), (1, 'b'), (2, 'c')] {
+    ///     g.set_vertex_attrs(&x, c);
+    /// }
+    /// for (x, c) in [(3, 'b'), (4, 'a'), (5, 'c')] {
+    ///     h.set_vertex_attrs(&x, c);
+    /// }
+    ///
+    /// // Ignoring the labels, a path can be mapped onto itself in two ways.
+    /// assert_eq!(VF2::from((&g, &h)).count(), 2);
+    ///
+    /// // Build the search object over said graphs, comparing labels.
+    /// let mut search = VF2::from((
+    ///     &g,
+    ///     &h,
+    ///     |x, y| g.get_vertex_attrs(x).unwrap() == h.get_vertex_attrs(y).unwrap(),
+    ///     |_, _| true,
+    /// ));
+    ///
+    /// // Only one isomorphism preserves labels.
+    /// assert_eq!(search.next(), Some(HashMap::from([(&0, &4), (&1, &3), (&2, &5)])));
+    /// assert_eq!(search.next(), None);
+    /// ```
+    ///
+    pub fn new(g: &'a T, h: &'a T, f: F, e: G) -> Self {
+        Self {
+            // Initialize the state of the first graph.
+            first: State::new(g),
+            // Initialize the state of the second graph.
+            second: State::new(h),
+            // Set the matching problem.
+            problem: Problem::Isomorphism,
+            // Set vertex compatibility predicate.
+            vertex_match: f,
+            // Set edge compatibility predicate.
+            edge_match: e,
+            // Initialize the search stack.
+            stack: Default::default(),
+            // Initialize the search flag.
+            started: false,
+        }
+    }
+
+    /// Build a VF2 iterator for induced subgraph isomorphism.
+    ///
+    /// Build a VF2 iterator over the induced subgraph isomorphisms of a given pattern graph into a given target graph,
+    /// i.e. the injective maps from the vertices of the pattern to the vertices of the target that preserve both
+    /// adjacency and non-adjacency. Equivalently, each map is an isomorphism between the pattern and
+    /// an induced subgraph of the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a path pattern and a square with a chord.
+    /// let p = Graph::from_edges([(0, 1), (1, 2)]);
+    /// let g = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+    ///
+    /// // Build the search object over said graphs.
+    /// let search = VF2::new_induced_subgraph(&p, &g, |_, _| true, |_, _| true);
+    ///
+    /// // The only induced paths are 1-0-3 and 1-2-3, in both directions.
+    /// assert_eq!(search.count(), 4);
+    /// ```
+    ///
+    pub fn new_induced_subgraph(g: &'a T, h: &'a T, f: F, e: G) -> Self {
+        Self {
+            // Set the matching problem.
+            problem: Problem::InducedSubgraph,
+            // Initialize the remaining fields.
+            ..Self::new(g, h, f, e)
+        }
+    }
+
+    /// Build a VF2 iterator for subgraph isomorphism.
+    ///
+    /// Build a VF2 iterator over the subgraph isomorphisms (or monomorphisms) of a given pattern graph into
+    /// a given target graph, i.e. the injective maps from the vertices of the pattern to the vertices of the target
+    /// that preserve adjacency. Equivalently, each map is an isomorphism between the pattern and
+    /// a (not necessarily induced) subgraph of the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a path pattern and a square with a chord.
+    /// let p = Graph::from_edges([(0, 1), (1, 2)]);
+    /// let g = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+    ///
+    /// // Build the search object over said graphs.
+    /// let search = VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true);
+    ///
+    /// // Each vertex is the middle of a path for each ordered pair of its neighbors.
+    /// assert_eq!(search.count(), 2 * 3 + 2 * 1 + 2 * 3 + 2 * 1);
+    /// ```
+    ///
+    pub fn new_subgraph(g: &'a T, h: &'a T, f: F, e: G) -> Self {
+        Self {
+            // Set the matching problem.
+            problem: Problem::Subgraph,
+            // Initialize the remaining fields.
+            ..Self::new(g, h, f, e)
+        }
+    }
+
+    /// Checks isomorphism.
+    ///
+    /// Checks whether there is an isomorphism between two given graphs that satisfies the given predicates,
+    /// running a dedicated search that stops at the first isomorphism found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a path and a relabeled copy of it.
+    /// let g = Graph::from_edges([(0, 1), (1, 2)]);
+    /// let h = Graph::from_edges([(5, 3), (3, 4)]);
+    ///
+    /// // The two paths are isomorphic.
+    /// assert!(VF2::is_isomorphic(&g, &h, |_, _| true, |_, _| true));
+    ///
+    /// // Build a triangle, which is not isomorphic to a path.
+    /// let h = Graph::from_edges([(0, 1), (1, 2), (2, 0)]);
+    /// assert!(!VF2::is_isomorphic(&g, &h, |_, _| true, |_, _| true));
+    /// ```
+    ///
+    pub fn is_isomorphic(g: &'a T, h: &'a T, f: F, e: G) -> bool {
+        Self::new(g, h, f, e).next().is_some()
+    }
+
+    /// Check whether two graphs can be matched, comparing their orders and, for isomorphism, their degree sequences.
+    fn is_compatible(&self) -> bool {
+        if self.problem != Problem::Isomorphism {
+            return self.first.vertices.len() <= self.second.vertices.len();
+        }
+        let degrees = |s: &State<'a, T::Vertex>| {
+            let mut d: Vec<_> = (0..s.vertices.len())
+                .map(|x| (s.out[x].len(), s.inc[x].len()))
+                .collect();
+            d.sort_unstable();
+            d
+        };
+        degrees(&self.first) == degrees(&self.second)
+    }
+
+    /// Build the next search frame, if any vertex of the first graph is left to be mapped.
+    fn frame(&self) -> Option<Frame> {
+        // Select the next vertex of the first graph.
+        let x = self.first.next_vertex()?;
+        // Select the candidates in the second graph, i.e. the ones in the terminal set if the vertex is,
+        // otherwise the ones not in the terminal set, unless non-adjacency is not preserved.
+        let terminal = self.first.terminal[x] > 0;
+        let candidates = (0..self.second.vertices.len())
+            .filter(|&y| self.second.core[y].is_none())
+            .filter(|&y| match (terminal, self.problem) {
+                (false, Problem::Subgraph) => true,
+                _ => (self.second.terminal[y] > 0) == terminal,
+            })
+            .collect();
+
+        Some(Frame {
+            x,
+            candidates,
+            next: 0,
+            mapped: None,
+        })
+    }
+
+    /// Check whether a pair of vertices can be added to the current mapping.
+    fn is_feasible(&self, x: usize, y: usize) -> bool {
+        let (g, h) = (&self.first, &self.second);
+        // Check the vertices are compatible.
+        if !(self.vertex_match)(g.vertices[x], h.vertices[y]) {
+            return false;
+        }
+        // Check the self-edges are preserved.
+        match (g.has_edge(x, x), h.has_edge(y, y)) {
+            (true, true) => {
+                let (a, b) = (g.vertices[x], h.vertices[y]);
+                if !(self.edge_match)((a, a), (b, b)) {
+                    return false;
+                }
+            }
+            (false, false) => {}
+            (false, true) if self.problem == Problem::Subgraph => {}
+            _ => return false,
+        }
+        // Check the edges to the mapped vertices are preserved, in both directions.
+        let (mut mapped_g, mut mapped_h) = (0, 0);
+        for &z in g.neighbors[x].iter() {
+            if let Some(w) = g.core[z] {
+                mapped_g += 1;
+                for ((a, b), (c, d)) in [((x, z), (y, w)), ((z, x), (w, y))] {
+                    match (g.has_edge(a, b), h.has_edge(c, d)) {
+                        (true, true) => {
+                            let (a, b) = (g.vertices[a], g.vertices[b]);
+                            let (c, d) = (h.vertices[c], h.vertices[d]);
+                            if !(self.edge_match)((a, b), (c, d)) {
+                                return false;
+                            }
+                        }
+                        (false, false) => {}
+                        (false, true) if self.problem == Problem::Subgraph => {}
+                        _ => return false,
+                    }
+                }
+            }
+        }
+        // Check the mapped neighbors are in one-to-one correspondence, if non-adjacency is preserved.
+        for &w in h.neighbors[y].iter() {
+            if h.core[w].is_some() {
+                mapped_h += 1;
+            }
+        }
+        if self.problem != Problem::Subgraph && mapped_g != mapped_h {
+            return false;
+        }
+        // Check the unmapped neighbors, both inside and outside the terminal sets.
+        let ((t_g, n_g), (t_h, n_h)) = (g.lookahead(x), h.lookahead(y));
+        match self.problem {
+            Problem::Isomorphism => t_g == t_h && n_g == n_h,
+            Problem::InducedSubgraph => t_g <= t_h && n_g <= n_h,
+            // Neighbors outside the terminal set can be mapped to neighbors inside it.
+            Problem::Subgraph => t_g <= t_h && t_g + n_g <= t_h + n_h,
+        }
+    }
+
+    /// Get the current mapping.
+    fn mapping(&self) -> HashMap<&'a T::Vertex, &'a T::Vertex> {
+        (0..self.first.vertices.len())
+            .filter_map(|x| self.first.core[x].map(|y| (self.first.vertices[x], self.second.vertices[y])))
+            .collect()
+    }
+}
+
+impl<'a, T, F, G> Iterator for VF2<'a, T, F, G>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> bool,
+    G: Fn((&'a T::Vertex, &'a T::Vertex), (&'a T::Vertex, &'a T::Vertex)) -> bool,
+{
+    type Item = HashMap<&'a T::Vertex, &'a T::Vertex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Start the search.
+        if !self.started {
+            self.started = true;
+            // Check the graphs are compatible.
+            if !self.is_compatible() {
+                return None;
+            }
+            // Push the first frame, if any, otherwise the graphs are both null.
+            match self.frame() {
+                Some(frame) => self.stack.push(frame),
+                None => return Some(Default::default()),
+            }
+        }
+        // While there are frames on the stack.
+        while let Some(frame) = self.stack.last_mut() {
+            let x = frame.x;
+            // Undo the previous candidate, if any.
+            if let Some(y) = frame.mapped.take() {
+                self.first.pop(x);
+                self.second.pop(y);
+            }
+            // Find the next feasible candidate.
+            let frame = self.stack.last().unwrap();
+            let next = frame.candidates[frame.next..]
+                .iter()
+                .position(|&y| self.is_feasible(x, y))
+                .map(|i| frame.next + i);
+            let i = match next {
+                Some(i) => i,
+                // If no candidate is left, backtrack.
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            // Map the candidate.
+            let frame = self.stack.last_mut().unwrap();
+            let y = frame.candidates[i];
+            frame.next = i + 1;
+            frame.mapped = Some(y);
+            self.first.push(x, y);
+            self.second.push(y, x);
+            // If every vertex is mapped, then an isomorphism is found, otherwise go deeper.
+            match self.frame() {
+                Some(frame) => self.stack.push(frame),
+                None => return Some(self.mapping()),
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, T, F, G> FusedIterator for VF2<'a, T, F, G>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> bool,
+    G: Fn((&'a T::Vertex, &'a T::Vertex), (&'a T::Vertex, &'a T::Vertex)) -> bool,
+{
+}
+
+impl<'a, T> From<(&'a T, &'a T)>
+    for VF2<
+        'a,
+        T,
+        fn(&'a T::Vertex, &'a T::Vertex) -> bool,
+        fn((&'a T::Vertex, &'a T::Vertex), (&'a T::Vertex, &'a T::Vertex)) -> bool,
+    >
+where
+    T: Storage,
+{
+    /// Builds a search object from two given graphs, ignoring attributes.
+    ///
+    fn from((g, h): (&'a T, &'a T)) -> Self {
+        Self::new(g, h, |_, _| true, |_, _| true)
+    }
+}
+
+impl<'a, T, F, G> From<(&'a T, &'a T, F, G)> for VF2<'a, T, F, G>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> bool,
+    G: Fn((&'a T::Vertex, &'a T::Vertex), (&'a T::Vertex, &'a T::Vertex)) -> bool,
+{
+    /// Builds a search object from two given graphs, with vertex and edge compatibility predicates.
+    ///
+    fn from((g, h, f, e): (&'a T, &'a T, F, G)) -> Self {
+        Self::new(g, h, f, e)
+    }
+}
@@ -0,0 +1,197 @@
+use crate::traits::Storage;
+use crate::V;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Digest a given sequence of words into a label.
+///
+/// Words are fed in little-endian byte order to the 64-bit FNV-1a hash function, which is fully specified,
+/// hence labels do not depend on the platform nor on the compiler release.
+///
+fn digest<I: IntoIterator<Item = u64>>(words: I) -> u64 {
+    // Set the FNV-1a 64-bit offset basis.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in words.into_iter().flat_map(u64::to_le_bytes) {
+        // Xor the byte into the hash, then multiply by the FNV-1a 64-bit prime.
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    hash
+}
+
+/// Weisfeiler-Lehman hash structure.
+///
+/// This structure contains the Weisfeiler-Lehman `hash` of a given graph, along with the `labels`
+/// of its vertices after the last iteration.
+///
+pub struct WeisfeilerLehman<'a, T, F, G>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex) -> u64,
+    G: Fn(&'a T::Vertex, &'a T::Vertex) -> u64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Number of refinement iterations.
+    iterations: usize,
+    /// Vertex label function.
+    vertex_label: F,
+    /// Edge label function.
+    edge_label: G,
+    /// Label of each vertex after the last iteration.
+    pub labels: HashMap<&'a T::Vertex, u64>,
+    /// Graph hash.
+    pub hash: u64,
+}
+
+impl<'a, T, F, G> WeisfeilerLehman<'a, T, F, G>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex) -> u64,
+    G: Fn(&'a T::Vertex, &'a T::Vertex) -> u64,
+{
+    /// Build a Weisfeiler-Lehman hash.
+    ///
+    /// Build a Weisfeiler-Lehman[^1] hash over a given graph, with a given number of iterations. Each vertex
+    /// is initially labeled by the given vertex label function, then, at each iteration, it is relabeled
+    /// by digesting its current label together with the sorted labels of its out-going and in-coming neighbors,
+    /// each paired with the label of the corresponding edge. The graph hash digests the sorted labels of all
+    /// the iterations.
+    ///
+    /// Isomorphic graphs have the same hash, while the converse does not hold in general, e.g. for regular graphs
+    /// with the same order and degree. Labels are computed with the 64-bit FNV-1a hash function, hence both labels
+    /// and hash are stable across platforms and compiler releases.
+    ///
+    /// [^1]: [Shervashidze, N., Schweitzer, P., Van Leeuwen, E. J., Mehlhorn, K., & Borgwardt, K. M. (2011). Weisfeiler-lehman graph kernels. Journal of Machine Learning Research, 12(9).](https://scholar.google.com/scholar?q=Weisfeiler-lehman+graph+kernels)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::UndirectedAdjacencyListGraph;
+    ///
+    /// // Build two paths with labelled vertices.
+    /// type G = UndirectedAdjacencyListGraph<i32, AttributesMap<i32, (), u64, ()>>;
+    /// let mut g = G::from_edges([(0, 1), (1, 2)]);
+    /// let mut h = G::from_edges([(0, 1), (1, 2)]);
+    /// for (x, c) in [(0, 1), (1, 2), (2, 3)] {
+    ///     g.set_vertex_attrs(&x, c);
+    /// }
+    /// for (x, c) in [(0, 2), (1, 1), (2, 3)] {
+    ///     h.set_vertex_attrs(&x, c);
+    /// }
+    ///
+    /// // Ignoring the labels, the two graphs have the same hash.
+    /// let mut hash_g = WeisfeilerLehman::from(&g);
+    /// let mut hash_h = WeisfeilerLehman::from(&h);
+    /// assert_eq!(hash_g.run().hash, hash_h.run().hash);
+    ///
+    /// // Build the hash objects over said graphs, with vertex labels.
+    /// let mut hash_g = WeisfeilerLehman::new(&g, 3, |x| *g.get_vertex_attrs(x).unwrap(), |_, _| 0);
+    /// let mut hash_h = WeisfeilerLehman::new(&h, 3, |x| *h.get_vertex_attrs(x).unwrap(), |_, _| 0);
+    ///
+    /// // The middle vertices have different labels, hence the hashes differ.
+    /// assert_ne!(hash_g.run().hash, hash_h.run().hash);
+    /// ```
+    ///
+    pub fn new(g: &'a T, k: usize, f: F, e: G) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set number of iterations.
+            iterations: k,
+            // Set vertex label function.
+            vertex_label: f,
+            // Set edge label function.
+            edge_label: e,
+            // Initialize the vertex labels.
+            labels: Default::default(),
+            // Initialize the graph hash.
+            hash: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(self.graph).collect();
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Compute the labelled adjacency of each vertex.
+        let mut out = vec![vec![]; vertices.len()];
+        let mut inc = vec![vec![]; vertices.len()];
+        for (x, y) in self.graph.edges_iter() {
+            let w = (self.edge_label)(x, y);
+            out[index[x]].push((index[y], w));
+            inc[index[y]].push((index[x], w));
+        }
+        // Compute the initial labels.
+        let mut labels: Vec<_> = vertices.iter().map(|&x| digest([(self.vertex_label)(x)])).collect();
+        // Collect the labels of every iteration.
+        let mut history = labels.clone();
+        for _ in 0..self.iterations {
+            labels = (0..vertices.len())
+                .map(|i| {
+                    // Get the sorted labels of the neighbors, paired with the labels of the edges.
+                    let mut o: Vec<_> = out[i].iter().map(|&(j, w)| (w, labels[j])).collect();
+                    let mut n: Vec<_> = inc[i].iter().map(|&(j, w)| (w, labels[j])).collect();
+                    o.sort_unstable();
+                    n.sort_unstable();
+                    // Digest them together with the current label, prefixing each list with its length.
+                    let o = std::iter::once(o.len() as u64).chain(o.into_iter().flat_map(|(w, l)| [w, l]));
+                    let n = std::iter::once(n.len() as u64).chain(n.into_iter().flat_map(|(w, l)| [w, l]));
+                    digest(std::iter::once(labels[i]).chain(o).chain(n))
+                })
+                .collect();
+            history.extend(&labels);
+        }
+        // Digest the sorted labels into the graph hash.
+        history.sort_unstable();
+        self.hash = digest(history);
+        // Map each vertex to its label.
+        self.labels = vertices.into_iter().zip(labels).collect();
+
+        self
+    }
+}
+
+impl<'a, T> From<&'a T> for WeisfeilerLehman<'a, T, fn(&'a T::Vertex) -> u64, fn(&'a T::Vertex, &'a T::Vertex) -> u64>
+where
+    T: Storage,
+{
+    /// Builds a hash object from a given graph, ignoring attributes, with three iterations.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g, 3, |_| 0, |_, _| 0)
+    }
+}
+
+impl<'a, T> From<(&'a T, usize)>
+    for WeisfeilerLehman<'a, T, fn(&'a T::Vertex) -> u64, fn(&'a T::Vertex, &'a T::Vertex) -> u64>
+where
+    T: Storage,
+{
+    /// Builds a hash object from a given graph and number of iterations, ignoring attributes.
+    ///
+    fn from((g, k): (&'a T, usize)) -> Self {
+        Self::new(g, k, |_| 0, |_, _| 0)
+    }
+}
+
+impl<'a, T, F, G> From<(&'a T, usize, F, G)> for WeisfeilerLehman<'a, T, F, G>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex) -> u64,
+    G: Fn(&'a T::Vertex, &'a T::Vertex) -> u64,
+{
+    /// Builds a hash object from a given graph and number of iterations, with vertex and edge label functions.
+    ///
+    fn from((g, k, f, e): (&'a T, usize, F, G)) -> Self {
+        Self::new(g, k, f, e)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::traits::Undirected;
+use crate::types::EdgeList;
+use crate::V;
+use std::collections::{HashMap, VecDeque};
+use std::vec::Vec;
+
+/// Edmonds maximum cardinality matching structure.
+///
+/// This structure contains the maximum cardinality `matching` and the `mate` of each matched vertex.
+///
+pub struct EdmondsBlossom<'a, T>
+where
+    T: Undirected,
+{
+    /// Vertex set, in order.
+    vertices: Vec<&'a T::Vertex>,
+    /// Neighbors indices of each vertex.
+    neighbors: Vec<Vec<usize>>,
+    /// Mate index of each vertex, if any.
+    matched: Vec<Option<usize>>,
+    /// Base index of the blossom containing each vertex.
+    base: Vec<usize>,
+    /// Predecessor index of each vertex in the alternating tree, if any.
+    predecessor: Vec<Option<usize>>,
+    /// Mate of each matched vertex.
+    pub mate: HashMap<&'a T::Vertex, &'a T::Vertex>,
+    /// Set of matched edges, with the lowest endpoint first.
+    pub matching: EdgeList<&'a T::Vertex>,
+}
+
+impl<'a, T> EdmondsBlossom<'a, T>
+where
+    T: Undirected,
+{
+    /// Build an Edmonds maximum cardinality matching structure.
+    ///
+    /// Build an Edmonds[^1] maximum cardinality matching structure for a given graph. Augmenting paths
+    /// are searched by growing alternating trees, where odd cycles (i.e. *blossoms*) are contracted
+    /// into their base vertex.
+    ///
+    /// [^1]: [Edmonds, J. (1965). Paths, trees, and flowers. Canadian Journal of mathematics, 17, 449-467.](https://scholar.google.com/scholar?q=Paths%2C+trees%2C+and+flowers)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::types::EdgeList;
+    ///
+    /// // Build an undirected graph, i.e. two triangles joined by an edge.
+    /// let g = Graph::from_edges([
+    ///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)
+    /// ]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = EdmondsBlossom::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The matching is perfect.
+    /// assert_eq!(search.matching, EdgeList::from([(&0, &1), (&2, &3), (&4, &5)]));
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(g).collect();
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Compute the neighbors indices of each vertex.
+        let neighbors = vertices
+            .iter()
+            .map(|&x| g.neighbors_iter(x).map(|y| index[y]).collect())
+            .collect();
+
+        // Get the order of the graph.
+        let n = vertices.len();
+
+        Self {
+            // Set the vertex set.
+            vertices,
+            // Set the neighbors indices.
+            neighbors,
+            // Initialize the mate indices.
+            matched: vec![None; n],
+            // Initialize the base indices.
+            base: (0..n).collect(),
+            // Initialize the predecessor indices.
+            predecessor: vec![None; n],
+            // Initialize the mate map.
+            mate: Default::default(),
+            // Initialize the matching.
+            matching: Default::default(),
+        }
+    }
+
+    fn lowest_common_ancestor(&self, mut x: usize, mut y: usize) -> usize {
+        // Initialize the visited set.
+        let mut visited = vec![false; self.vertices.len()];
+        // Climb the alternating tree from the first vertex up to the root, marking the bases.
+        loop {
+            x = self.base[x];
+            visited[x] = true;
+            match self.matched[x] {
+                Some(z) => x = self.predecessor[z].unwrap(),
+                None => break,
+            }
+        }
+        // Climb the alternating tree from the second vertex until a marked base is found.
+        loop {
+            y = self.base[y];
+            if visited[y] {
+                return y;
+            }
+            y = self.predecessor[self.matched[y].unwrap()].unwrap();
+        }
+    }
+
+    fn mark_path(&mut self, blossom: &mut [bool], mut x: usize, b: usize, mut child: usize) {
+        // Walk from the vertex down to the base of the blossom.
+        while self.base[x] != b {
+            // Mark the bases along the path as part of the blossom.
+            blossom[self.base[x]] = true;
+            blossom[self.base[self.matched[x].unwrap()]] = true;
+            // Reverse the predecessor along the path.
+            self.predecessor[x] = Some(child);
+            child = self.matched[x].unwrap();
+            x = self.predecessor[child].unwrap();
+        }
+    }
+
+    fn find_path(&mut self, root: usize) -> Option<usize> {
+        // Initialize the visited set, i.e. the outer vertices.
+        let mut visited = vec![false; self.vertices.len()];
+        // Reset the alternating tree.
+        self.predecessor.iter_mut().for_each(|p| *p = None);
+        self.base.iter_mut().enumerate().for_each(|(i, b)| *b = i);
+        // Initialize the to-be-visited queue with the root.
+        visited[root] = true;
+        let mut queue = VecDeque::from([root]);
+        // Grow the alternating tree in breadth-first order.
+        while let Some(x) = queue.pop_front() {
+            for i in 0..self.neighbors[x].len() {
+                let y = self.neighbors[x][i];
+                // Skip edges inside a blossom and matched edges.
+                if self.base[x] == self.base[y] || self.matched[x] == Some(y) {
+                    continue;
+                }
+                // If the edge joins two outer vertices, then a blossom is found.
+                if y == root || self.matched[y].is_some_and(|z| self.predecessor[z].is_some()) {
+                    // Get the base of the blossom.
+                    let b = self.lowest_common_ancestor(x, y);
+                    // Mark the vertices of the blossom.
+                    let mut blossom = vec![false; self.vertices.len()];
+                    self.mark_path(&mut blossom, x, b, y);
+                    self.mark_path(&mut blossom, y, b, x);
+                    // Contract the blossom into its base.
+                    for z in 0..self.vertices.len() {
+                        if blossom[self.base[z]] {
+                            self.base[z] = b;
+                            // Any vertex in the blossom becomes outer.
+                            if !visited[z] {
+                                visited[z] = true;
+                                queue.push_back(z);
+                            }
+                        }
+                    }
+                // Otherwise, if the vertex is not in the tree yet, extend the tree.
+                } else if self.predecessor[y].is_none() {
+                    self.predecessor[y] = Some(x);
+                    // If the vertex is free, then an augmenting path is found.
+                    match self.matched[y] {
+                        None => return Some(y),
+                        Some(z) => {
+                            visited[z] = true;
+                            queue.push_back(z);
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // For each free vertex.
+        for root in 0..self.vertices.len() {
+            if self.matched[root].is_some() {
+                continue;
+            }
+            // Search for an augmenting path from it.
+            let mut y = self.find_path(root);
+            // Augment the matching along the path, if any.
+            while let Some(x) = y {
+                let p = self.predecessor[x].unwrap();
+                y = self.matched[p];
+                self.matched[x] = Some(p);
+                self.matched[p] = Some(x);
+            }
+        }
+        // Store the mate map.
+        self.mate = (0..self.vertices.len())
+            .filter_map(|i| self.matched[i].map(|j| (self.vertices[i], self.vertices[j])))
+            .collect();
+        // Store the matched edges.
+        self.matching = self.mate.iter().filter(|(x, y)| x < y).map(|(&x, &y)| (x, y)).collect();
+
+        self
+    }
+}
+
+impl<'a, T> From<&'a T> for EdmondsBlossom<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
@@ -0,0 +1,226 @@
+use crate::traits::Undirected;
+use crate::types::{EdgeList, Error};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// Hopcroft-Karp maximum bipartite matching structure.
+///
+/// This structure contains the maximum cardinality `matching`, the `mate` of each matched vertex
+/// and the minimum `vertex_cover` derived from the matching by König's theorem.
+///
+pub struct HopcroftKarp<'a, T>
+where
+    T: Undirected,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Color of each vertex, where the left side is the `0` color.
+    color: HashMap<&'a T::Vertex, usize>,
+    /// Layer of each vertex in the left side, during the current phase.
+    layer: HashMap<&'a T::Vertex, usize>,
+    /// Mate of each matched vertex.
+    pub mate: HashMap<&'a T::Vertex, &'a T::Vertex>,
+    /// Set of matched edges, with the lowest endpoint first.
+    pub matching: EdgeList<&'a T::Vertex>,
+    /// Minimum vertex cover.
+    pub vertex_cover: BTreeSet<&'a T::Vertex>,
+}
+
+impl<'a, T> HopcroftKarp<'a, T>
+where
+    T: Undirected,
+{
+    /// Build a Hopcroft-Karp maximum bipartite matching structure.
+    ///
+    /// Build a Hopcroft-Karp[^1] maximum bipartite matching structure for a given graph. At each phase,
+    /// a maximal set of vertex-disjoint shortest augmenting paths is found and used to augment the matching.
+    ///
+    /// [^1]: [Hopcroft, J. E., & Karp, R. M. (1973). An n^5/2 algorithm for maximum matchings in bipartite graphs. SIAM Journal on computing, 2(4), 225-231.](https://scholar.google.com/scholar?q=An+n%5E5%2F2+algorithm+for+maximum+matchings+in+bipartite+graphs)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    /// use grathe::types::EdgeList;
+    ///
+    /// // Build a bipartite graph.
+    /// let g = Graph::from_edges([
+    ///     (0, 3), (0, 4), (1, 3), (2, 3)
+    /// ]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = HopcroftKarp::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run().unwrap();
+    ///
+    /// // Vertices `1` and `2` compete for `3`, i.e. their only neighbor.
+    /// assert_eq!(search.matching, EdgeList::from([(&0, &4), (&1, &3)]));
+    /// assert!(!search.mate.contains_key(&2));
+    ///
+    /// // The minimum vertex cover has the same size of the maximum matching.
+    /// assert_eq!(search.vertex_cover, BTreeSet::from([&0, &3]));
+    /// ```
+    ///
+    pub fn new(g: &'a T) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Initialize the color map.
+            color: Default::default(),
+            // Initialize the layer map.
+            layer: Default::default(),
+            // Initialize the mate map.
+            mate: Default::default(),
+            // Initialize the matching.
+            matching: Default::default(),
+            // Initialize the vertex cover.
+            vertex_cover: Default::default(),
+        }
+    }
+
+    /// Search for a shortest augmenting path from a given free left vertex, and flip the matching along it.
+    ///
+    /// The path is searched depth-first through the layers with an explicit stack, so that
+    /// long paths do not overflow the call stack.
+    ///
+    fn augment(&mut self, x: &'a T::Vertex, shortest: usize) -> bool {
+        // Initialize the current path with the given vertex, where each left vertex is paired with
+        // its remaining neighbors and the right vertex it was left through, if any.
+        let mut path = vec![(x, self.graph.neighbors_iter(x), None)];
+        // While the current path is not empty.
+        while let Some((x, neighbors, via)) = path.last_mut() {
+            let (x, l) = (*x, self.layer[*x]);
+            // Get the next neighbor of the current left vertex, if any.
+            match neighbors.next() {
+                Some(y) => match self.mate.get(y) {
+                    // A free right vertex ends a shortest augmenting path only in the last layer.
+                    None if l == shortest => {
+                        *via = Some(y);
+                        // Flip the matching along the path, removing its vertices from the layers
+                        // so that the paths of the current phase are vertex-disjoint.
+                        for (x, _, y) in path {
+                            let y = y.unwrap();
+                            self.mate.insert(x, y);
+                            self.mate.insert(y, x);
+                            self.layer.remove(x);
+                        }
+                        return true;
+                    }
+                    // Otherwise, continue through its mate, if it is in the next layer.
+                    Some(&z) if l < shortest && self.layer.get(z) == Some(&(l + 1)) => {
+                        *via = Some(y);
+                        path.push((z, self.graph.neighbors_iter(z), None));
+                    }
+                    _ => {}
+                },
+                // If the vertex is exhausted, remove it from the layers of the current phase and backtrack.
+                None => {
+                    self.layer.remove(x);
+                    path.pop();
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    /// # Errors
+    ///
+    /// If the graph is not bipartite, then returns an error with an odd cycle as witness.
+    ///
+    pub fn run(&mut self) -> Result<&Self, Error<T::Vertex>> {
+        // Compute the bipartition, if any.
+        self.color = self
+            .graph
+            .is_bipartite()
+            .map_err(|c| Error::NotBipartite(c.into_iter().cloned().collect()))?;
+        // Get the left side of the bipartition.
+        let left: Vec<_> = self.graph.vertices_iter().filter(|x| self.color[x] == 0).collect();
+        // Repeat until no augmenting path is found.
+        loop {
+            // Compute the layers from the free left vertices in breadth-first order.
+            self.layer = left
+                .iter()
+                .filter(|x| !self.mate.contains_key(*x))
+                .map(|&x| (x, 0))
+                .collect();
+            let mut queue: VecDeque<_> = left.iter().filter(|x| self.layer.contains_key(*x)).copied().collect();
+            // Initialize the layer of the left vertices adjacent to a free right vertex, if any.
+            let mut shortest = None;
+            while let Some(x) = queue.pop_front() {
+                let l = self.layer[x];
+                // Stop expanding past the layer where the shortest augmenting paths end.
+                if shortest.is_some_and(|s| l > s) {
+                    break;
+                }
+                for y in self.graph.neighbors_iter(x) {
+                    match self.mate.get(y) {
+                        // A free right vertex ends a shortest augmenting path.
+                        None => shortest = shortest.or(Some(l)),
+                        // Otherwise, continue through its mate.
+                        Some(&z) => {
+                            if !self.layer.contains_key(z) {
+                                self.layer.insert(z, l + 1);
+                                queue.push_back(z);
+                            }
+                        }
+                    }
+                }
+            }
+            // If no augmenting path exists, the matching is maximum.
+            let shortest = match shortest {
+                Some(s) => s,
+                None => break,
+            };
+            // Augment along a maximal set of vertex-disjoint shortest paths from the free left vertices.
+            for &x in left.iter() {
+                if !self.mate.contains_key(x) && self.layer.get(x) == Some(&0) {
+                    self.augment(x, shortest);
+                }
+            }
+        }
+        // Store the matched edges.
+        self.matching = self.mate.iter().filter(|(x, y)| x < y).map(|(&x, &y)| (x, y)).collect();
+        // Compute the vertices reachable from the free left vertices through alternating paths.
+        let mut visited: HashSet<_> = left.iter().filter(|x| !self.mate.contains_key(*x)).copied().collect();
+        let mut queue: VecDeque<_> = visited.iter().copied().collect();
+        while let Some(x) = queue.pop_front() {
+            for y in self.graph.neighbors_iter(x) {
+                // Reach right vertices through unmatched edges ...
+                if self.mate.get(x) != Some(&y) && visited.insert(y) {
+                    // ... and left vertices back through matched edges.
+                    if let Some(&z) = self.mate.get(y) {
+                        if visited.insert(z) {
+                            queue.push_back(z);
+                        }
+                    }
+                }
+            }
+        }
+        // By König's theorem, the minimum vertex cover is made of the unreachable left vertices
+        // and the reachable right vertices.
+        self.vertex_cover = self
+            .graph
+            .vertices_iter()
+            .filter(|x| (self.color[x] == 0) != visited.contains(x))
+            .collect();
+
+        Ok(self)
+    }
+}
+
+impl<'a, T> From<&'a T> for HopcroftKarp<'a, T>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g)
+    }
+}
@@ -0,0 +1,726 @@
+use crate::traits::Undirected;
+use crate::types::EdgeList;
+use crate::V;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Maximum weight matching structure.
+///
+/// This structure contains the maximum weight `matching`, the `mate` of each matched vertex and
+/// the `total_weight` of the matching, where the weight of each edge is given by a user-supplied closure.
+///
+pub struct MaximumWeightMatching<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Weight of a given edge.
+    weight: F,
+    /// Mate of each matched vertex.
+    pub mate: HashMap<&'a T::Vertex, &'a T::Vertex>,
+    /// Set of matched edges, with the lowest endpoint first.
+    pub matching: EdgeList<&'a T::Vertex>,
+    /// Total weight of the matching.
+    pub total_weight: f64,
+}
+
+impl<'a, T, F> MaximumWeightMatching<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a maximum weight matching structure.
+    ///
+    /// Build a maximum weight matching structure for a given graph and weight function, following
+    /// the primal-dual blossom algorithm by Galil[^1], where edges with non-positive weight are never matched.
+    ///
+    /// [^1]: [Galil, Z. (1986). Efficient algorithms for finding maximum matching in graphs. ACM Computing Surveys, 18(1), 23-38.](https://scholar.google.com/scholar?q=Efficient+algorithms+for+finding+maximum+matching+in+graphs)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::UndirectedAdjacencyListGraph;
+    /// use grathe::types::EdgeList;
+    ///
+    /// // Build an undirected path with weights as edge attributes.
+    /// let mut g = UndirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (1, 2), (2, 3)
+    /// ]);
+    /// for (x, y, w) in [(0, 1, 2.), (1, 2, 5.), (2, 3, 2.)] {
+    ///     g.set_edge_attrs(&x, &y, w);
+    ///     g.set_edge_attrs(&y, &x, w);
+    /// }
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = MaximumWeightMatching::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The heaviest edge outweighs the two lighter ones,
+    /// // even if the resulting matching is not maximum in cardinality.
+    /// assert_eq!(search.matching, EdgeList::from([(&1, &2)]));
+    /// assert_eq!(search.total_weight, 5.);
+    /// ```
+    ///
+    pub fn new(g: &'a T, w: F) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set weight function.
+            weight: w,
+            // Initialize the mate map.
+            mate: Default::default(),
+            // Initialize the matching.
+            matching: Default::default(),
+            // Initialize the total weight.
+            total_weight: 0.,
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(self.graph).collect();
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Get the weighted edge set, skipping self-edges, which cannot be matched.
+        let edges: Vec<_> = self
+            .graph
+            .edges_iter()
+            .filter(|(x, y)| x < y)
+            .map(|(x, y)| (index[x], index[y], (self.weight)(x, y)))
+            .collect();
+        // Solve the matching problem over the indices.
+        let mate = Blossom::new(vertices.len(), edges).solve();
+        // Store the mate map.
+        self.mate = mate
+            .iter()
+            .enumerate()
+            .filter_map(|(i, j)| j.map(|j| (vertices[i], vertices[j])))
+            .collect();
+        // Store the matched edges.
+        self.matching = self.mate.iter().filter(|(x, y)| x < y).map(|(&x, &y)| (x, y)).collect();
+        // Compute the total weight.
+        self.total_weight = self.matching.iter().map(|&(x, y)| (self.weight)(x, y)).sum();
+
+        self
+    }
+}
+
+impl<'a, T, F> From<(&'a T, F)> for MaximumWeightMatching<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Builds a search object from a given graph and a weight function.
+    ///
+    fn from((g, w): (&'a T, F)) -> Self {
+        Self::new(g, w)
+    }
+}
+
+/// Label of a free blossom, i.e. not in any alternating tree.
+const FREE: u8 = 0;
+/// Label of an outer (S) blossom, i.e. at even distance from the root of its alternating tree.
+const OUTER: u8 = 1;
+/// Label of an inner (T) blossom, i.e. at odd distance from the root of its alternating tree.
+const INNER: u8 = 2;
+/// Flag marking an outer blossom already visited while scanning for a new blossom base.
+const BREADCRUMB: u8 = 4;
+
+/// Primal-dual blossom algorithm state.
+///
+/// Vertices are indexed in $[0, n)$, while non-trivial blossoms are indexed in $[n, 2n)$.
+/// Edge endpoints are indexed in $[0, 2m)$, where the $p$-th endpoint is the $(p \bmod 2)$-th
+/// vertex of the $\lfloor p / 2 \rfloor$-th edge, therefore $p \oplus 1$ is the opposite endpoint.
+///
+struct Blossom {
+    /// Number of vertices.
+    n: usize,
+    /// Weighted edges.
+    edges: Vec<(usize, usize, f64)>,
+    /// Vertex of each endpoint.
+    endpoint_vertex: Vec<usize>,
+    /// Remote endpoints of the edges incident to each vertex.
+    neighbor_ends: Vec<Vec<usize>>,
+    /// Remote endpoint of the matched edge of each vertex, if any.
+    mate_end: Vec<Option<usize>>,
+    /// Label of each top-level blossom, i.e. free, outer or inner.
+    label: Vec<u8>,
+    /// Endpoint through which each labeled blossom got its label, if any.
+    label_end: Vec<Option<usize>>,
+    /// Top-level blossom containing each vertex.
+    in_blossom: Vec<usize>,
+    /// Parent blossom of each blossom, if any.
+    blossom_parent: Vec<Option<usize>>,
+    /// Sub-blossoms of each non-trivial blossom, starting from the base.
+    blossom_children: Vec<Vec<usize>>,
+    /// Base vertex of each blossom, if any.
+    blossom_base: Vec<Option<usize>>,
+    /// Endpoints of the edges connecting the sub-blossoms of each non-trivial blossom.
+    blossom_endpoints: Vec<Vec<usize>>,
+    /// Least-slack edge to a different outer blossom, if any.
+    best_edge: Vec<Option<usize>>,
+    /// Least-slack edges to the neighboring outer blossoms of each non-trivial outer blossom.
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    /// Unused non-trivial blossom indices.
+    unused_blossoms: Vec<usize>,
+    /// Dual variables of vertices and blossoms.
+    dual: Vec<f64>,
+    /// Whether each edge is tight, i.e. its slack is zero.
+    allowed_edge: Vec<bool>,
+    /// To-be-scanned outer vertices.
+    scan_queue: Vec<usize>,
+}
+
+impl Blossom {
+    fn new(n: usize, edges: Vec<(usize, usize, f64)>) -> Self {
+        // Get the maximum weight, used to initialize the dual variables.
+        let max_weight = edges.iter().fold(0., |m: f64, &(_, _, w)| m.max(w));
+        // Compute the endpoints.
+        let endpoint_vertex = (0..2 * edges.len())
+            .map(|p| match p % 2 {
+                0 => edges[p / 2].0,
+                _ => edges[p / 2].1,
+            })
+            .collect();
+        // Compute the remote endpoints of the incident edges.
+        let mut neighbor_ends = vec![Vec::new(); n];
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            neighbor_ends[i].push(2 * k + 1);
+            neighbor_ends[j].push(2 * k);
+        }
+
+        Self {
+            // Set the number of vertices.
+            n,
+            // Set the vertex of each endpoint.
+            endpoint_vertex,
+            // Set the remote endpoints of the incident edges.
+            neighbor_ends,
+            // Initialize the matching as empty.
+            mate_end: vec![None; n],
+            // Initialize every vertex and blossom as free.
+            label: vec![FREE; 2 * n],
+            label_end: vec![None; 2 * n],
+            // Initialize each vertex as a trivial top-level blossom, i.e. its own base.
+            in_blossom: (0..n).collect(),
+            blossom_parent: vec![None; 2 * n],
+            blossom_children: vec![Vec::new(); 2 * n],
+            blossom_base: (0..n).map(Some).chain((0..n).map(|_| None)).collect(),
+            blossom_endpoints: vec![Vec::new(); 2 * n],
+            // Initialize the least-slack edges.
+            best_edge: vec![None; 2 * n],
+            blossom_best_edges: vec![None; 2 * n],
+            // Mark every non-trivial blossom index as unused.
+            unused_blossoms: (n..2 * n).collect(),
+            // Initialize the dual variables of the vertices to the maximum weight, and of the blossoms to zero.
+            dual: (0..2 * n).map(|i| if i < n { max_weight } else { 0. }).collect(),
+            // Initialize every edge as not tight.
+            allowed_edge: vec![false; edges.len()],
+            // Initialize the scan queue.
+            scan_queue: Vec::new(),
+            // Set the weighted edges.
+            edges,
+        }
+    }
+
+    /// Slack of a given edge.
+    fn slack(&self, k: usize) -> f64 {
+        let (i, j, w) = self.edges[k];
+        self.dual[i] + self.dual[j] - 2. * w
+    }
+
+    /// Vertices contained in a given blossom.
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        match b < self.n {
+            true => vec![b],
+            false => self.blossom_children[b].iter().flat_map(|&t| self.leaves(t)).collect(),
+        }
+    }
+
+    /// Position of a sub-blossom, with negative positions counted from the end.
+    fn at(&self, b: usize, j: isize) -> usize {
+        match j < 0 {
+            true => (j + self.blossom_children[b].len() as isize) as usize,
+            false => j as usize,
+        }
+    }
+
+    /// Assign a label to the top-level blossom containing a given vertex.
+    fn assign_label(&mut self, w: usize, t: u8, p: Option<usize>) {
+        // Label both the vertex and its top-level blossom, recording the endpoint it was reached through.
+        let b = self.in_blossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        // Reset their least-slack edges.
+        self.best_edge[w] = None;
+        self.best_edge[b] = None;
+        match t {
+            // Outer vertices are to be scanned.
+            OUTER => {
+                let leaves = self.leaves(b);
+                self.scan_queue.extend(leaves);
+            }
+            // The mate of the base of an inner blossom becomes outer.
+            _ => {
+                let m = self.mate_end[self.blossom_base[b].unwrap()].unwrap();
+                self.assign_label(self.endpoint_vertex[m], OUTER, Some(m ^ 1));
+            }
+        }
+    }
+
+    /// Trace back from two outer vertices to find either a new blossom base or an augmenting path.
+    fn scan_blossom(&mut self, v: usize, w: usize) -> Option<usize> {
+        // Initialize the visited blossoms and the base.
+        let (mut path, mut base) = (Vec::new(), None);
+        // Start from both vertices, where `None` denotes a path that reached the root of its tree.
+        let (mut v, mut w) = (Some(v), Some(w));
+        // Alternate between the two paths, leaving breadcrumbs.
+        while let Some(x) = v {
+            let b = self.in_blossom[x];
+            // If a breadcrumb is found, then the base is found.
+            if self.label[b] & BREADCRUMB != 0 {
+                base = self.blossom_base[b];
+                break;
+            }
+            // Otherwise, leave a breadcrumb on the blossom.
+            path.push(b);
+            self.label[b] = OUTER | BREADCRUMB;
+            // Move to the next outer blossom, i.e. through the inner blossom above, if any.
+            v = self.label_end[b].map(|l| {
+                let t = self.in_blossom[self.endpoint_vertex[l]];
+                self.endpoint_vertex[self.label_end[t].unwrap()]
+            });
+            // Switch to the other path, unless it already reached its root.
+            if w.is_some() {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        // Remove the breadcrumbs.
+        for b in path {
+            self.label[b] = OUTER;
+        }
+
+        base
+    }
+
+    /// Construct a new blossom with a given base, through a given edge.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        // Get the top-level blossoms of the base and of the endpoints of the edge.
+        let (v, w, _) = self.edges[k];
+        let (bb, mut bv, mut bw) = (self.in_blossom[base], self.in_blossom[v], self.in_blossom[w]);
+        // Create the blossom.
+        let b = self.unused_blossoms.pop().unwrap();
+        self.blossom_base[b] = Some(base);
+        self.blossom_parent[b] = None;
+        self.blossom_parent[bb] = Some(b);
+        // Trace back from the first endpoint to the base.
+        let (mut path, mut endpoints) = (Vec::new(), Vec::new());
+        while bv != bb {
+            self.blossom_parent[bv] = Some(b);
+            path.push(bv);
+            endpoints.push(self.label_end[bv].unwrap());
+            bv = self.in_blossom[self.endpoint_vertex[self.label_end[bv].unwrap()]];
+        }
+        // Start the cycle from the base, then close it through the given edge.
+        path.push(bb);
+        path.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+        // Trace back from the second endpoint to the base.
+        while bw != bb {
+            self.blossom_parent[bw] = Some(b);
+            path.push(bw);
+            endpoints.push(self.label_end[bw].unwrap() ^ 1);
+            bw = self.in_blossom[self.endpoint_vertex[self.label_end[bw].unwrap()]];
+        }
+        // Store the sub-blossoms and the endpoints connecting them.
+        self.blossom_children[b] = path.clone();
+        self.blossom_endpoints[b] = endpoints;
+        // The new blossom is outer.
+        self.label[b] = OUTER;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = 0.;
+        // Relabel the vertices, inner ones become outer and are to be scanned.
+        for v in self.leaves(b) {
+            if self.label[self.in_blossom[v]] == INNER {
+                self.scan_queue.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+        // Compute the least-slack edges to the neighboring outer blossoms.
+        let mut best_edge_to: Vec<Option<usize>> = vec![None; 2 * self.n];
+        for bv in path {
+            // Get the candidate edges of the sub-blossom, i.e. its own least-slack edges, if any,
+            // otherwise the edges incident to its vertices.
+            let neighbor_lists = match self.blossom_best_edges[bv].take() {
+                Some(l) => vec![l],
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .map(|v| self.neighbor_ends[v].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+            for k in neighbor_lists.into_iter().flatten() {
+                // Orient the edge so that its second endpoint is outside the new blossom, if any.
+                let (mut i, mut j, _) = self.edges[k];
+                if self.in_blossom[j] == b {
+                    std::mem::swap(&mut i, &mut j);
+                }
+                // Keep the least-slack edge for each neighboring outer blossom.
+                let bj = self.in_blossom[j];
+                if bj != b && self.label[bj] == OUTER && best_edge_to[bj].is_none_or(|e| self.slack(k) < self.slack(e))
+                {
+                    best_edge_to[bj] = Some(k);
+                }
+            }
+            self.best_edge[bv] = None;
+        }
+        // Store the least-slack edges, together with the least-slack one overall.
+        let best: Vec<_> = best_edge_to.into_iter().flatten().collect();
+        self.best_edge[b] = best.iter().copied().reduce(|e, k| match self.slack(k) < self.slack(e) {
+            true => k,
+            false => e,
+        });
+        self.blossom_best_edges[b] = Some(best);
+    }
+
+    /// Expand a given blossom.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        // Convert the sub-blossoms into top-level blossoms.
+        for s in self.blossom_children[b].clone() {
+            self.blossom_parent[s] = None;
+            // Trivial sub-blossoms are their own top-level blossom, while non-trivial ones with zero dual
+            // variable are expanded recursively at the end of a stage.
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == 0. {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+        // If the blossom is inner and expanded in the middle of a stage, relabel its sub-blossoms.
+        if !end_stage && self.label[b] == INNER {
+            // Get the number of sub-blossoms.
+            let l = self.blossom_children[b].len() as isize;
+            // Find the sub-blossom through which the blossom got its label.
+            let entry_child = self.in_blossom[self.endpoint_vertex[self.label_end[b].unwrap() ^ 1]];
+            let mut j = self.blossom_children[b].iter().position(|&t| t == entry_child).unwrap() as isize;
+            // Move along the blossom in the direction of the even path to the base, where moving backward
+            // requires to flip the connecting endpoints.
+            let (step, flip) = match j & 1 {
+                1 => {
+                    j -= l;
+                    (1, 0)
+                }
+                _ => (-1, 1),
+            };
+            // Relabel the sub-blossoms along the path to the base.
+            let mut p = self.label_end[b].unwrap();
+            while j != 0 {
+                // Relabel the next sub-blossom as inner, which in turn labels its mate as outer.
+                let q = self.blossom_endpoints[b][self.at(b, j - flip)];
+                self.label[self.endpoint_vertex[p ^ 1]] = FREE;
+                self.label[self.endpoint_vertex[q ^ flip as usize ^ 1]] = FREE;
+                self.assign_label(self.endpoint_vertex[p ^ 1], INNER, Some(p));
+                // Mark the edges along the path as tight, then move two sub-blossoms forward.
+                self.allowed_edge[q / 2] = true;
+                j += step;
+                p = self.blossom_endpoints[b][self.at(b, j - flip)] ^ flip as usize;
+                self.allowed_edge[p / 2] = true;
+                j += step;
+            }
+            // Relabel the base sub-blossom as inner, without labeling its mate.
+            let bv = self.blossom_children[b][self.at(b, j)];
+            self.label[self.endpoint_vertex[p ^ 1]] = INNER;
+            self.label[bv] = INNER;
+            self.label_end[self.endpoint_vertex[p ^ 1]] = Some(p);
+            self.label_end[bv] = Some(p);
+            self.best_edge[bv] = None;
+            // Relabel the remaining sub-blossoms that are reachable from outside.
+            j += step;
+            while self.blossom_children[b][self.at(b, j)] != entry_child {
+                let bv = self.blossom_children[b][self.at(b, j)];
+                // Skip the sub-blossoms already labeled outer, otherwise relabel them if any vertex was reached.
+                if self.label[bv] != OUTER {
+                    if let Some(v) = self.leaves(bv).into_iter().find(|&v| self.label[v] != FREE) {
+                        self.label[v] = FREE;
+                        let m = self.mate_end[self.blossom_base[bv].unwrap()].unwrap();
+                        self.label[self.endpoint_vertex[m]] = FREE;
+                        self.assign_label(v, INNER, self.label_end[v]);
+                    }
+                }
+                j += step;
+            }
+        }
+        // Recycle the blossom.
+        self.label[b] = FREE;
+        self.label_end[b] = None;
+        self.blossom_children[b].clear();
+        self.blossom_endpoints[b].clear();
+        self.blossom_base[b] = None;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = None;
+        self.unused_blossoms.push(b);
+    }
+
+    /// Swap matched and unmatched edges along the even path from a given vertex to the base of a given blossom.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        // Find the sub-blossom containing the vertex.
+        let mut t = v;
+        while self.blossom_parent[t] != Some(b) {
+            t = self.blossom_parent[t].unwrap();
+        }
+        // Augment the sub-blossom recursively.
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        // Get the number of sub-blossoms and the position of the one containing the vertex.
+        let l = self.blossom_children[b].len() as isize;
+        let i = self.blossom_children[b].iter().position(|&s| s == t).unwrap();
+        let mut j = i as isize;
+        // Move along the blossom in the direction of the even path to the base, where moving backward
+        // requires to flip the connecting endpoints.
+        let (step, flip) = match j & 1 {
+            1 => {
+                j -= l;
+                (1, 0)
+            }
+            _ => (-1, 1),
+        };
+        // Swap matched and unmatched edges along the path.
+        while j != 0 {
+            // Augment the next two sub-blossoms through the edge connecting them.
+            j += step;
+            let t = self.blossom_children[b][self.at(b, j)];
+            let p = self.blossom_endpoints[b][self.at(b, j - flip)] ^ flip as usize;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint_vertex[p]);
+            }
+            j += step;
+            let t = self.blossom_children[b][self.at(b, j)];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint_vertex[p ^ 1]);
+            }
+            // Match the edge connecting them.
+            self.mate_end[self.endpoint_vertex[p]] = Some(p ^ 1);
+            self.mate_end[self.endpoint_vertex[p ^ 1]] = Some(p);
+        }
+        // Rotate the sub-blossoms so that the new base is first.
+        self.blossom_children[b].rotate_left(i);
+        self.blossom_endpoints[b].rotate_left(i);
+        self.blossom_base[b] = self.blossom_base[self.blossom_children[b][0]];
+    }
+
+    /// Swap matched and unmatched edges along the augmenting path through a given edge.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        // For each endpoint of the edge, paired with the remote endpoint it gets matched through.
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            // Walk back to the root of the alternating tree.
+            loop {
+                // Match the outer vertex, augmenting its blossom first, if any.
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate_end[s] = Some(p);
+                // Stop when a root is reached.
+                let l = match self.label_end[bs] {
+                    Some(l) => l,
+                    None => break,
+                };
+                // Move to the inner blossom above, then to the outer vertex above it.
+                let bt = self.in_blossom[self.endpoint_vertex[l]];
+                let m = self.label_end[bt].unwrap();
+                s = self.endpoint_vertex[m];
+                // Match the inner vertex to the outer one, augmenting its blossom first, if any.
+                let j = self.endpoint_vertex[m ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate_end[j] = Some(m);
+                p = m ^ 1;
+            }
+        }
+    }
+
+    /// Solve the maximum weight matching problem, returning the mate of each vertex.
+    fn solve(mut self) -> Vec<Option<usize>> {
+        let n = self.n;
+        // Each stage either augments the matching or terminates.
+        for _ in 0..n {
+            // Reset the labels and the least-slack edges.
+            self.label.iter_mut().for_each(|l| *l = FREE);
+            self.best_edge.iter_mut().for_each(|e| *e = None);
+            self.blossom_best_edges[n..].iter_mut().for_each(|e| *e = None);
+            self.allowed_edge.iter_mut().for_each(|a| *a = false);
+            self.scan_queue.clear();
+            // Label the free vertices as outer.
+            for v in 0..n {
+                if self.mate_end[v].is_none() && self.label[self.in_blossom[v]] == FREE {
+                    self.assign_label(v, OUTER, None);
+                }
+            }
+            // Initialize the augmented flag.
+            let mut augmented = false;
+            loop {
+                // Scan the outer vertices, until the queue is empty or the matching is augmented.
+                while !augmented {
+                    let v = match self.scan_queue.pop() {
+                        Some(v) => v,
+                        None => break,
+                    };
+                    // For each incident edge, get its index and its remote vertex.
+                    for p in self.neighbor_ends[v].clone() {
+                        let (k, w) = (p / 2, self.endpoint_vertex[p]);
+                        // Skip edges inside a blossom.
+                        if self.in_blossom[v] == self.in_blossom[w] {
+                            continue;
+                        }
+                        // Check whether the edge is tight, computing its slack otherwise.
+                        let mut k_slack = 0.;
+                        if !self.allowed_edge[k] {
+                            k_slack = self.slack(k);
+                            if k_slack <= 0. {
+                                self.allowed_edge[k] = true;
+                            }
+                        }
+                        if self.allowed_edge[k] {
+                            if self.label[self.in_blossom[w]] == FREE {
+                                // Grow the alternating tree.
+                                self.assign_label(w, INNER, Some(p ^ 1));
+                            } else if self.label[self.in_blossom[w]] == OUTER {
+                                // Either a new blossom or an augmenting path is found.
+                                match self.scan_blossom(v, w) {
+                                    Some(base) => self.add_blossom(base, k),
+                                    None => {
+                                        self.augment_matching(k);
+                                        augmented = true;
+                                        break;
+                                    }
+                                }
+                            } else if self.label[w] == FREE {
+                                // The vertex is reachable inside an inner blossom.
+                                self.label[w] = INNER;
+                                self.label_end[w] = Some(p ^ 1);
+                            }
+                        } else if self.label[self.in_blossom[w]] == OUTER {
+                            // Keep track of the least-slack edge to a different outer blossom.
+                            let b = self.in_blossom[v];
+                            if self.best_edge[b].is_none_or(|e| k_slack < self.slack(e)) {
+                                self.best_edge[b] = Some(k);
+                            }
+                        } else if self.label[w] == FREE && self.best_edge[w].is_none_or(|e| k_slack < self.slack(e)) {
+                            // Keep track of the least-slack edge to an unlabeled vertex.
+                            self.best_edge[w] = Some(k);
+                        }
+                    }
+                }
+                // If the matching was augmented, then the stage is over.
+                if augmented {
+                    break;
+                }
+                // Compute the dual update, i.e. the minimum over the possible delta types.
+                // Delta 1: least dual variable of a vertex.
+                let mut delta_type = 1;
+                let mut delta = self.dual[..n].iter().copied().fold(f64::INFINITY, f64::min);
+                let (mut delta_edge, mut delta_blossom) = (0, 0);
+                // Delta 2: least-slack edge from an outer vertex to an unlabeled vertex.
+                for v in 0..n {
+                    if let (FREE, Some(e)) = (self.label[self.in_blossom[v]], self.best_edge[v]) {
+                        if self.slack(e) < delta {
+                            (delta, delta_type, delta_edge) = (self.slack(e), 2, e);
+                        }
+                    }
+                }
+                // Delta 3: half the least-slack edge between two outer blossoms.
+                for b in 0..2 * n {
+                    if let (None, OUTER, Some(e)) = (self.blossom_parent[b], self.label[b], self.best_edge[b]) {
+                        if self.slack(e) / 2. < delta {
+                            (delta, delta_type, delta_edge) = (self.slack(e) / 2., 3, e);
+                        }
+                    }
+                }
+                // Delta 4: least dual variable of an inner blossom.
+                for b in n..2 * n {
+                    if self.blossom_base[b].is_some()
+                        && self.blossom_parent[b].is_none()
+                        && self.label[b] == INNER
+                        && self.dual[b] < delta
+                    {
+                        (delta, delta_type, delta_blossom) = (self.dual[b], 4, b);
+                    }
+                }
+                // Update the dual variables of the vertices.
+                for v in 0..n {
+                    match self.label[self.in_blossom[v]] {
+                        OUTER => self.dual[v] -= delta,
+                        INNER => self.dual[v] += delta,
+                        _ => {}
+                    }
+                }
+                // Update the dual variables of the top-level blossoms.
+                for b in n..2 * n {
+                    if self.blossom_base[b].is_some() && self.blossom_parent[b].is_none() {
+                        match self.label[b] {
+                            OUTER => self.dual[b] += delta,
+                            INNER => self.dual[b] -= delta,
+                            _ => {}
+                        }
+                    }
+                }
+                // Take action at the point where the minimum delta occurred.
+                match delta_type {
+                    // No further improvement is possible.
+                    1 => break,
+                    // An edge became tight, scan its outer endpoint.
+                    2 | 3 => {
+                        // Mark the edge as tight, then orient it so that its first endpoint is outer.
+                        self.allowed_edge[delta_edge] = true;
+                        let (mut i, mut j, _) = self.edges[delta_edge];
+                        if self.label[self.in_blossom[i]] == FREE {
+                            std::mem::swap(&mut i, &mut j);
+                        }
+                        self.scan_queue.push(i);
+                    }
+                    // An inner blossom dual variable became zero, expand it.
+                    _ => self.expand_blossom(delta_blossom, false),
+                }
+            }
+            // If no augmenting path was found, the matching is maximum.
+            if !augmented {
+                break;
+            }
+            // Expand the outer blossoms with zero dual variable at the end of the stage.
+            for b in n..2 * n {
+                if self.blossom_parent[b].is_none()
+                    && self.blossom_base[b].is_some()
+                    && self.label[b] == OUTER
+                    && self.dual[b] == 0.
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+        // Convert the mate endpoints into vertices.
+        self.mate_end
+            .iter()
+            .map(|p| p.map(|p| self.endpoint_vertex[p]))
+            .collect()
+    }
+}
@@ -0,0 +1,8 @@
+mod edmonds_blossom;
+pub use edmonds_blossom::EdmondsBlossom;
+
+mod hopcroft_karp;
+pub use hopcroft_karp::HopcroftKarp;
+
+mod maximum_weight_matching;
+pub use maximum_weight_matching::MaximumWeightMatching;
@@ -1,11 +1,69 @@
+mod centrality;
+pub use centrality::*;
+
+mod chordal;
+pub use chordal::*;
+
+mod cliques;
+pub use cliques::*;
+
+mod clustering;
+pub use clustering::*;
+
+mod coloring;
+pub use coloring::*;
+
+mod components;
+pub use components::*;
+
+mod cycles;
+pub use cycles::*;
+
+mod flows;
+pub use flows::*;
+
+mod isomorphism;
+pub use isomorphism::*;
+
+mod matching;
+pub use matching::*;
+
+mod shortest_paths;
+pub use shortest_paths::*;
+
 mod simple_paths;
 pub use simple_paths::*;
 
+mod spanning_trees;
+pub use spanning_trees::*;
+
 mod traversal;
 pub use traversal::*;
 
 macro_rules! impl_algorithms_directed {
     ($graph:ident) => {
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F, H> From<(&'a $graph<T, U>, &'a T, &'a T, F, H)>
+            for $crate::algorithms::AStar<'a, $graph<T, U>, F, H>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+            H: Fn(&'a T) -> f64,
+        {
+            /// Builds a search object from a given graph, with source and target vertices,
+            /// a weight function and a heuristic function.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source vertex or the target vertex are not in the graph.
+            ///
+            fn from((g, x, y, w, h): (&'a $graph<T, U>, &'a T, &'a T, F, H)) -> Self {
+                Self::new(g, x, y, $graph::<T, U>::children_iter, w, h)
+            }
+        }
+
         // TODO: Once `min_specialization` will be stabilized,
         // replace this with blanket `From` implementation.
         impl<'a, T, U> From<(&'a $graph<T, U>, &'a T, &'a T)> for $crate::algorithms::AllSimplePaths<'a, $graph<T, U>>
@@ -30,6 +88,25 @@ macro_rules! impl_algorithms_directed {
             }
         }
 
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, &'a T, F)> for $crate::algorithms::BellmanFord<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph, with a source vertex and a weight function.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source vertex is not in the graph.
+            ///
+            fn from((g, x, w): (&'a $graph<T, U>, &'a T, F)) -> Self {
+                Self::new(g, Some(x), $graph::<T, U>::children_iter, w)
+            }
+        }
+
         // TODO: Once `min_specialization` will be stabilized,
         // replace this with blanket `From` implementation.
         impl<'a, T, U> From<&'a $graph<T, U>> for $crate::algorithms::BreadthFirstSearch<'a, $graph<T, U>>
@@ -117,11 +194,122 @@ macro_rules! impl_algorithms_directed {
                 )
             }
         }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, &'a T, F)> for $crate::algorithms::Dijkstra<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph, with a source vertex and a weight function.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source vertex is not in the graph.
+            ///
+            fn from((g, x, w): (&'a $graph<T, U>, &'a T, F)) -> Self {
+                Self::new(g, x, None, $graph::<T, U>::children_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, &'a T, &'a T, F)>
+            for $crate::algorithms::Dijkstra<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph, with source and target vertices and a weight function.
+            ///
+            /// The search terminates as soon as the target vertex is visited.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source vertex or the target vertex are not in the graph.
+            ///
+            fn from((g, x, y, w): (&'a $graph<T, U>, &'a T, &'a T, F)) -> Self {
+                Self::new(g, x, Some(y), $graph::<T, U>::children_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, F)> for $crate::algorithms::FloydWarshall<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph and a weight function.
+            ///
+            fn from((g, w): (&'a $graph<T, U>, F)) -> Self {
+                Self::new(g, $graph::<T, U>::children_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, F)> for $crate::algorithms::Johnson<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph and a weight function.
+            ///
+            fn from((g, w): (&'a $graph<T, U>, F)) -> Self {
+                Self::new(g, $graph::<T, U>::children_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U> From<(&'a $graph<T, U>, usize)> for $crate::algorithms::MotifCount<'a, $graph<T, U>>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+        {
+            /// Builds a search object from a given graph and a motif size.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the motif size is neither three nor four.
+            ///
+            fn from((g, k): (&'a $graph<T, U>, usize)) -> Self {
+                Self::new(g, k)
+            }
+        }
     };
 }
 
 macro_rules! impl_algorithms_undirected {
     ($graph:ident) => {
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F, H> From<(&'a $graph<T, U>, &'a T, &'a T, F, H)>
+            for $crate::algorithms::AStar<'a, $graph<T, U>, F, H>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+            H: Fn(&'a T) -> f64,
+        {
+            /// Builds a search object from a given graph, with source and target vertices,
+            /// a weight function and a heuristic function.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source vertex or the target vertex are not in the graph.
+            ///
+            fn from((g, x, y, w, h): (&'a $graph<T, U>, &'a T, &'a T, F, H)) -> Self {
+                Self::new(g, x, y, $graph::<T, U>::neighbors_iter, w, h)
+            }
+        }
+
         // TODO: Once `min_specialization` will be stabilized,
         // replace this with blanket `From` implementation.
         impl<'a, T, U> From<(&'a $graph<T, U>, &'a T, &'a T)> for $crate::algorithms::AllSimplePaths<'a, $graph<T, U>>
@@ -142,7 +330,38 @@ macro_rules! impl_algorithms_undirected {
             U: $crate::traits::WithAttributes<T>,
         {
             fn from(g: &'a $graph<T, U>) -> Self {
-                todo!()
+                Self::new_undirected(g, $graph::<T, U>::neighbors_iter)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, &'a T, F)> for $crate::algorithms::BellmanFord<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph, with a source vertex and a weight function.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source vertex is not in the graph.
+            ///
+            fn from((g, x, w): (&'a $graph<T, U>, &'a T, F)) -> Self {
+                Self::new(g, Some(x), $graph::<T, U>::neighbors_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U> From<&'a $graph<T, U>> for $crate::algorithms::BiconnectedComponents<'a, $graph<T, U>>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+        {
+            fn from(g: &'a $graph<T, U>) -> Self {
+                Self::new(g, $graph::<T, U>::neighbors_iter)
             }
         }
 
@@ -190,6 +409,18 @@ macro_rules! impl_algorithms_undirected {
             }
         }
 
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U> From<&'a $graph<T, U>> for $crate::algorithms::CycleBasis<'a, $graph<T, U>>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+        {
+            fn from(g: &'a $graph<T, U>) -> Self {
+                Self::new(g, $graph::<T, U>::neighbors_iter)
+            }
+        }
+
         // TODO: Once `min_specialization` will be stabilized,
         // replace this with blanket `From` implementation.
         impl<'a, T, U> From<&'a $graph<T, U>> for $crate::algorithms::DepthFirstSearch<'a, $graph<T, U>>
@@ -233,6 +464,124 @@ macro_rules! impl_algorithms_undirected {
                 )
             }
         }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, &'a T, F)> for $crate::algorithms::Dijkstra<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph, with a source vertex and a weight function.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source vertex is not in the graph.
+            ///
+            fn from((g, x, w): (&'a $graph<T, U>, &'a T, F)) -> Self {
+                Self::new(g, x, None, $graph::<T, U>::neighbors_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, &'a T, &'a T, F)>
+            for $crate::algorithms::Dijkstra<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph, with source and target vertices and a weight function.
+            ///
+            /// The search terminates as soon as the target vertex is visited.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source vertex or the target vertex are not in the graph.
+            ///
+            fn from((g, x, y, w): (&'a $graph<T, U>, &'a T, &'a T, F)) -> Self {
+                Self::new(g, x, Some(y), $graph::<T, U>::neighbors_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, F)> for $crate::algorithms::FloydWarshall<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph and a weight function.
+            ///
+            fn from((g, w): (&'a $graph<T, U>, F)) -> Self {
+                Self::new(g, $graph::<T, U>::neighbors_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, F)> for $crate::algorithms::Johnson<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph and a weight function.
+            ///
+            fn from((g, w): (&'a $graph<T, U>, F)) -> Self {
+                Self::new(g, $graph::<T, U>::neighbors_iter, w)
+            }
+        }
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U> From<&'a $graph<T, U>>
+            for $crate::algorithms::MinimumCycleBasis<'a, $graph<T, U>, fn(&'a T, &'a T) -> f64>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+        {
+            /// Builds a search object from a given graph, with unit weights.
+            ///
+            fn from(g: &'a $graph<T, U>) -> Self {
+                Self::new(g, $graph::<T, U>::neighbors_iter, |_, _| 1.)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U, F> From<(&'a $graph<T, U>, F)> for $crate::algorithms::MinimumCycleBasis<'a, $graph<T, U>, F>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+            F: Fn(&'a T, &'a T) -> f64,
+        {
+            /// Builds a search object from a given graph and a weight function.
+            ///
+            fn from((g, w): (&'a $graph<T, U>, F)) -> Self {
+                Self::new(g, $graph::<T, U>::neighbors_iter, w)
+            }
+        }
+
+        // TODO: Once `min_specialization` will be stabilized,
+        // replace this with blanket `From` implementation.
+        impl<'a, T, U> From<(&'a $graph<T, U>, usize)> for $crate::algorithms::MotifCount<'a, $graph<T, U>>
+        where
+            T: $crate::types::Vertex,
+            U: $crate::traits::WithAttributes<T>,
+        {
+            /// Builds a search object from a given graph and a motif size.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the motif size is neither three nor four.
+            ///
+            fn from((g, k): (&'a $graph<T, U>, usize)) -> Self {
+                Self::new_undirected(g, k)
+            }
+        }
     };
 }
 
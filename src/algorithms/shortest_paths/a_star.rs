@@ -0,0 +1,172 @@
+use crate::traits::Storage;
+use crate::types::VertexIterator;
+use crate::utils::MinPriority;
+use std::collections::{BinaryHeap, HashMap};
+use std::vec::Vec;
+
+/// A* search structure.
+///
+/// This structure contains the `distance` and `predecessor` maps.
+///
+/// The search is guided towards the target vertex by a user-supplied heuristic, which
+/// estimates the distance of a given vertex from the target vertex.
+///
+pub struct AStar<'a, T, F, H>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+    H: Fn(&'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Reachable vertices of distance one from given vertex.
+    reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Weight of a given edge.
+    weight: F,
+    /// Estimated distance of a given vertex from the target vertex.
+    heuristic: H,
+    /// Source vertex.
+    source: &'a T::Vertex,
+    /// Target vertex.
+    target: &'a T::Vertex,
+    /// Distance from the source vertex.
+    pub distance: HashMap<&'a T::Vertex, f64>,
+    /// Predecessor of each discovered vertex (except the source vertex).
+    pub predecessor: HashMap<&'a T::Vertex, &'a T::Vertex>,
+}
+
+impl<'a, T, F, H> AStar<'a, T, F, H>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+    H: Fn(&'a T::Vertex) -> f64,
+{
+    /// Build an A* search structure.
+    ///
+    /// Build an A*[^1] search structure for a given graph, source and target vertices,
+    /// weight function and heuristic function.
+    ///
+    /// If the heuristic is *admissible*, i.e. it never overestimates the distance from the target vertex,
+    /// then the path found is a shortest path.
+    ///
+    /// [^1]: [Hart, P. E., Nilsson, N. J., & Raphael, B. (1968). A formal basis for the heuristic determination of minimum cost paths. IEEE transactions on Systems Science and Cybernetics, 4(2), 100-107.](https://scholar.google.com/scholar?q=A+formal+basis+for+the+heuristic+determination+of+minimum+cost+paths)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source vertex or the target vertex are not in the graph,
+    /// or if a negative weight is encountered during the search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// // Build an undirected grid graph.
+    /// let g = Graph::from_edges([
+    ///     (0, 1), (1, 2),
+    ///     (0, 3), (1, 4), (2, 5),
+    ///     (3, 4), (4, 5),
+    /// ]);
+    /// // Set the vertices coordinates on the grid.
+    /// let p: HashMap<_, _> = V!(g).map(|&x| (x, ((x % 3) as f64, (x / 3) as f64))).collect();
+    ///
+    /// // Build the search object using the Manhattan distance as heuristic.
+    /// let mut search = AStar::from((&g, &0, &5, |_, _| 1., |x| {
+    ///     (p[x].0 - p[&5].0).abs() + (p[x].1 - p[&5].1).abs()
+    /// }));
+    ///
+    /// // Run the algorithm and get the path with its cost.
+    /// let (path, cost) = search.run().unwrap();
+    ///
+    /// // The path goes from the source to the target vertex ...
+    /// assert_eq!(path, [&0, &1, &2, &5]);
+    /// // ... with a cost of three.
+    /// assert_eq!(cost, 3.);
+    /// ```
+    ///
+    pub fn new(
+        g: &'a T,
+        x: &'a T::Vertex,
+        y: &'a T::Vertex,
+        f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+        w: F,
+        h: H,
+    ) -> Self {
+        // Assert that source and target vertices are in graph.
+        assert!(g.has_vertex(x) && g.has_vertex(y));
+
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set reachability function.
+            reachable: f,
+            // Set weight function.
+            weight: w,
+            // Set heuristic function.
+            heuristic: h,
+            // Set source vertex.
+            source: x,
+            // Set target vertex.
+            target: y,
+            // Initialize the distance map.
+            distance: Default::default(),
+            // Initialize the predecessor map.
+            predecessor: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and return the path from the source vertex to the target vertex,
+    /// together with its cost, or [`None`] if the target vertex is not reachable.
+    ///
+    pub fn run(&mut self) -> Option<(Vec<&'a T::Vertex>, f64)> {
+        // Initialize the distance map with the source vertex.
+        self.distance.insert(self.source, 0.);
+        // Initialize the to-be-visited queue with the source vertex,
+        // prioritized by its estimated distance from the target vertex.
+        let mut queue = BinaryHeap::from([MinPriority((self.heuristic)(self.source), (0., self.source))]);
+        // While there are still vertices to be visited.
+        while let Some(MinPriority(_, (d, x))) = queue.pop() {
+            // If a shorter path to this vertex was found, skip its outdated entry.
+            if d > self.distance[x] {
+                continue;
+            }
+            // If the vertex is the target vertex ...
+            if x == self.target {
+                // ... initialize the path with the target vertex ...
+                let mut path = vec![x];
+                // ... follow the predecessors until the source vertex is reached ...
+                while let Some(&y) = self.predecessor.get(path[path.len() - 1]) {
+                    path.push(y);
+                }
+                // ... and reverse the path to follow the edges direction.
+                path.reverse();
+                // Return the path with its cost.
+                return Some((path, d));
+            }
+            // Iterate over the reachable vertices of the popped vertex.
+            for y in (self.reachable)(self.graph, x) {
+                // Get the weight of the edge.
+                let w = (self.weight)(x, y);
+                // Assert the weight is non-negative.
+                assert!(w >= 0., "negative weight found for edge ({:?}, {:?})", x, y);
+                // Compute the distance through the popped vertex.
+                let d = d + w;
+                // If the new distance is shorter than the current one ...
+                if self.distance.get(y).is_none_or(|&e| d < e) {
+                    // ... update its distance ...
+                    self.distance.insert(y, d);
+                    // ... set its predecessor ...
+                    self.predecessor.insert(y, x);
+                    // ... and push it into the to-be-visited queue.
+                    queue.push(MinPriority(d + (self.heuristic)(y), (d, y)));
+                }
+            }
+        }
+
+        // Otherwise the target vertex is not reachable.
+        None
+    }
+}
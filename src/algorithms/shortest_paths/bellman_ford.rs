@@ -0,0 +1,187 @@
+use crate::traits::Storage;
+use crate::types::{Error, VertexIterator};
+use crate::V;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Bellman-Ford search structure.
+///
+/// This structure contains the `distance` and `predecessor` maps.
+///
+/// Differently from [`Dijkstra`](super::Dijkstra), edges are allowed to have negative weights,
+/// and negative cycles reachable from the source vertex are reported as errors.
+///
+pub struct BellmanFord<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Reachable vertices of distance one from given vertex.
+    reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Weight of a given edge.
+    weight: F,
+    /// Distance from the source vertex.
+    pub distance: HashMap<&'a T::Vertex, f64>,
+    /// Predecessor of each discovered vertex (except the source vertex).
+    pub predecessor: HashMap<&'a T::Vertex, &'a T::Vertex>,
+}
+
+impl<'a, T, F> BellmanFord<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a Bellman-Ford search structure.
+    ///
+    /// Build a Bellman-Ford[^1] search structure for a given graph, source vertex and weight function.
+    ///
+    /// If no source vertex is given, every vertex is considered a source with distance zero,
+    /// i.e. as if a *virtual* source vertex were connected to every vertex by a zero-weight edge.
+    /// In this case, any negative cycle in the graph is reported.
+    ///
+    /// [^1]: [Bellman, R. (1958). On a routing problem. Quarterly of applied mathematics, 16(1), 87-90.](https://scholar.google.com/scholar?q=On+a+routing+problem)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the (optional) source vertex is not in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::DirectedAdjacencyListGraph;
+    ///
+    /// // Build a directed graph with weights as edge attributes.
+    /// let mut g = DirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (0, 2), (1, 2), (2, 3)
+    /// ]);
+    /// g.set_edge_attrs(&0, &1, 4.);
+    /// g.set_edge_attrs(&0, &2, 2.);
+    /// g.set_edge_attrs(&1, &2, -3.);
+    /// g.set_edge_attrs(&2, &3, 1.);
+    ///
+    /// // Build the search object over graph with `0` as source vertex.
+    /// let mut search = BellmanFord::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// assert!(search.run().is_ok());
+    ///
+    /// // Vertex `2` has distance one from `0` ...
+    /// assert_eq!(search.distance[&2], 1.);
+    /// // ... and its predecessor is `1`.
+    /// assert_eq!(search.predecessor[&2], &1);
+    ///
+    /// // Add an edge that closes a negative cycle.
+    /// g.add_edge(&3, &1).unwrap();
+    /// g.set_edge_attrs(&3, &1, 1.);
+    ///
+    /// // The negative cycle is reported as an error.
+    /// let mut search = BellmanFord::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    /// assert_eq!(
+    ///     search.run().err(),
+    ///     Some(Error::NegativeCycle(vec![1, 2, 3, 1]))
+    /// );
+    /// ```
+    ///
+    pub fn new(
+        g: &'a T,
+        x: Option<&'a T::Vertex>,
+        f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+        w: F,
+    ) -> Self {
+        // Assert that source vertex, if any, is in graph.
+        assert!(x.iter().all(|x| g.has_vertex(x)));
+
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set reachability function.
+            reachable: f,
+            // Set weight function.
+            weight: w,
+            // Initialize the distance map with the source vertex, or with any vertex if none.
+            distance: match x {
+                Some(x) => HashMap::from([(x, 0.)]),
+                None => V!(g).map(|x| (x, 0.)).collect(),
+            },
+            // Initialize the predecessor map.
+            predecessor: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    /// # Errors
+    ///
+    /// A negative cycle is reachable from the source vertex, in which case
+    /// the cycle is returned as a sequence of vertices, starting and ending
+    /// with the same vertex.
+    ///
+    pub fn run(&mut self) -> Result<&Self, Error<T::Vertex>> {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(self.graph).collect();
+        // Initialize the last relaxed vertex.
+        let mut relaxed = None;
+        // Relax the edges at most |V| times, the last iteration detects negative cycles.
+        for _ in 0..vertices.len() {
+            // Reset the last relaxed vertex.
+            relaxed = None;
+            // For each vertex already reached from the source vertex ...
+            for &x in vertices.iter() {
+                if let Some(&d) = self.distance.get(x) {
+                    // ... iterate over its reachable vertices.
+                    for y in (self.reachable)(self.graph, x) {
+                        // Compute the distance through the current vertex.
+                        let d = d + (self.weight)(x, y);
+                        // If the new distance is shorter than the current one ...
+                        if self.distance.get(y).is_none_or(|&e| d < e) {
+                            // ... update its distance ...
+                            self.distance.insert(y, d);
+                            // ... set its predecessor ...
+                            self.predecessor.insert(y, x);
+                            // ... and mark it as relaxed.
+                            relaxed = Some(y);
+                        }
+                    }
+                }
+            }
+            // If no edge has been relaxed, then the distances are final.
+            if relaxed.is_none() {
+                break;
+            }
+        }
+        // If an edge has been relaxed during the last iteration, there is a negative cycle.
+        if let Some(mut x) = relaxed {
+            // Walk back the predecessors to be sure to land on the cycle.
+            for _ in 0..vertices.len() {
+                x = self.predecessor[x];
+            }
+            // Collect the cycle backwards, starting from the landing vertex.
+            let mut cycle = vec![x.clone()];
+            // Follow the predecessors until the landing vertex is reached again.
+            let mut y = self.predecessor[x];
+            while y != x {
+                cycle.push(y.clone());
+                y = self.predecessor[y];
+            }
+            // Close the cycle.
+            cycle.push(x.clone());
+            // Reverse the cycle to follow the edges direction.
+            cycle.reverse();
+            // Rotate the cycle so that it starts from its minimum vertex.
+            let i = (0..cycle.len() - 1).min_by_key(|&i| &cycle[i]).unwrap();
+            cycle.pop();
+            cycle.rotate_left(i);
+            cycle.push(cycle[0].clone());
+
+            return Err(Error::NegativeCycle(cycle));
+        }
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,175 @@
+use crate::traits::Storage;
+use crate::types::VertexIterator;
+use crate::utils::MinPriority;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::iter::FusedIterator;
+
+/// Dijkstra search structure.
+///
+/// This structure contains the `distance` and `predecessor` maps.
+///
+/// Vertices are visited in non-decreasing order of distance from the source vertex,
+/// where the weight of each edge is given by a user-supplied closure.
+///
+pub struct Dijkstra<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Reachable vertices of distance one from given vertex.
+    reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Weight of a given edge.
+    weight: F,
+    /// Optional target vertex, the search stops once it is visited.
+    target: Option<&'a T::Vertex>,
+    /// To-be-visited priority queue.
+    queue: BinaryHeap<MinPriority<f64, &'a T::Vertex>>,
+    /// Already visited set.
+    visited: HashSet<&'a T::Vertex>,
+    /// Distance from the source vertex.
+    pub distance: HashMap<&'a T::Vertex, f64>,
+    /// Predecessor of each discovered vertex (except the source vertex).
+    pub predecessor: HashMap<&'a T::Vertex, &'a T::Vertex>,
+}
+
+impl<'a, T, F> Dijkstra<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a Dijkstra iterator.
+    ///
+    /// Build a Dijkstra[^1] iterator for a given graph, source vertex and weight function.
+    /// If a target vertex is given, the search terminates as soon as it is visited.
+    ///
+    /// [^1]: [Dijkstra, E. W. (1959). A note on two problems in connexion with graphs. Numerische mathematik, 1(1), 269-271.](https://scholar.google.com/scholar?q=A+note+on+two+problems+in+connexion+with+graphs)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source vertex or the (optional) target vertex are not in the graph,
+    /// or if a negative weight is encountered during the search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::DirectedAdjacencyListGraph;
+    ///
+    /// // Build a directed graph with weights as edge attributes.
+    /// let mut g = DirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (0, 2), (1, 2), (2, 3)
+    /// ]);
+    /// g.set_edge_attrs(&0, &1, 1.);
+    /// g.set_edge_attrs(&0, &2, 4.);
+    /// g.set_edge_attrs(&1, &2, 2.);
+    /// g.set_edge_attrs(&2, &3, 1.);
+    ///
+    /// // Build the search object over graph with `0` as source vertex.
+    /// let mut search = Dijkstra::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Collect visit order, preserving search structure.
+    /// let order: Vec<_> = search.by_ref().collect();
+    /// // The visit returns vertices by increasing distance.
+    /// assert_eq!(order, [&0, &1, &2, &3]);
+    ///
+    /// // The source vertex has distance zero from itself ...
+    /// assert_eq!(search.distance[&0], 0.);
+    /// // ... and no predecessor by definition.
+    /// assert_eq!(search.predecessor.contains_key(&0), false);
+    ///
+    /// // For example, vertex `2` has distance three from `0` ...
+    /// assert_eq!(search.distance[&2], 3.);
+    /// // ... and its predecessor is `1`.
+    /// assert_eq!(search.predecessor[&2], &1);
+    /// ```
+    ///
+    pub fn new(
+        g: &'a T,
+        x: &'a T::Vertex,
+        y: Option<&'a T::Vertex>,
+        f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+        w: F,
+    ) -> Self {
+        // Assert that source vertex is in graph.
+        assert!(g.has_vertex(x));
+        // Assert that target vertex, if any, is in graph.
+        assert!(y.iter().all(|y| g.has_vertex(y)));
+
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set reachability function.
+            reachable: f,
+            // Set weight function.
+            weight: w,
+            // Set target vertex.
+            target: y,
+            // Initialize the to-be-visited queue with the source vertex.
+            queue: BinaryHeap::from([MinPriority(0., x)]),
+            // Initialize the already visited set.
+            visited: Default::default(),
+            // Initialize the distance map with the source vertex.
+            distance: HashMap::from([(x, 0.)]),
+            // Initialize the predecessor map.
+            predecessor: Default::default(),
+        }
+    }
+}
+
+impl<'a, T, F> Iterator for Dijkstra<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    type Item = &'a T::Vertex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // While there are still vertices to be visited.
+        while let Some(MinPriority(d, x)) = self.queue.pop() {
+            // If the vertex was already visited, skip its outdated entry.
+            if !self.visited.insert(x) {
+                continue;
+            }
+            // If the vertex is the target vertex ...
+            if self.target == Some(x) {
+                // ... then terminate the search early.
+                self.queue.clear();
+                // Return the target vertex.
+                return Some(x);
+            }
+            // Iterate over the reachable vertices of the popped vertex.
+            for y in (self.reachable)(self.graph, x) {
+                // Get the weight of the edge.
+                let w = (self.weight)(x, y);
+                // Assert the weight is non-negative.
+                assert!(w >= 0., "negative weight found for edge ({:?}, {:?})", x, y);
+                // Compute the distance through the popped vertex.
+                let d = d + w;
+                // If the new distance is shorter than the current one ...
+                if self.distance.get(y).is_none_or(|&e| d < e) {
+                    // ... update its distance ...
+                    self.distance.insert(y, d);
+                    // ... set its predecessor ...
+                    self.predecessor.insert(y, x);
+                    // ... and push it into the to-be-visited queue.
+                    self.queue.push(MinPriority(d, y));
+                }
+            }
+            // Return next vertex.
+            return Some(x);
+        }
+
+        // Otherwise end is reached.
+        None
+    }
+}
+
+impl<'a, T, F> FusedIterator for Dijkstra<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+}
@@ -0,0 +1,201 @@
+use super::BellmanFord;
+use crate::traits::Storage;
+use crate::types::{Error, VertexIterator};
+use crate::V;
+use ndarray::Array2;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Floyd-Warshall all-pairs shortest paths structure.
+///
+/// This structure contains the `distance` and `predecessor` matrices, which are indexed
+/// following the vertex set order, consistently with [`Convert::dense_adjacency_matrix`](crate::traits::Convert::dense_adjacency_matrix).
+///
+/// If a vertex is not reachable from another one, their distance is [`f64::INFINITY`].
+///
+pub struct FloydWarshall<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Reachable vertices of distance one from given vertex.
+    reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Weight of a given edge.
+    weight: F,
+    /// Vertex set, in order.
+    vertices: Vec<&'a T::Vertex>,
+    /// Vertex-to-index mapping.
+    index: HashMap<&'a T::Vertex, usize>,
+    /// Distance matrix.
+    pub distance: Array2<f64>,
+    /// Predecessor matrix, i.e. the index of the predecessor of the $j$-th vertex
+    /// in the shortest path from the $i$-th vertex, if any.
+    pub predecessor: Array2<Option<usize>>,
+}
+
+impl<'a, T, F> FloydWarshall<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a Floyd-Warshall search structure.
+    ///
+    /// Build a Floyd-Warshall[^1] search structure for a given graph and weight function.
+    ///
+    /// [^1]: [Floyd, R. W. (1962). Algorithm 97: shortest path. Communications of the ACM, 5(6), 345.](https://scholar.google.com/scholar?q=Algorithm+97%3A+shortest+path)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::DirectedAdjacencyListGraph;
+    ///
+    /// // Build a directed graph with weights as edge attributes.
+    /// let mut g = DirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (1, 2), (0, 2)
+    /// ]);
+    /// g.set_edge_attrs(&0, &1, 1.);
+    /// g.set_edge_attrs(&1, &2, -2.);
+    /// g.set_edge_attrs(&0, &2, 3.);
+    ///
+    /// // Build the search object over graph.
+    /// let mut search = FloydWarshall::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// assert!(search.run().is_ok());
+    ///
+    /// // The distance matrix follows the vertex set order.
+    /// let inf = f64::INFINITY;
+    /// assert_eq!(
+    ///     search.distance,
+    ///     arr2(&[
+    ///         [0., 1., -1.],
+    ///         [inf, 0., -2.],
+    ///         [inf, inf, 0.],
+    ///     ])
+    /// );
+    ///
+    /// // The shortest path between any two vertices can be reconstructed.
+    /// assert_eq!(search.path(&0, &2), Some(vec![&0, &1, &2]));
+    /// assert_eq!(search.path(&2, &0), None);
+    /// ```
+    ///
+    pub fn new(g: &'a T, f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>, w: F) -> Self {
+        // Get the vertex set.
+        let vertices: Vec<_> = V!(g).collect();
+        // Build vid-to-index mapping.
+        let index = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Get the graph order.
+        let n = vertices.len();
+
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set reachability function.
+            reachable: f,
+            // Set weight function.
+            weight: w,
+            // Set the vertex set.
+            vertices,
+            // Set the vertex-to-index mapping.
+            index,
+            // Initialize the distance matrix.
+            distance: Array2::from_elem((n, n), f64::INFINITY),
+            // Initialize the predecessor matrix.
+            predecessor: Array2::from_elem((n, n), None),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    /// # Errors
+    ///
+    /// The graph contains a negative cycle, in which case the cycle is returned
+    /// as a sequence of vertices, starting and ending with the same vertex.
+    ///
+    pub fn run(&mut self) -> Result<&Self, Error<T::Vertex>> {
+        // Get the graph order.
+        let n = self.vertices.len();
+        // Any vertex has distance zero from itself.
+        for i in 0..n {
+            self.distance[(i, i)] = 0.;
+        }
+        // Initialize the distances with the edges weights.
+        for (i, &x) in self.vertices.iter().enumerate() {
+            for y in (self.reachable)(self.graph, x) {
+                // Get the index of the reachable vertex.
+                let j = self.index[y];
+                // Get the weight of the edge.
+                let w = (self.weight)(x, y);
+                // Keep the edge only if shorter, e.g. w.r.t. non-negative self-loops.
+                if w < self.distance[(i, j)] {
+                    self.distance[(i, j)] = w;
+                    self.predecessor[(i, j)] = Some(i);
+                }
+            }
+        }
+        // For each intermediate vertex ...
+        for k in 0..n {
+            // ... for each pair of vertices ...
+            for i in 0..n {
+                // ... skip if the intermediate vertex is unreachable ...
+                if self.distance[(i, k)] == f64::INFINITY {
+                    continue;
+                }
+                for j in 0..n {
+                    // ... compute the distance through the intermediate vertex ...
+                    let d = self.distance[(i, k)] + self.distance[(k, j)];
+                    // ... and if it is shorter than the current one, update it.
+                    if d < self.distance[(i, j)] {
+                        self.distance[(i, j)] = d;
+                        self.predecessor[(i, j)] = self.predecessor[(k, j)];
+                    }
+                }
+            }
+        }
+        // If any vertex has negative distance from itself, then there is a negative cycle.
+        if (0..n).any(|i| self.distance[(i, i)] < 0.) {
+            // Get the weight function.
+            let w = &self.weight;
+            // Find the negative cycle, if any.
+            BellmanFord::new(self.graph, None, self.reachable, w).run()?;
+        }
+
+        Ok(self)
+    }
+
+    /// Shortest path between two vertices.
+    ///
+    /// Returns the shortest path from the source vertex to the target vertex,
+    /// or [`None`] if the target vertex is not reachable from the source vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if at least one of the two vertices is not in the graph.
+    ///
+    pub fn path(&self, x: &T::Vertex, y: &T::Vertex) -> Option<Vec<&'a T::Vertex>> {
+        // Get the indices of the vertices.
+        let (i, mut j) = (self.index[x], self.index[y]);
+        // If the target vertex is not reachable, then there is no path.
+        if self.distance[(i, j)] == f64::INFINITY {
+            return None;
+        }
+        // Initialize the path with the target vertex.
+        let mut path = vec![self.vertices[j]];
+        // Follow the predecessors until the source vertex is reached.
+        while i != j {
+            j = self.predecessor[(i, j)].unwrap();
+            path.push(self.vertices[j]);
+        }
+        // Reverse the path to follow the edges direction.
+        path.reverse();
+
+        Some(path)
+    }
+}
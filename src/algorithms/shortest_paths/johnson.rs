@@ -0,0 +1,155 @@
+use super::{BellmanFord, Dijkstra};
+use crate::traits::Storage;
+use crate::types::{Error, VertexIterator};
+use crate::V;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Johnson all-pairs shortest paths structure.
+///
+/// This structure contains the `distance` and `predecessor` maps of each source vertex.
+///
+/// Differently from [`FloydWarshall`](super::FloydWarshall), only reachable vertices are stored,
+/// which makes this algorithm suitable for sparse graphs.
+///
+pub struct Johnson<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Reachable vertices of distance one from given vertex.
+    reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Weight of a given edge.
+    weight: F,
+    /// Distance from each source vertex.
+    pub distance: HashMap<&'a T::Vertex, HashMap<&'a T::Vertex, f64>>,
+    /// Predecessor of each discovered vertex (except the source vertex) for each source vertex.
+    pub predecessor: HashMap<&'a T::Vertex, HashMap<&'a T::Vertex, &'a T::Vertex>>,
+}
+
+impl<'a, T, F> Johnson<'a, T, F>
+where
+    T: Storage,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a Johnson search structure.
+    ///
+    /// Build a Johnson[^1] search structure for a given graph and weight function.
+    ///
+    /// The weights are first re-weighted to be non-negative using the potentials computed by [`BellmanFord`],
+    /// then [`Dijkstra`] is executed from each vertex.
+    ///
+    /// [^1]: [Johnson, D. B. (1977). Efficient algorithms for shortest paths in sparse networks. Journal of the ACM, 24(1), 1-13.](https://scholar.google.com/scholar?q=Efficient+algorithms+for+shortest+paths+in+sparse+networks)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::DirectedAdjacencyListGraph;
+    ///
+    /// // Build a directed graph with weights as edge attributes.
+    /// let mut g = DirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (1, 2), (0, 2)
+    /// ]);
+    /// g.set_edge_attrs(&0, &1, 1.);
+    /// g.set_edge_attrs(&1, &2, -2.);
+    /// g.set_edge_attrs(&0, &2, 3.);
+    ///
+    /// // Build the search object over graph.
+    /// let mut search = Johnson::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// assert!(search.run().is_ok());
+    ///
+    /// // Vertex `2` has distance minus one from `0` ...
+    /// assert_eq!(search.distance[&0][&2], -1.);
+    /// // ... while `0` is not reachable from `2`.
+    /// assert_eq!(search.distance[&2].get(&0), None);
+    ///
+    /// // The shortest path between any two vertices can be reconstructed.
+    /// assert_eq!(search.path(&0, &2), Some(vec![&0, &1, &2]));
+    /// assert_eq!(search.path(&2, &0), None);
+    /// ```
+    ///
+    pub fn new(g: &'a T, f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>, w: F) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set reachability function.
+            reachable: f,
+            // Set weight function.
+            weight: w,
+            // Initialize the distance map.
+            distance: Default::default(),
+            // Initialize the predecessor map.
+            predecessor: Default::default(),
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    /// # Errors
+    ///
+    /// The graph contains a negative cycle, in which case the cycle is returned
+    /// as a sequence of vertices, starting and ending with the same vertex.
+    ///
+    pub fn run(&mut self) -> Result<&Self, Error<T::Vertex>> {
+        // Get the weight function.
+        let w = &self.weight;
+        // Compute the vertices potentials w.r.t. a virtual source vertex.
+        let mut search = BellmanFord::new(self.graph, None, self.reachable, w);
+        // Return early if there is a negative cycle.
+        search.run()?;
+        // Get the vertices potentials.
+        let h = search.distance;
+        // For each vertex in the graph ...
+        for x in V!(self.graph) {
+            // ... execute Dijkstra using the re-weighted non-negative weights ...
+            // NOTE: The re-weighted weights are clamped to zero in order
+            // to avoid negative values due to floating point rounding.
+            let mut search = Dijkstra::new(self.graph, x, None, self.reachable, |y, z| {
+                f64::max(w(y, z) + h[y] - h[z], 0.)
+            });
+            // ... until every reachable vertex is visited.
+            search.by_ref().for_each(drop);
+            // Restore the original distances.
+            let distance = search.distance.into_iter().map(|(y, d)| (y, d - h[x] + h[y])).collect();
+            // Store the distance and predecessor maps.
+            self.distance.insert(x, distance);
+            self.predecessor.insert(x, search.predecessor);
+        }
+
+        Ok(self)
+    }
+
+    /// Shortest path between two vertices.
+    ///
+    /// Returns the shortest path from the source vertex to the target vertex,
+    /// or [`None`] if the target vertex is not reachable from the source vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source vertex is not in the graph.
+    ///
+    pub fn path(&self, x: &T::Vertex, y: &T::Vertex) -> Option<Vec<&'a T::Vertex>> {
+        // If the target vertex is not reachable, then there is no path.
+        let (&y, _) = self.distance[x].get_key_value(y)?;
+        // Get the predecessor map of the source vertex.
+        let predecessor = &self.predecessor[x];
+        // Initialize the path with the target vertex.
+        let mut path = vec![y];
+        // Follow the predecessors until the source vertex is reached.
+        while let Some(&z) = predecessor.get(path[path.len() - 1]) {
+            path.push(z);
+        }
+        // Reverse the path to follow the edges direction.
+        path.reverse();
+
+        Some(path)
+    }
+}
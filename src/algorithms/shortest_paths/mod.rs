@@ -0,0 +1,14 @@
+mod a_star;
+pub use a_star::AStar;
+
+mod bellman_ford;
+pub use bellman_ford::BellmanFord;
+
+mod dijkstra;
+pub use dijkstra::Dijkstra;
+
+mod floyd_warshall;
+pub use floyd_warshall::FloydWarshall;
+
+mod johnson;
+pub use johnson::Johnson;
@@ -23,6 +23,8 @@ where
     graph: &'a T,
     /// Reachable vertices of distance one from given vertex.
     reachable: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    /// Whether the reachability function is symmetric, i.e. the graph is undirected.
+    undirected: bool,
     /// The currently visited stack.
     stack: Vec<&'a T::Vertex>,
     /// Map of *blocked* vertices in order to avoid double counting.
@@ -86,6 +88,8 @@ where
             graph: g,
             // Set reachability function.
             reachable: f,
+            // Set the graph as directed.
+            undirected: false,
             // Initialize the currently visited stack.
             stack: Default::default(),
             // Initialize blocked map.
@@ -97,6 +101,65 @@ where
         }
     }
 
+    /// Build an *all cycles* search structure for undirected graphs.
+    ///
+    /// Build an *all cycles* search structure which retains the cycles found,
+    /// together with the popularity count of each vertex.
+    ///
+    /// Differently from [`new`](Self::new), the reachability function is assumed to be symmetric,
+    /// therefore a single edge is not reported as a cycle of length two, and each cycle is
+    /// reported only once, i.e. in the orientation where the second vertex is lower than the
+    /// second-to-last one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build an undirected graph.
+    /// let g = Graph::from_edges([
+    ///     (0, 1), (1, 2), (2, 3), (2, 4), (3, 4), (4, 1)
+    /// ]);
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = AllSimpleCycles::from(&g);
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // In this graph there are three undirected cycles,
+    /// // these are reported in discovery order.
+    /// assert_eq!(
+    ///     search.simple_cycles,
+    ///     [
+    ///         vec![&1, &2, &3, &4, &1],
+    ///         vec![&1, &2, &4, &1],
+    ///         vec![&2, &3, &4, &2]
+    ///     ]
+    /// );
+    ///
+    /// // Vertex `0` is not present in any cycle,
+    /// // therefore it is not in the popularity map.
+    /// assert_eq!(search.popularity.get(&0), None);
+    /// // Vertices `2` and `4` are present in every cycle.
+    /// assert_eq!(search.popularity[&1], 2);
+    /// assert_eq!(search.popularity[&2], 3);
+    /// assert_eq!(search.popularity[&3], 2);
+    /// assert_eq!(search.popularity[&4], 3);
+    /// ```
+    ///
+    pub fn new_undirected(
+        g: &'a T,
+        f: fn(&'a T, &'a T::Vertex) -> Box<dyn VertexIterator<'a, T::Vertex> + 'a>,
+    ) -> Self {
+        Self {
+            // Set the graph as undirected.
+            undirected: true,
+            // Initialize the remaining fields.
+            ..Self::new(g, f)
+        }
+    }
+
     fn block(&mut self, x: &'a T::Vertex) {
         for y in (self.reachable)(self.graph, x) {
             if y < self.stack[0] {
@@ -112,6 +175,16 @@ where
         }
     }
 
+    fn is_canonical(&self) -> bool {
+        match self.stack.len() {
+            // A self-edge is a cycle.
+            1 => true,
+            // Otherwise, the cycle is in canonical orientation if the second vertex
+            // is lower than the second-to-last one.
+            n => self.stack[1] < self.stack[n - 1],
+        }
+    }
+
     fn circuit(&mut self, x: &'a T::Vertex) -> bool {
         // Initialize found flag.
         let mut found = false;
@@ -126,23 +199,30 @@ where
             // then skip this iteration.
             if y < self.stack[0] {
                 continue;
+            // If the graph is undirected and current vertex is the starting vertex,
+            // then skip the single edge traversed back, which is not a cycle.
+            } else if self.undirected && y == self.stack[0] && self.stack.len() == 2 {
+                continue;
             // If current vertex is the starting vertex,
             // then a cycle has been found.
             } else if y == self.stack[0] {
-                // Store the cycle.
-                self.simple_cycles.push({
-                    // Clone the current stack.
-                    let mut c = self.stack.clone();
-                    // Add the leading vertex.
-                    c.push(y);
-                    // Return the completed cycle.
-                    c
-                });
-                // Update popularity.
-                for z in self.stack.iter() {
-                    *self.popularity.entry(z).or_default() += 1;
+                // If the graph is undirected, skip cycles traversed in the opposite orientation.
+                if !self.undirected || self.is_canonical() {
+                    // Store the cycle.
+                    self.simple_cycles.push({
+                        // Clone the current stack.
+                        let mut c = self.stack.clone();
+                        // Add the leading vertex.
+                        c.push(y);
+                        // Return the completed cycle.
+                        c
+                    });
+                    // Update popularity.
+                    for z in self.stack.iter() {
+                        *self.popularity.entry(z).or_default() += 1;
+                    }
                 }
-                // Set the found flag.
+                // Set the found flag, since the starting vertex is reachable through a cycle anyway.
                 found = true;
             // Finally, if the current vertex has not been blocked...
             } else if !self.blocked.contains_key(&y) {
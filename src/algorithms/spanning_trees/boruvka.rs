@@ -0,0 +1,160 @@
+use crate::graphs::UndirectedAdjacencyListGraph;
+use crate::traits::{Storage, Undirected};
+use crate::utils::DisjointSet;
+use crate::V;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Borůvka search structure.
+///
+/// This structure contains the minimum spanning forest `tree` and its `total_weight`,
+/// where the weight of each edge is given by a user-supplied closure.
+///
+pub struct Boruvka<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Weight of a given edge.
+    weight: F,
+    /// Minimum spanning forest, with the same vertex set of the given graph.
+    pub tree: UndirectedAdjacencyListGraph<T::Vertex>,
+    /// Total weight of the minimum spanning forest.
+    pub total_weight: f64,
+}
+
+impl<'a, T, F> Boruvka<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a Borůvka search structure.
+    ///
+    /// Build a Borůvka[^1] search structure, which computes a minimum spanning forest of a given graph
+    /// by repeatedly adding, for each tree of the current forest, the lightest edge leaving it.
+    /// Ties are broken by comparing the endpoints of the edges, which is required to avoid cycles.
+    ///
+    /// [^1]: [Nešetřil, J., Milková, E., & Nešetřilová, H. (2001). Otakar Borůvka on minimum spanning tree problem: Translation of both the 1926 papers, comments, history. Discrete Mathematics, 233(1-3), 3-36.](https://scholar.google.com/scholar?q=Otakar+Boruvka+on+minimum+spanning+tree+problem)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::UndirectedAdjacencyListGraph;
+    ///
+    /// // Build an undirected graph with weights as edge attributes.
+    /// let mut g = UndirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (0, 2), (1, 2), (2, 3)
+    /// ]);
+    /// for (x, y, w) in [(0, 1, 4.), (0, 2, 1.), (1, 2, 2.), (2, 3, 5.)] {
+    ///     g.set_edge_attrs(&x, &y, w);
+    ///     g.set_edge_attrs(&y, &x, w);
+    /// }
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = Boruvka::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The heaviest edge of the triangle is left out.
+    /// assert_eq!(search.tree, Graph::from_edges([(0, 2), (1, 2), (2, 3)]));
+    /// assert_eq!(search.total_weight, 8.);
+    /// ```
+    ///
+    pub fn new(g: &'a T, w: F) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set weight function.
+            weight: w,
+            // Initialize the spanning forest.
+            tree: Default::default(),
+            // Initialize the total weight.
+            total_weight: 0.,
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = V!(self.graph).enumerate().map(|(i, x)| (x, i)).collect();
+        // Initialize a disjoint-set forest over the vertices.
+        let mut sets = DisjointSet::new(index.len());
+        // Get the weighted edge set, skipping self-edges.
+        let edges: Vec<_> = self
+            .graph
+            .edges_iter()
+            .filter(|(x, y)| x < y)
+            .map(|(x, y)| ((self.weight)(x, y), x, y))
+            .collect();
+        // Initialize the selected edges.
+        let mut tree = Vec::new();
+        // Repeat until no tree has an edge leaving it.
+        loop {
+            // Initialize the lightest edge leaving each tree.
+            let mut lightest: Vec<Option<usize>> = vec![None; index.len()];
+            // For each edge whose endpoints are in different trees.
+            for (k, (_, x, y)) in edges.iter().enumerate() {
+                let (i, j) = (sets.find(index[x]), sets.find(index[y]));
+                if i == j {
+                    continue;
+                }
+                // Update the lightest edge of both trees, breaking ties by endpoints.
+                for r in [i, j] {
+                    if lightest[r].is_none_or(|l| edges[k].partial_cmp(&edges[l]) == Some(std::cmp::Ordering::Less)) {
+                        lightest[r] = Some(k);
+                    }
+                }
+            }
+            // Merge the trees through their lightest edges.
+            let mut merged = false;
+            for k in lightest.into_iter().flatten() {
+                let (w, x, y) = edges[k];
+                // The same edge may be the lightest one for both its trees.
+                if sets.union(index[x], index[y]) {
+                    tree.push((x.clone(), y.clone()));
+                    self.total_weight += w;
+                    merged = true;
+                }
+            }
+            // If no tree was merged, then the forest is spanning.
+            if !merged {
+                break;
+            }
+        }
+        // Build the spanning forest over the whole vertex set.
+        self.tree = UndirectedAdjacencyListGraph::new(V!(self.graph).cloned(), tree);
+
+        self
+    }
+}
+
+impl<'a, T> From<&'a T> for Boruvka<'a, T, fn(&'a T::Vertex, &'a T::Vertex) -> f64>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph, with unit weights.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g, |_, _| 1.)
+    }
+}
+
+impl<'a, T, F> From<(&'a T, F)> for Boruvka<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Builds a search object from a given graph and a weight function.
+    ///
+    fn from((g, w): (&'a T, F)) -> Self {
+        Self::new(g, w)
+    }
+}
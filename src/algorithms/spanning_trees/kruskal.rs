@@ -0,0 +1,137 @@
+use crate::graphs::UndirectedAdjacencyListGraph;
+use crate::traits::{Storage, Undirected};
+use crate::utils::DisjointSet;
+use crate::V;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Kruskal search structure.
+///
+/// This structure contains the minimum spanning forest `tree` and its `total_weight`,
+/// where the weight of each edge is given by a user-supplied closure.
+///
+pub struct Kruskal<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Weight of a given edge.
+    weight: F,
+    /// Minimum spanning forest, with the same vertex set of the given graph.
+    pub tree: UndirectedAdjacencyListGraph<T::Vertex>,
+    /// Total weight of the minimum spanning forest.
+    pub total_weight: f64,
+}
+
+impl<'a, T, F> Kruskal<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a Kruskal search structure.
+    ///
+    /// Build a Kruskal[^1] search structure, which computes a minimum spanning forest of a given graph
+    /// by adding edges in order of non-decreasing weight, unless they would close a cycle.
+    /// Ties are broken by comparing the endpoints of the edges, so that the forest is unique.
+    ///
+    /// [^1]: [Kruskal, J. B. (1956). On the shortest spanning subtree of a graph and the traveling salesman problem. Proceedings of the American Mathematical Society, 7(1), 48-50.](https://scholar.google.com/scholar?q=On+the+shortest+spanning+subtree+of+a+graph+and+the+traveling+salesman+problem)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::UndirectedAdjacencyListGraph;
+    ///
+    /// // Build an undirected graph with weights as edge attributes.
+    /// let mut g = UndirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (0, 2), (1, 2), (2, 3)
+    /// ]);
+    /// for (x, y, w) in [(0, 1, 4.), (0, 2, 1.), (1, 2, 2.), (2, 3, 5.)] {
+    ///     g.set_edge_attrs(&x, &y, w);
+    ///     g.set_edge_attrs(&y, &x, w);
+    /// }
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = Kruskal::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The heaviest edge of the triangle is left out.
+    /// assert_eq!(search.tree, Graph::from_edges([(0, 2), (1, 2), (2, 3)]));
+    /// assert_eq!(search.total_weight, 8.);
+    /// ```
+    ///
+    pub fn new(g: &'a T, w: F) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set weight function.
+            weight: w,
+            // Initialize the spanning forest.
+            tree: Default::default(),
+            // Initialize the total weight.
+            total_weight: 0.,
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = V!(self.graph).enumerate().map(|(i, x)| (x, i)).collect();
+        // Initialize a disjoint-set forest over the vertices.
+        let mut sets = DisjointSet::new(index.len());
+        // Get the weighted edge set, skipping self-edges.
+        let mut edges: Vec<_> = self
+            .graph
+            .edges_iter()
+            .filter(|(x, y)| x < y)
+            .map(|(x, y)| ((self.weight)(x, y), x, y))
+            .collect();
+        // Sort the edges by weight, breaking ties by endpoints.
+        edges.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        // Initialize the selected edges.
+        let mut tree = Vec::new();
+        // For each edge, in order.
+        for (w, x, y) in edges {
+            // If the endpoints are in different trees, then merge them through the edge.
+            if sets.union(index[x], index[y]) {
+                tree.push((x.clone(), y.clone()));
+                self.total_weight += w;
+            }
+        }
+        // Build the spanning forest over the whole vertex set.
+        self.tree = UndirectedAdjacencyListGraph::new(V!(self.graph).cloned(), tree);
+
+        self
+    }
+}
+
+impl<'a, T> From<&'a T> for Kruskal<'a, T, fn(&'a T::Vertex, &'a T::Vertex) -> f64>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph, with unit weights.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g, |_, _| 1.)
+    }
+}
+
+impl<'a, T, F> From<(&'a T, F)> for Kruskal<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Builds a search object from a given graph and a weight function.
+    ///
+    fn from((g, w): (&'a T, F)) -> Self {
+        Self::new(g, w)
+    }
+}
@@ -0,0 +1,8 @@
+mod boruvka;
+pub use boruvka::Boruvka;
+
+mod kruskal;
+pub use kruskal::Kruskal;
+
+mod prim;
+pub use prim::Prim;
@@ -0,0 +1,155 @@
+use crate::graphs::UndirectedAdjacencyListGraph;
+use crate::traits::{Storage, Undirected};
+use crate::utils::MinPriority;
+use crate::{Ne, V};
+use std::collections::{BinaryHeap, HashSet};
+use std::vec::Vec;
+
+/// Prim search structure.
+///
+/// This structure contains the minimum spanning forest `tree` and its `total_weight`,
+/// where the weight of each edge is given by a user-supplied closure.
+///
+pub struct Prim<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Given graph reference.
+    graph: &'a T,
+    /// Weight of a given edge.
+    weight: F,
+    /// Minimum spanning forest, with the same vertex set of the given graph.
+    pub tree: UndirectedAdjacencyListGraph<T::Vertex>,
+    /// Total weight of the minimum spanning forest.
+    pub total_weight: f64,
+}
+
+impl<'a, T, F> Prim<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Build a Prim search structure.
+    ///
+    /// Build a Prim[^1] search structure, which computes a minimum spanning forest of a given graph
+    /// by growing a tree from each unvisited vertex, adding the lightest edge leaving the tree at each step.
+    /// Ties are broken by comparing the endpoints of the edges, so that the forest is unique.
+    ///
+    /// [^1]: [Prim, R. C. (1957). Shortest connection networks and some generalizations. The Bell System Technical Journal, 36(6), 1389-1401.](https://scholar.google.com/scholar?q=Shortest+connection+networks+and+some+generalizations)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    /// use grathe::graphs::attributes::AttributesMap;
+    /// use grathe::graphs::UndirectedAdjacencyListGraph;
+    ///
+    /// // Build an undirected graph with weights as edge attributes.
+    /// let mut g = UndirectedAdjacencyListGraph::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+    ///     (0, 1), (0, 2), (1, 2), (2, 3)
+    /// ]);
+    /// for (x, y, w) in [(0, 1, 4.), (0, 2, 1.), (1, 2, 2.), (2, 3, 5.)] {
+    ///     g.set_edge_attrs(&x, &y, w);
+    ///     g.set_edge_attrs(&y, &x, w);
+    /// }
+    ///
+    /// // Build the search object over said graph.
+    /// let mut search = Prim::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+    ///
+    /// // Run the algorithm and assert later.
+    /// search.run();
+    ///
+    /// // The heaviest edge of the triangle is left out.
+    /// assert_eq!(search.tree, Graph::from_edges([(0, 2), (1, 2), (2, 3)]));
+    /// assert_eq!(search.total_weight, 8.);
+    /// ```
+    ///
+    pub fn new(g: &'a T, w: F) -> Self {
+        Self {
+            // Set target graph.
+            graph: g,
+            // Set weight function.
+            weight: w,
+            // Initialize the spanning forest.
+            tree: Default::default(),
+            // Initialize the total weight.
+            total_weight: 0.,
+        }
+    }
+
+    /// Execute the procedure.
+    ///
+    /// Execute the procedure and store the results for later queries.
+    ///
+    pub fn run(&mut self) -> &Self {
+        // Initialize the set of visited vertices.
+        let mut visited = HashSet::new();
+        // Initialize the min-priority queue of edges leaving the current tree.
+        let mut queue = BinaryHeap::new();
+        // Initialize the selected edges.
+        let mut tree = Vec::new();
+        // For each vertex in the graph, grow a tree rooted in it, if not visited yet.
+        for r in V!(self.graph) {
+            if !visited.insert(r) {
+                continue;
+            }
+            // Push the edges leaving the root.
+            self.push(&mut queue, &visited, r);
+            // While there are edges leaving the tree.
+            while let Some(MinPriority((w, _, _), (x, y))) = queue.pop() {
+                // If the edge does not leave the tree anymore, skip it.
+                if !visited.insert(y) {
+                    continue;
+                }
+                // Add the edge to the tree.
+                tree.push((x.clone(), y.clone()));
+                self.total_weight += w;
+                // Push the edges leaving the new vertex.
+                self.push(&mut queue, &visited, y);
+            }
+        }
+        // Build the spanning forest over the whole vertex set.
+        self.tree = UndirectedAdjacencyListGraph::new(V!(self.graph).cloned(), tree);
+
+        self
+    }
+
+    fn push(
+        &self,
+        queue: &mut BinaryHeap<MinPriority<(f64, &'a T::Vertex, &'a T::Vertex), (&'a T::Vertex, &'a T::Vertex)>>,
+        visited: &HashSet<&'a T::Vertex>,
+        x: &'a T::Vertex,
+    ) {
+        // For each neighbor that has not been visited yet, skipping self-edges.
+        for y in Ne!(self.graph, x).filter(|y| !visited.contains(y)) {
+            // Order the endpoints as Kruskal and Borůvka do, so that asymmetric weights agree.
+            let (u, v) = (x.min(y), x.max(y));
+            // Prioritize the edge by weight, breaking ties by endpoints.
+            queue.push(MinPriority(((self.weight)(u, v), u, v), (x, y)));
+        }
+    }
+}
+
+impl<'a, T> From<&'a T> for Prim<'a, T, fn(&'a T::Vertex, &'a T::Vertex) -> f64>
+where
+    T: Undirected,
+{
+    /// Builds a search object from a given graph, with unit weights.
+    ///
+    fn from(g: &'a T) -> Self {
+        Self::new(g, |_, _| 1.)
+    }
+}
+
+impl<'a, T, F> From<(&'a T, F)> for Prim<'a, T, F>
+where
+    T: Undirected,
+    F: Fn(&'a T::Vertex, &'a T::Vertex) -> f64,
+{
+    /// Builds a search object from a given graph and a weight function.
+    ///
+    fn from((g, w): (&'a T, F)) -> Self {
+        Self::new(g, w)
+    }
+}
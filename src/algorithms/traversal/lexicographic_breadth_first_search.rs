@@ -88,6 +88,10 @@ where
         if let Some(p) = self.partitions.front_mut() {
             // Select the first vertex from the partition.
             let x = p.pop_front().unwrap();
+            // If the partition is now empty, remove it from the queue.
+            if p.is_empty() {
+                self.partitions.pop_front();
+            }
             // Get neighbors of selected vertex.
             let mut neighbors: HashSet<_> = self.graph.neighbors_iter(x).collect();
             // Initialize the new partitioning ordering.
@@ -0,0 +1,345 @@
+#[cfg(test)]
+mod directed {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn degree_centrality() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    assert!(algorithms::in_degree_centrality(&g).is_empty());
+
+                    // Build a graph with a single vertex.
+                    let g = $T::<$U>::new([0], []);
+                    assert_eq!(algorithms::in_degree_centrality(&g), HashMap::from([(&0, 1.)]));
+                    assert_eq!(algorithms::out_degree_centrality(&g), HashMap::from([(&0, 1.)]));
+
+                    // Build an out-star graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4)]);
+                    let (i, o) = (algorithms::in_degree_centrality(&g), algorithms::out_degree_centrality(&g));
+                    assert_eq!((i[&0], o[&0]), (0., 1.));
+                    assert_eq!((i[&1], o[&1]), (0.25, 0.));
+                }
+
+                #[test]
+                fn closeness_centrality() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    assert!(algorithms::closeness_centrality(&g).is_empty());
+                    assert!(algorithms::harmonic_centrality(&g).is_empty());
+
+                    // Build a directed path, where distances are computed towards each vertex.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+                    let c = algorithms::closeness_centrality(&g);
+                    assert_eq!(c[&0], 0.);
+                    assert_eq!(c[&1], (1. / 1.) * (1. / 2.));
+                    assert_eq!(c[&2], (2. / 3.) * (2. / 2.));
+                    let c = algorithms::harmonic_centrality(&g);
+                    assert_eq!(c[&0], 0.);
+                    assert_eq!(c[&1], 1.);
+                    assert_eq!(c[&2], 1. + 1. / 2.);
+
+                    // Build a directed cycle, where every vertex is equally central.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let c = algorithms::closeness_centrality(&g);
+                    assert!(V!(g).all(|x| c[x] == 3. / (1. + 2. + 3.)));
+                }
+
+                #[test]
+                fn betweenness_centrality() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    assert!(algorithms::betweenness_centrality(&g).is_empty());
+                    assert!(algorithms::edge_betweenness_centrality(&g).is_empty());
+
+                    // Build a directed path.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+                    let c = algorithms::betweenness_centrality(&g);
+                    assert_eq!(c, HashMap::from([(&0, 0.), (&1, 0.5), (&2, 0.)]));
+                    let c = algorithms::edge_betweenness_centrality(&g);
+                    assert_eq!(c, HashMap::from([((&0, &1), 2. / 6.), ((&1, &2), 2. / 6.)]));
+
+                    // Build a directed graph with two shortest paths from `0` to `3`.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 3), (2, 3)]);
+                    let c = algorithms::betweenness_centrality(&g);
+                    assert_eq!(c[&1], 0.5 / 6.);
+                    assert_eq!(c[&0], 0.);
+                    let c = algorithms::edge_betweenness_centrality(&g);
+                    assert_eq!(c[&(&0, &1)], 1.5 / 12.);
+                    assert_eq!(c[&(&1, &3)], 1.5 / 12.);
+
+                    // Make the path through `1` shorter.
+                    let w = |x: &i32, y: &i32| if *x == 2 || *y == 2 { 2. } else { 1. };
+                    let c = algorithms::weighted_betweenness_centrality(&g, w);
+                    assert_eq!(c[&1], 1. / 6.);
+                    assert_eq!(c[&2], 0.);
+                    let c = algorithms::weighted_edge_betweenness_centrality(&g, w);
+                    assert_eq!(c[&(&0, &1)], 2. / 12.);
+                    assert_eq!(c[&(&2, &3)], 1. / 12.);
+
+                    // Unit weights are equivalent to the unweighted variant.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (1, 4)]);
+                    assert_eq!(algorithms::betweenness_centrality(&g), algorithms::weighted_betweenness_centrality(&g, |_, _| 1.));
+                    assert_eq!(algorithms::edge_betweenness_centrality(&g), algorithms::weighted_edge_betweenness_centrality(&g, |_, _| 1.));
+                }
+
+                #[test]
+                fn eigenvector_centrality() -> Result<(), Error<i32>> {
+                    // Build a directed cycle, where every vertex is equally central.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    let c = algorithms::eigenvector_centrality(&g, 1e-9, 100)?;
+                    for x in V!(g) {
+                        assert_relative_eq!(c[x], 1. / f64::sqrt(3.), epsilon = 1e-6);
+                    }
+
+                    // A single iteration is not enough to converge.
+                    assert!(matches!(algorithms::eigenvector_centrality(&g, 1e-9, 1), Err(Error::NotConverged(1))));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn hits() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let (h, a) = algorithms::hits(&g, 1e-9, 100)?;
+                    assert!(h.is_empty() && a.is_empty());
+
+                    // Build a graph without edges, hence neither hubs nor authorities.
+                    let g = $T::<$U>::new([0, 1, 2], []);
+                    let (h, a) = algorithms::hits(&g, 1e-9, 100)?;
+                    assert!(h.values().chain(a.values()).all(|&x| x == 0.));
+
+                    // Build a citation graph, where `0` cites more than `1`, and `3` is cited more than `2`.
+                    let g = $T::<$U>::from_edges([(0, 2), (0, 3), (1, 3)]);
+                    let (h, a) = algorithms::hits(&g, 1e-9, 100)?;
+                    assert!(h[&0] > h[&1] && h[&1] > 0.);
+                    assert!(a[&3] > a[&2] && a[&2] > 0.);
+                    assert_relative_eq!(h.values().sum::<f64>(), 1., epsilon = 1e-6);
+                    assert_relative_eq!(a.values().sum::<f64>(), 1., epsilon = 1e-6);
+                    // The scores are the principal eigenvectors of [[2, 1], [1, 1]] and [[1, 1], [1, 2]].
+                    let phi = (1. + f64::sqrt(5.)) / 2.;
+                    assert_relative_eq!(h[&0], phi / (1. + phi), epsilon = 1e-6);
+                    assert_relative_eq!(a[&3], phi / (1. + phi), epsilon = 1e-6);
+
+                    // A single iteration is not enough to converge.
+                    assert!(matches!(algorithms::hits(&g, 1e-9, 1), Err(Error::NotConverged(1))));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn katz_centrality() -> Result<(), Error<i32>> {
+                    // Build a directed cycle, where every vertex is equally central.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    let c = algorithms::katz_centrality(&g, 0.5, 1., 1e-9, 100)?;
+                    for x in V!(g) {
+                        assert_relative_eq!(c[x], 1. / f64::sqrt(3.), epsilon = 1e-6);
+                    }
+
+                    // The attenuation factor is too large.
+                    assert!(matches!(algorithms::katz_centrality(&g, 1., 1., 1e-9, 100), Err(Error::NotConverged(100))));
+
+                    // Build an in-star graph.
+                    let g = $T::<$U>::from_edges([(1, 0), (2, 0), (3, 0)]);
+                    let c = algorithms::katz_centrality(&g, 0.1, 1., 1e-9, 100)?;
+                    let norm = f64::sqrt(1.3 * 1.3 + 3.);
+                    assert_relative_eq!(c[&0], 1.3 / norm, epsilon = 1e-6);
+                    assert_relative_eq!(c[&1], 1. / norm, epsilon = 1e-6);
+
+                    Ok(())
+                }
+
+                #[test]
+                fn pagerank() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    assert!(algorithms::pagerank(&g, 0.85, 1e-9, 100)?.is_empty());
+
+                    // Build an out-star graph, whose leaves have no out-going edges.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2)]);
+                    let c = algorithms::pagerank(&g, 0.85, 1e-9, 100)?;
+                    // Solve the stationary equations, i.e. a = 0.85 (2 / 3) b + 0.05 and a + 2 b = 1.
+                    let b = 0.95 / (2. + 0.85 * 2. / 3.);
+                    assert_relative_eq!(c[&0], 1. - 2. * b, epsilon = 1e-6);
+                    assert_relative_eq!(c[&1], b, epsilon = 1e-6);
+                    assert_relative_eq!(c[&2], b, epsilon = 1e-6);
+
+                    // Without teleportation, the random walk is trapped in the self-edge.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 2)]);
+                    let c = algorithms::pagerank(&g, 1., 1e-9, 100)?;
+                    assert_relative_eq!(c[&0], 0., epsilon = 1e-6);
+                    assert_relative_eq!(c[&2], 1., epsilon = 1e-6);
+
+                    // Without teleportation, the random walk oscillates in the cycle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 0), (2, 0)]);
+                    assert!(matches!(algorithms::pagerank(&g, 1., 1e-9, 100), Err(Error::NotConverged(100))));
+
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms;
+        use crate::graphs::DirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage};
+        use crate::types::Error;
+        use crate::V;
+        use approx::*;
+        use std::collections::HashMap;
+
+        generic_tests!(DirectedAdjacencyListGraph, i32);
+    }
+}
+
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn degree_centrality() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    assert!(algorithms::degree_centrality(&g).is_empty());
+
+                    // Build a star graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4)]);
+                    let c = algorithms::degree_centrality(&g);
+                    assert_eq!(c, HashMap::from([(&0, 1.), (&1, 0.25), (&2, 0.25), (&3, 0.25), (&4, 0.25)]));
+
+                    // Build a complete graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 2)]);
+                    assert!(algorithms::degree_centrality(&g).values().all(|&c| c == 1.));
+                }
+
+                #[test]
+                fn closeness_centrality() {
+                    // Build a path graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+                    let c = algorithms::closeness_centrality(&g);
+                    assert_eq!(c[&0], 4. / 10.);
+                    assert_eq!(c[&1], 4. / 7.);
+                    assert_eq!(c[&2], 4. / 6.);
+
+                    // Build a disconnected graph, with an isolated vertex.
+                    let mut g = $T::<$U>::from_edges([(0, 1), (2, 3), (3, 4)]);
+                    g.add_vertex(5).unwrap();
+                    let c = algorithms::closeness_centrality(&g);
+                    // Scale each centrality by the fraction of reaching vertices.
+                    assert_eq!(c[&0], (1. / 1.) * (1. / 5.));
+                    assert_eq!(c[&3], (2. / 2.) * (2. / 5.));
+                    assert_eq!(c[&5], 0.);
+                    let c = algorithms::harmonic_centrality(&g);
+                    assert_eq!(c[&0], 1.);
+                    assert_eq!(c[&2], 1. + 1. / 2.);
+                    assert_eq!(c[&5], 0.);
+                }
+
+                #[test]
+                fn betweenness_centrality() {
+                    // Build a path graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+                    let c = algorithms::betweenness_centrality(&g);
+                    assert_eq!(c[&0], 0.);
+                    assert_eq!(c[&1], 6. / 12.);
+                    assert_eq!(c[&2], 8. / 12.);
+
+                    // Build a cycle graph, where opposite vertices have two shortest paths.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let c = algorithms::betweenness_centrality(&g);
+                    assert!(V!(g).all(|x| c[x] == 1. / 6.));
+                    let c = algorithms::edge_betweenness_centrality(&g);
+                    // Each edge is returned in both directions, with the same centrality.
+                    assert_eq!(c.len(), 8);
+                    assert!(c.values().all(|&c| c == 2. / 12.));
+
+                    // Build the Petersen graph, where every vertex and edge is equally central.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ]);
+                    let c = algorithms::betweenness_centrality(&g);
+                    assert!(V!(g).all(|x| abs_diff_eq!(c[x], 1. / 12., epsilon = 1e-9)));
+                    let d = algorithms::weighted_betweenness_centrality(&g, |_, _| 2.);
+                    assert!(V!(g).all(|x| abs_diff_eq!(c[x], d[x], epsilon = 1e-9)));
+
+                    // Build a square with a long edge, which is never traversed.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let w = |x: &i32, y: &i32| if (x - y).abs() == 3 { 4. } else { 1. };
+                    let c = algorithms::weighted_betweenness_centrality(&g, w);
+                    // Hence, the square is equivalent to a path.
+                    assert_eq!(c, HashMap::from([(&0, 0.), (&1, 4. / 6.), (&2, 4. / 6.), (&3, 0.)]));
+                    let c = algorithms::weighted_edge_betweenness_centrality(&g, w);
+                    assert_eq!(c[&(&0, &3)], 0.);
+                    assert_eq!(c[&(&0, &1)], 3. / 12.);
+                    assert_eq!(c[&(&1, &2)], 4. / 12.);
+                }
+
+                #[test]
+                fn eigenvector_centrality() -> Result<(), Error<i32>> {
+                    // Build a path graph, which is bipartite.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+                    let c = algorithms::eigenvector_centrality(&g, 1e-9, 100)?;
+                    assert_relative_eq!(c[&0], 0.5, epsilon = 1e-6);
+                    assert_relative_eq!(c[&1], 1. / f64::sqrt(2.), epsilon = 1e-6);
+
+                    // Build a complete graph, where every vertex is equally central.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+                    let c = algorithms::eigenvector_centrality(&g, 1e-9, 100)?;
+                    assert!(V!(g).all(|x| relative_eq!(c[x], 0.5, epsilon = 1e-6)));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn katz_centrality() -> Result<(), Error<i32>> {
+                    // Build a complete graph, where every vertex is equally central.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 2)]);
+                    let c = algorithms::katz_centrality(&g, 0.1, 1., 1e-9, 100)?;
+                    assert!(V!(g).all(|x| relative_eq!(c[x], 1. / f64::sqrt(3.), epsilon = 1e-6)));
+
+                    // The attenuation factor is larger than the inverse of the largest eigenvalue.
+                    assert!(matches!(algorithms::katz_centrality(&g, 0.5, 1., 1e-9, 100), Err(Error::NotConverged(100))));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn pagerank() -> Result<(), Error<i32>> {
+                    // Build a cycle graph, where every vertex is equally central.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let c = algorithms::pagerank(&g, 0.85, 1e-9, 100)?;
+                    assert!(V!(g).all(|x| relative_eq!(c[x], 0.25, epsilon = 1e-6)));
+
+                    // Build a star graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4)]);
+                    let c = algorithms::pagerank(&g, 0.85, 1e-9, 1000)?;
+                    // Check the stationary equations.
+                    assert_relative_eq!(c[&0], 0.85 * 4. * c[&1] + 0.15 / 5., epsilon = 1e-6);
+                    assert_relative_eq!(c[&1], 0.85 * c[&0] / 4. + 0.15 / 5., epsilon = 1e-6);
+                    assert_relative_eq!(c.values().sum::<f64>(), 1., epsilon = 1e-6);
+
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms;
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage};
+        use crate::types::Error;
+        use crate::V;
+        use approx::*;
+        use std::collections::HashMap;
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
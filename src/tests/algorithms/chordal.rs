@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn minimal_triangulation() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = MinimalTriangulation::from(&g);
+                    search.run();
+                    assert!(search.order.is_empty());
+                    assert!(search.fill_in.is_empty());
+
+                    // Build a chordal graph, with a self-edge and an isolated vertex.
+                    let mut g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 3)]);
+                    g.add_vertex(4)?;
+                    let mut search = MinimalTriangulation::from(&g);
+                    search.run();
+                    // No fill-in edge is required.
+                    assert!(search.fill_in.is_empty());
+                    assert_eq!(search.triangulation(), g);
+                    assert_eq!(search.order.len(), 5);
+
+                    // Build chordless cycles.
+                    for n in 4..8_i32 {
+                        let g = $T::<$U>::from_edges((0..n).map(|i| (i, (i + 1) % n)));
+                        let mut search = MinimalTriangulation::from(&g);
+                        search.run();
+                        // A minimal triangulation of a cycle has exactly n - 3 chords.
+                        assert_eq!(search.fill_in.len() as i32, n - 3);
+                    }
+
+                    // Build a 3x3 grid and two squares sharing an edge.
+                    for g in [
+                        $T::<$U>::from_edges([
+                            (0, 1), (1, 2), (3, 4), (4, 5), (6, 7), (7, 8),
+                            (0, 3), (3, 6), (1, 4), (4, 7), (2, 5), (5, 8)
+                        ]),
+                        $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (2, 4), (4, 5), (5, 3)]),
+                    ] {
+                        let mut search = MinimalTriangulation::from(&g);
+                        search.run();
+                        let h = search.triangulation();
+                        // The triangulated graph is chordal.
+                        assert!(h.is_chordal());
+                        // Each fill-in edge is new.
+                        assert!(search.fill_in.iter().all(|(x, y)| !g.has_edge(x, y).unwrap()));
+                        // The elimination ordering is perfect for the triangulated graph.
+                        for (i, x) in search.order.iter().enumerate() {
+                            let later: Vec<_> = Ne!(h, x).filter(|y| search.order[i + 1..].contains(y)).collect();
+                            for y in later.iter() {
+                                for z in later.iter().filter(|z| *z != y) {
+                                    assert!(h.has_edge(y, z)?);
+                                }
+                            }
+                        }
+                        // The triangulation is minimal, i.e. removing any fill-in edge breaks chordality.
+                        for (x, y) in search.fill_in.iter() {
+                            let mut h = search.triangulation();
+                            h.del_edge(x, y)?;
+                            assert!(!h.is_chordal());
+                        }
+                    }
+
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::MinimalTriangulation;
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage, Undirected};
+        use crate::types::Error;
+        use crate::Ne;
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
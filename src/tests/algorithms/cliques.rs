@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn bron_kerbosch() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = BronKerbosch::from(&g);
+                    search.run();
+                    assert!(search.cliques.is_empty());
+
+                    // Build a graph with a self-edge and an isolated vertex.
+                    let mut g = $T::<$U>::from_edges([(0, 0), (0, 1)]);
+                    g.add_vertex(2)?;
+                    let mut search = BronKerbosch::from(&g);
+                    search.run();
+                    assert_eq!(search.cliques, [BTreeSet::from([&0, &1]), BTreeSet::from([&2])]);
+
+                    // Build a complete graph.
+                    let g = $T::<$U>::from_edges((0..5).flat_map(|x| (x + 1..5).map(move |y| (x, y))));
+                    let mut search = BronKerbosch::from(&g);
+                    search.run();
+                    assert_eq!(search.cliques, [BTreeSet::from_iter(V!(g))]);
+
+                    // Build a complete tripartite graph, i.e. a Moon-Moser graph with 3^3 maximal cliques.
+                    let g = $T::<$U>::from_edges(
+                        (0..9).flat_map(|x| (x + 1..9).filter(move |y| x / 3 != y / 3).map(move |y| (x, y)))
+                    );
+                    let mut search = BronKerbosch::from(&g);
+                    search.run();
+                    assert_eq!(search.cliques.len(), 27);
+                    // Each clique takes one vertex from each part.
+                    for c in search.cliques.iter() {
+                        assert_eq!(c.iter().map(|&&x| x / 3).collect::<BTreeSet<_>>(), BTreeSet::from([0, 1, 2]));
+                    }
+
+                    // Build a chordless cycle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let mut search = BronKerbosch::from(&g);
+                    search.run();
+                    assert_eq!(search.cliques.len(), 4);
+
+                    Ok(())
+                }
+
+                #[test]
+                fn chordal_cliques() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = ChordalCliques::from(&g);
+                    assert!(search.run()?.cliques.is_empty());
+
+                    // Build chordal graphs, both connected and disconnected.
+                    for g in [
+                        $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (0, 2), (2, 4), (4, 0), (4, 5)]),
+                        $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (2, 3), (4, 5), (5, 6), (6, 6)]),
+                        $T::<$U>::from_edges((0..6).flat_map(|x| (x + 1..6).map(move |y| (x, y)))),
+                    ] {
+                        let mut search = ChordalCliques::from(&g);
+                        search.run()?;
+                        // The maximal cliques agree with the general enumeration.
+                        assert_eq!(search.cliques, BronKerbosch::from(&g).run().cliques);
+                    }
+
+                    // Build a chordless cycle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let mut search = ChordalCliques::from(&g);
+                    assert_eq!(search.run().err(), Some(Error::NotChordal()));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn clique_tree() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = CliqueTree::from(&g);
+                    assert_eq!(search.run()?.tree.order(), 0);
+
+                    // Build a 3x3 grid and triangulate it.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (3, 4), (4, 5), (6, 7), (7, 8),
+                        (0, 3), (3, 6), (1, 4), (4, 7), (2, 5), (5, 8)
+                    ]);
+                    let mut triangulation = MinimalTriangulation::from(&g);
+                    let h = triangulation.run().triangulation();
+                    // Add a disconnected component.
+                    let mut h = $T::<$U>::new(V!(h).copied().chain([9, 10, 11]), E!(h).map(|(&x, &y)| (x, y)));
+                    h.add_edge(&9, &10)?;
+
+                    let mut search = CliqueTree::from(&h);
+                    search.run()?;
+                    let tree = &search.tree;
+                    // Vertices are the maximal cliques.
+                    let cliques: Vec<_> = BronKerbosch::from(&h)
+                        .run()
+                        .cliques
+                        .iter()
+                        .map(|c| c.iter().map(|&&x| x).collect::<BTreeSet<_>>())
+                        .collect();
+                    assert_eq!(V!(tree).cloned().collect::<Vec<_>>(), cliques);
+                    // The clique tree is a forest, with one tree for each connected component.
+                    assert_eq!(tree.size(), tree.order() - 3);
+                    // Separator sets are the intersection of the endpoints.
+                    for (x, y) in E!(tree) {
+                        let s: BTreeSet<_> = x.intersection(y).copied().collect();
+                        assert!(!s.is_empty());
+                        assert_eq!(tree.get_edge_attrs(x, y).unwrap(), &s);
+                    }
+                    // The cliques containing any given vertex induce a connected subtree.
+                    for x in V!(h) {
+                        let c: Vec<_> = V!(tree).filter(|c| c.contains(x)).collect();
+                        let e = E!(tree).filter(|(a, b)| a < b && a.contains(x) && b.contains(x)).count();
+                        assert_eq!(e + 1, c.len());
+                    }
+
+                    // Build a chordless cycle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let mut search = CliqueTree::from(&g);
+                    assert_eq!(search.run().err(), Some(Error::NotChordal()));
+
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{BronKerbosch, ChordalCliques, CliqueTree, MinimalTriangulation};
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage, WithAttributes};
+        use crate::types::Error;
+        use crate::{E, V};
+        use std::collections::BTreeSet;
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod directed {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn directed_triangles() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    assert!(algorithms::directed_triangles(&g, TrianglePattern::Total).is_empty());
+
+                    // Build a directed cycle, ignoring self-edges.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0), (0, 0)]);
+                    for (p, t) in [
+                        (TrianglePattern::Total, 1),
+                        (TrianglePattern::Cycle, 1),
+                        (TrianglePattern::Middleman, 0),
+                        (TrianglePattern::In, 0),
+                        (TrianglePattern::Out, 0),
+                    ] {
+                        assert!(algorithms::directed_triangles(&g, p).values().all(|&x| x == t));
+                    }
+
+                    // Build a transitive triangle, where each vertex plays a different role.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 2)]);
+                    let t = algorithms::directed_triangles(&g, TrianglePattern::Out);
+                    assert_eq!(t, HashMap::from([(&0, 1), (&1, 0), (&2, 0)]));
+                    let t = algorithms::directed_triangles(&g, TrianglePattern::Middleman);
+                    assert_eq!(t, HashMap::from([(&0, 0), (&1, 1), (&2, 0)]));
+                    let t = algorithms::directed_triangles(&g, TrianglePattern::In);
+                    assert_eq!(t, HashMap::from([(&0, 0), (&1, 0), (&2, 1)]));
+
+                    // Build a graph with reciprocal edges.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 0), (0, 2), (2, 1), (1, 3), (3, 2), (2, 3), (3, 0), (4, 0), (4, 2)
+                    ]);
+                    // The total count is half the diagonal of the cubed symmetrized adjacency matrix.
+                    let a = g.dense_adjacency_matrix().mapv(|x| x as usize);
+                    let s = &a + &a.t();
+                    let s = s.dot(&s).dot(&s);
+                    let t = algorithms::directed_triangles(&g, TrianglePattern::Total);
+                    for (i, x) in V!(g).enumerate() {
+                        assert_eq!(t[x], s[(i, i)] / 2);
+                    }
+                    // The total count is the sum of the other patterns.
+                    let u: Vec<_> = [
+                        TrianglePattern::Cycle,
+                        TrianglePattern::Middleman,
+                        TrianglePattern::In,
+                        TrianglePattern::Out,
+                    ]
+                    .into_iter()
+                    .map(|p| algorithms::directed_triangles(&g, p))
+                    .collect();
+                    assert!(V!(g).all(|x| t[x] == u.iter().map(|u| u[x]).sum()));
+                }
+
+                #[test]
+                fn directed_clustering() {
+                    // Build a complete graph with reciprocal edges.
+                    let g = $T::<$U>::from_edges((0..4).flat_map(|x| (0..4).filter(move |&y| x != y).map(move |y| (x, y))));
+                    for p in [
+                        TrianglePattern::Total,
+                        TrianglePattern::Cycle,
+                        TrianglePattern::Middleman,
+                        TrianglePattern::In,
+                        TrianglePattern::Out,
+                    ] {
+                        // Every possible triangle is present.
+                        assert!(algorithms::directed_clustering(&g, p).values().all(|&c| c == 1.));
+                    }
+
+                    // Build a transitive triangle.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 2)]);
+                    let c = algorithms::directed_clustering(&g, TrianglePattern::Total);
+                    assert!(c.values().all(|&c| c == 1. / 2.));
+                    let c = algorithms::directed_clustering(&g, TrianglePattern::Middleman);
+                    assert_eq!(c, HashMap::from([(&0, 0.), (&1, 1.), (&2, 0.)]));
+                    // The sink has one in-triangle out of the two with reciprocal edges.
+                    let c = algorithms::directed_clustering(&g, TrianglePattern::In);
+                    assert_eq!(c, HashMap::from([(&0, 0.), (&1, 0.), (&2, 1. / 2.)]));
+
+                    // Build a directed path, without triangles.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+                    let c = algorithms::directed_clustering(&g, TrianglePattern::Total);
+                    assert!(c.values().all(|&c| c == 0.));
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{self, TrianglePattern};
+        use crate::graphs::DirectedAdjacencyListGraph;
+        use crate::traits::{Convert, From, Storage};
+        use crate::V;
+        use std::collections::HashMap;
+
+        generic_tests!(DirectedAdjacencyListGraph, i32);
+    }
+}
+
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn triangles() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    assert!(algorithms::triangles(&g).is_empty());
+                    assert_eq!(algorithms::total_triangles(&g), 0);
+
+                    // Build a complete graph, ignoring self-edges.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (0, 0)]);
+                    assert!(algorithms::triangles(&g).values().all(|&t| t == 3));
+                    assert_eq!(algorithms::total_triangles(&g), 4);
+
+                    // Build the Petersen graph, which is triangle-free.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ]);
+                    assert_eq!(algorithms::total_triangles(&g), 0);
+
+                    // Build a wheel graph, whose hub belongs to every triangle.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (2, 3), (3, 4), (4, 1)]);
+                    let t = algorithms::triangles(&g);
+                    assert_eq!(t, HashMap::from([(&0, 4), (&1, 2), (&2, 2), (&3, 2), (&4, 2)]));
+                    assert_eq!(algorithms::total_triangles(&g), 4);
+                }
+
+                #[test]
+                fn clustering() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    assert!(algorithms::clustering(&g).is_empty());
+                    assert_eq!(algorithms::average_clustering(&g), 0.);
+                    assert_eq!(algorithms::transitivity(&g), 0.);
+
+                    // Build a star graph, whose leaves have a single neighbor.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3)]);
+                    assert!(algorithms::clustering(&g).values().all(|&c| c == 0.));
+                    assert_eq!(algorithms::transitivity(&g), 0.);
+
+                    // Build a wheel graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (2, 3), (3, 4), (4, 1)]);
+                    let c = algorithms::clustering(&g);
+                    assert_eq!(c[&0], 4. / 6.);
+                    assert_eq!(c[&1], 2. / 3.);
+                    assert_eq!(algorithms::average_clustering(&g), (4. / 6. + 4. * 2. / 3.) / 5.);
+                    // There are four triangles and 6 + 4 * 3 paths of length two.
+                    assert_eq!(algorithms::transitivity(&g), 12. / 18.);
+
+                    // Build a complete graph with a pendant vertex, ignoring self-edges.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 2), (2, 3), (3, 3)]);
+                    let c = algorithms::clustering(&g);
+                    assert_eq!(c, HashMap::from([(&0, 1.), (&1, 1.), (&2, 1. / 3.), (&3, 0.)]));
+                    assert_eq!(algorithms::transitivity(&g), 3. / 5.);
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms;
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage};
+        use std::collections::HashMap;
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
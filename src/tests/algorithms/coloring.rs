@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                // Build the Petersen graph.
+                fn petersen() -> $T<$U> {
+                    $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ])
+                }
+
+                // Build the Grötzsch graph, i.e. the triangle-free Mycielskian of a 5-cycle.
+                fn grotzsch() -> $T<$U> {
+                    $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (5, 1), (5, 4), (6, 0), (6, 2), (7, 1), (7, 3), (8, 2), (8, 4), (9, 3), (9, 0),
+                        (10, 5), (10, 6), (10, 7), (10, 8), (10, 9)
+                    ])
+                }
+
+                // Check whether a coloring is proper, ignoring self-edges.
+                fn is_proper(g: &$T<$U>, coloring: &HashMap<&$U, usize>, colors: usize) -> bool {
+                    coloring.len() == g.order()
+                        && coloring.values().all(|&c| c < colors)
+                        && E!(g).all(|(x, y)| x == y || coloring[x] != coloring[y])
+                }
+
+                #[test]
+                fn greedy_coloring() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = GreedyColoring::from(&g);
+                    search.run();
+                    assert!(search.coloring.is_empty());
+                    assert_eq!(search.colors, 0);
+
+                    // Build a graph with a self-edge and an isolated vertex.
+                    let mut g = $T::<$U>::from_edges([(0, 0), (0, 1)]);
+                    g.add_vertex(2)?;
+                    for order in [ColoringOrder::LargestFirst, ColoringOrder::SmallestLast, ColoringOrder::DSatur] {
+                        let mut search = GreedyColoring::from((&g, order));
+                        search.run();
+                        assert_eq!(search.colors, 2);
+                        assert!(is_proper(&g, &search.coloring, search.colors));
+                    }
+
+                    // Build a crown graph, where the vertex order is adversarial.
+                    let g = $T::<$U>::from_edges([(0, 3), (0, 5), (1, 2), (1, 4), (2, 5), (3, 4)]);
+                    for order in [ColoringOrder::LargestFirst, ColoringOrder::SmallestLast, ColoringOrder::DSatur] {
+                        let mut search = GreedyColoring::from((&g, order));
+                        search.run();
+                        assert!(is_proper(&g, &search.coloring, search.colors));
+                    }
+                    // DSATUR colors bipartite graphs optimally.
+                    let mut search = GreedyColoring::from((&g, ColoringOrder::DSatur));
+                    assert_eq!(search.run().colors, 2);
+
+                    // Build a tree, where smallest-last colors optimally.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (5, 6)]);
+                    let mut search = GreedyColoring::from((&g, ColoringOrder::SmallestLast));
+                    assert_eq!(search.run().colors, 2);
+
+                    // Build larger graphs.
+                    for g in [petersen(), grotzsch()] {
+                        for order in [ColoringOrder::LargestFirst, ColoringOrder::SmallestLast, ColoringOrder::DSatur] {
+                            let mut search = GreedyColoring::from((&g, order));
+                            search.run();
+                            assert!(is_proper(&g, &search.coloring, search.colors));
+                            // The number of colors is at most the maximum degree plus one.
+                            assert!(search.colors <= 4);
+                        }
+                    }
+
+                    Ok(())
+                }
+
+                #[test]
+                fn exact_coloring() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = ExactColoring::from(&g);
+                    assert_eq!(search.run().chromatic_number, 0);
+
+                    // Build graphs with known chromatic number.
+                    for (g, k) in [
+                        ($T::<$U>::empty([0, 1, 2]), 1),
+                        ($T::<$U>::from_edges([(0, 0), (0, 1), (1, 2), (2, 3), (3, 0)]), 2),
+                        ($T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]), 3),
+                        ($T::<$U>::from_edges((0..5).flat_map(|x| (x + 1..5).map(move |y| (x, y)))), 5),
+                        (petersen(), 3),
+                        // Triangle-free, hence the clique lower bound is not tight.
+                        (grotzsch(), 4),
+                    ] {
+                        let mut search = ExactColoring::from(&g);
+                        search.run();
+                        assert_eq!(search.chromatic_number, k);
+                        assert!(is_proper(&g, &search.coloring, search.chromatic_number));
+                    }
+                }
+
+                #[test]
+                fn chordal_coloring() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = ChordalColoring::from(&g);
+                    assert_eq!(search.run()?.colors, 0);
+
+                    // Build chordal graphs.
+                    for g in [
+                        $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (0, 2), (2, 4), (4, 0), (4, 5)]),
+                        $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (2, 3), (4, 5), (5, 6), (6, 6)]),
+                        $T::<$U>::from_edges((0..6).flat_map(|x| (x + 1..6).map(move |y| (x, y)))),
+                    ] {
+                        let mut search = ChordalColoring::from(&g);
+                        search.run()?;
+                        assert!(is_proper(&g, &search.coloring, search.colors));
+                        // The coloring is optimal, i.e. it uses as many colors as the largest clique.
+                        let omega = BronKerbosch::from(&g).run().cliques.iter().map(|c| c.len()).max().unwrap();
+                        assert_eq!(search.colors, omega);
+                        assert_eq!(search.colors, ExactColoring::from(&g).run().chromatic_number);
+                    }
+
+                    // Build a chordless cycle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let mut search = ChordalColoring::from(&g);
+                    assert_eq!(search.run().err(), Some(Error::NotChordal()));
+
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{BronKerbosch, ChordalColoring, ColoringOrder, ExactColoring, GreedyColoring};
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage};
+        use crate::types::Error;
+        use crate::E;
+        use std::collections::HashMap;
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
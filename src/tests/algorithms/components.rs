@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod directed {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn strongly_connected_components() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = SCC::from(&g);
+                    search.run();
+                    assert!(search.components.is_empty());
+                    assert_eq!(search.condensation(), DiGraph::null());
+
+                    // Build a DAG.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 3), (2, 3)]);
+                    let mut search = SCC::from(&g);
+                    search.run();
+                    // Each vertex is a component by itself.
+                    assert_eq!(search.components.len(), g.order());
+                    assert!(search.components.iter().all(|c| c.len() == 1));
+                    // The condensation of a DAG is isomorphic to the DAG itself.
+                    assert_eq!(search.condensation().size(), g.size());
+
+                    // Build a graph from Figure 22.9 of Introduction to Algorithms (CLRS).
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (1, 4), (1, 5), (2, 3), (2, 6), (3, 2), (3, 7),
+                        (4, 0), (4, 5), (5, 6), (6, 5), (6, 7), (7, 7)
+                    ]);
+                    let mut search = SCC::from(&g);
+                    search.run();
+                    assert_eq!(
+                        search.components,
+                        [
+                            BTreeSet::from([&0, &1, &4]),
+                            BTreeSet::from([&2, &3]),
+                            BTreeSet::from([&5, &6]),
+                            BTreeSet::from([&7]),
+                        ]
+                    );
+                    for (i, c) in search.components.iter().enumerate() {
+                        assert!(c.iter().all(|x| search.component[x] == i));
+                    }
+                    // The condensation is a DAG over the components.
+                    let h = search.condensation();
+                    assert_eq!(h, DiGraph::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)]));
+                    assert!(h.is_acyclic());
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::SCC;
+        use crate::graphs::{DiGraph, DirectedAdjacencyListGraph};
+        use crate::traits::{Connectivity, From, Storage};
+        use std::collections::BTreeSet;
+
+        generic_tests!(DirectedAdjacencyListGraph, i32);
+    }
+}
+
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn biconnected_components() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = BiconnectedComponents::from(&g);
+                    search.run();
+                    assert!(search.articulation_points.is_empty());
+                    assert!(search.bridges.is_empty());
+                    assert!(search.components.is_empty());
+                    assert_eq!(search.block_cut_tree(), Graph::null());
+
+                    // Build a cycle graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let mut search = BiconnectedComponents::from(&g);
+                    search.run();
+                    assert!(search.articulation_points.is_empty());
+                    assert!(search.bridges.is_empty());
+                    assert_eq!(search.components, [BTreeSet::from([&0, &1, &2, &3])]);
+
+                    // Build a star graph.
+                    let g = $T::<$U>::from_edges([(1, 0), (1, 2), (1, 3)]);
+                    let mut search = BiconnectedComponents::from(&g);
+                    search.run();
+                    assert_eq!(search.articulation_points, BTreeSet::from([&1]));
+                    assert_eq!(search.bridges, BTreeSet::from([(&0, &1), (&1, &2), (&1, &3)]));
+                    assert_eq!(search.components.len(), 3);
+                    assert_eq!(search.block_cut_tree(), Graph::from_edges([(0, 3), (1, 3), (2, 3)]));
+
+                    // Build a disconnected graph with a self-edge and an isolated vertex.
+                    let mut g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (4, 0), (4, 4),
+                        (5, 6), (6, 7), (7, 8), (8, 6)
+                    ]);
+                    g.add_vertex(9).unwrap();
+                    let mut search = BiconnectedComponents::from(&g);
+                    search.run();
+                    assert_eq!(search.articulation_points, BTreeSet::from([&0, &6]));
+                    assert_eq!(search.bridges, BTreeSet::from([(&5, &6)]));
+                    assert_eq!(
+                        search.components,
+                        [
+                            BTreeSet::from([&0, &1, &2]),
+                            BTreeSet::from([&0, &3, &4]),
+                            BTreeSet::from([&5, &6]),
+                            BTreeSet::from([&6, &7, &8]),
+                        ]
+                    );
+                    // The block-cut tree is a forest.
+                    let h = search.block_cut_tree();
+                    assert_eq!(h, Graph::from_edges([(0, 4), (1, 4), (2, 5), (3, 5)]));
+                    assert!(h.is_acyclic());
+                }
+
+                #[test]
+                fn connected_components() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = ConnectedComponents::from(&g);
+                    search.run();
+                    assert!(search.components.is_empty());
+                    assert!(search.component.is_empty());
+
+                    // Build a graph with three components.
+                    let g = $T::<$U>::from_edges([
+                        (0, 4), (4, 8), (1, 5), (5, 1), (2, 2), (3, 7), (6, 7), (7, 3)
+                    ]);
+                    let mut search = ConnectedComponents::from(&g);
+                    search.run();
+                    assert_eq!(
+                        search.components,
+                        [
+                            BTreeSet::from([&0, &4, &8]),
+                            BTreeSet::from([&1, &5]),
+                            BTreeSet::from([&2]),
+                            BTreeSet::from([&3, &6, &7]),
+                        ]
+                    );
+                    for (i, c) in search.components.iter().enumerate() {
+                        assert!(c.iter().all(|x| search.component[x] == i));
+                    }
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{BiconnectedComponents, ConnectedComponents};
+        use crate::graphs::{Graph, UndirectedAdjacencyListGraph};
+        use crate::traits::{Connectivity, From, Storage};
+        use std::collections::BTreeSet;
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn cycle_basis() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = CycleBasis::from(&g);
+                    search.run();
+                    assert!(search.cycle_basis.is_empty());
+
+                    // Build a forest.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (3, 4)]);
+                    let mut search = CycleBasis::from(&g);
+                    search.run();
+                    assert!(search.cycle_basis.is_empty());
+
+                    // Build a graph with two components and a self-edge.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (0, 2), (1, 2), (1, 3), (2, 3),
+                        (4, 5), (4, 6), (5, 6), (6, 6)
+                    ]);
+                    let mut search = CycleBasis::from(&g);
+                    search.run();
+                    // The number of fundamental cycles is equal to the cyclomatic number.
+                    assert_eq!(search.cycle_basis.len(), g.size() - g.order() + 2);
+                    assert_eq!(
+                        search.cycle_basis,
+                        [
+                            vec![&1, &0, &2, &1],
+                            vec![&2, &0, &1, &3, &2],
+                            vec![&5, &4, &6, &5],
+                            vec![&6, &6],
+                        ]
+                    );
+                    // Each cycle is closed and made of edges of the graph.
+                    for c in search.cycle_basis.iter() {
+                        assert_eq!(c.first(), c.last());
+                        assert!(c.windows(2).all(|e| g.has_edge(e[0], e[1]).unwrap()));
+                    }
+                }
+
+                #[test]
+                fn minimum_cycle_basis() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = MinimumCycleBasis::from(&g);
+                    search.run();
+                    assert!(search.cycle_basis.is_empty());
+                    assert_eq!(search.total_weight, 0.);
+
+                    // Build a 3x3 grid graph.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (3, 4), (4, 5), (6, 7), (7, 8),
+                        (0, 3), (3, 6), (1, 4), (4, 7), (2, 5), (5, 8)
+                    ]);
+                    let mut search = MinimumCycleBasis::from(&g);
+                    search.run();
+                    // The minimum cycle basis is made of the four squares.
+                    assert_eq!(
+                        search.cycle_basis,
+                        [
+                            vec![&0, &1, &4, &3, &0],
+                            vec![&1, &2, &5, &4, &1],
+                            vec![&3, &4, &7, &6, &3],
+                            vec![&4, &5, &8, &7, &4],
+                        ]
+                    );
+                    assert_eq!(search.total_weight, 16.);
+
+                    // Build a complete graph with a self-edge.
+                    let g = $T::<$U>::from_edges([
+                        (0, 0), (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+                    ]);
+                    let mut search = MinimumCycleBasis::from(&g);
+                    search.run();
+                    // The self-edge is in the basis, together with three triangles.
+                    assert_eq!(search.cycle_basis.len(), 4);
+                    assert_eq!(search.cycle_basis[0], [&0, &0]);
+                    assert!(search.cycle_basis[1..].iter().all(|c| c.len() == 4));
+                    assert_eq!(search.total_weight, 10.);
+                }
+
+                #[test]
+                fn minimum_cycle_basis_weighted() {
+                    // Build a wheel graph with a heavy hub.
+                    let mut g = $T::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (0, 3), (0, 4),
+                        (1, 2), (2, 3), (3, 4), (1, 4)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 5.), (0, 2, 5.), (0, 3, 5.), (0, 4, 5.),
+                        (1, 2, 1.), (2, 3, 1.), (3, 4, 1.), (1, 4, 1.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                        g.set_edge_attrs(&y, &x, w);
+                    }
+                    let mut search = MinimumCycleBasis::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    search.run();
+                    // The rim is in the basis, together with three spoke triangles.
+                    assert_eq!(search.cycle_basis.len(), 4);
+                    assert!(search.cycle_basis.contains(&vec![&1, &2, &3, &4, &1]));
+                    assert_eq!(search.total_weight, 4. + 3. * 11.);
+                }
+
+                #[test]
+                fn minimum_cycle_basis_zero_weights() {
+                    // Build a graph where zero-weight edges make the shortest odd closed walks tie
+                    // with walks passing twice through the same vertex.
+                    let edges = [
+                        ((0, 1), 0.), ((0, 2), 0.), ((0, 4), 2.), ((1, 3), 0.), ((1, 4), 0.),
+                        ((1, 5), 1.), ((2, 3), 2.), ((2, 4), 0.), ((2, 5), 2.), ((3, 5), 0.)
+                    ];
+                    let g = $T::<$U>::from_edges(edges.iter().map(|&(e, _)| e));
+                    let w: HashMap<_, _> = edges.into_iter().collect();
+                    let mut search = MinimumCycleBasis::from((&g, |x: &i32, y: &i32| w[&(*x.min(y), *x.max(y))]));
+                    search.run();
+                    // The basis has one cycle for each non-tree edge.
+                    assert_eq!(search.cycle_basis.len(), g.size() - g.order() + 1);
+                    // Each cycle is simple, and its weight sums up to the total weight.
+                    let mut total_weight = 0.;
+                    for c in search.cycle_basis.iter() {
+                        assert_eq!(c.first(), c.last());
+                        assert_eq!(c[1..].iter().collect::<HashSet<_>>().len(), c.len() - 1);
+                        total_weight += c.windows(2).map(|e| w[&(*e[0].min(e[1]), *e[0].max(e[1]))]).sum::<f64>();
+                    }
+                    assert_eq!(search.total_weight, total_weight);
+                    assert_eq!(search.total_weight, 7.);
+                }
+
+                #[test]
+                #[should_panic]
+                fn minimum_cycle_basis_should_panic() {
+                    // Build a triangle with a negative weight.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (0, 2)]);
+                    MinimumCycleBasis::from((&g, |x: &i32, y: &i32| if (*x, *y) == (0, 1) { -1. } else { 1. })).run();
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{CycleBasis, MinimumCycleBasis};
+        use crate::graphs::attributes::AttributesMap;
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage, WithAttributes};
+        use std::collections::{HashMap, HashSet};
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
@@ -0,0 +1,235 @@
+#[cfg(test)]
+mod directed {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                // Build the flow network from Figure 26.1 of Introduction to Algorithms (CLRS).
+                fn network() -> $T<i32, AttributesMap<i32, (), (), f64>> {
+                    let mut g = $T::<i32, AttributesMap<i32, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (1, 3), (2, 1), (2, 4), (3, 2), (3, 5), (4, 3), (4, 5)
+                    ]);
+                    for (x, y, c) in [
+                        (0, 1, 16.), (0, 2, 13.), (1, 3, 12.), (2, 1, 4.), (2, 4, 14.),
+                        (3, 2, 9.), (3, 5, 20.), (4, 3, 7.), (4, 5, 4.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, c);
+                    }
+
+                    g
+                }
+
+                // Check capacity constraints and flow conservation.
+                fn is_feasible(
+                    g: &$T<i32, AttributesMap<i32, (), (), f64>>,
+                    f: &HashMap<(&i32, &i32), f64>,
+                    s: &i32,
+                    t: &i32,
+                    v: f64,
+                ) -> bool {
+                    // Check capacity constraints.
+                    let capacity = f.iter().all(|(&(x, y), &f)| 0. <= f && f <= *g.get_edge_attrs(x, y).unwrap());
+                    // Compute the excess of each vertex.
+                    let mut excess: HashMap<&i32, f64> = Default::default();
+                    for (&(x, y), &f) in f.iter() {
+                        *excess.entry(x).or_default() -= f;
+                        *excess.entry(y).or_default() += f;
+                    }
+                    // Check flow conservation.
+                    let conservation = excess
+                        .iter()
+                        .all(|(&x, &e)| match x {
+                            x if x == s => e == -v,
+                            x if x == t => e == v,
+                            _ => e == 0.,
+                        });
+
+                    capacity && conservation
+                }
+
+                #[test]
+                fn dinic() {
+                    let g = network();
+                    let mut search = Dinic::from((&g, &0, &5, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    search.run();
+                    assert_eq!(search.max_flow, 23.);
+                    assert!(is_feasible(&g, &search.flow, &0, &5, 23.));
+                    assert_eq!(search.min_cut, (BTreeSet::from([&0, &1, &2, &4]), BTreeSet::from([&3, &5])));
+
+                    // Test antiparallel edges and an unreachable target vertex.
+                    let mut g = $T::<i32, AttributesMap<i32, (), (), f64>>::from_edges([(0, 1), (1, 0), (2, 1)]);
+                    for (x, y, c) in [(0, 1, 2.), (1, 0, 3.), (2, 1, 1.)] {
+                        g.set_edge_attrs(&x, &y, c);
+                    }
+                    let mut search = Dinic::from((&g, &0, &1, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    search.run();
+                    assert_eq!(search.max_flow, 2.);
+                    assert_eq!(search.flow[&(&1, &0)], 0.);
+                    let mut search = Dinic::from((&g, &0, &2, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    search.run();
+                    assert_eq!(search.max_flow, 0.);
+                    assert_eq!(search.min_cut, (BTreeSet::from([&0, &1]), BTreeSet::from([&2])));
+                }
+
+                #[test]
+                fn dinic_long_path() {
+                    // Build a long directed path with unit capacities.
+                    let n = 100_000;
+                    let g = $T::<i32>::from_edges((0..n - 1).map(|x| (x, x + 1)));
+                    // The augmenting path is as long as the graph, hence the search must not recurse.
+                    let t = n - 1;
+                    let mut search = Dinic::from((&g, &0, &t, |_, _| 1.));
+                    search.run();
+                    assert_eq!(search.max_flow, 1.);
+                    assert!(search.flow.values().all(|&f| f == 1.));
+                }
+
+                #[test]
+                #[should_panic]
+                fn dinic_should_panic() {
+                    let g = network();
+                    Dinic::from((&g, &0, &0, |x, y| *g.get_edge_attrs(x, y).unwrap())).run();
+                }
+
+                #[test]
+                fn edmonds_karp() {
+                    let g = network();
+                    let mut search = EdmondsKarp::from((&g, &0, &5, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    search.run();
+                    assert_eq!(search.max_flow, 23.);
+                    assert!(is_feasible(&g, &search.flow, &0, &5, 23.));
+                    assert_eq!(search.min_cut, (BTreeSet::from([&0, &1, &2, &4]), BTreeSet::from([&3, &5])));
+
+                    // Test antiparallel edges and an unreachable target vertex.
+                    let mut g = $T::<i32, AttributesMap<i32, (), (), f64>>::from_edges([(0, 1), (1, 0), (2, 1)]);
+                    for (x, y, c) in [(0, 1, 2.), (1, 0, 3.), (2, 1, 1.)] {
+                        g.set_edge_attrs(&x, &y, c);
+                    }
+                    let mut search = EdmondsKarp::from((&g, &0, &1, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    search.run();
+                    assert_eq!(search.max_flow, 2.);
+                    assert_eq!(search.flow[&(&1, &0)], 0.);
+                    let mut search = EdmondsKarp::from((&g, &0, &2, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    search.run();
+                    assert_eq!(search.max_flow, 0.);
+                    assert_eq!(search.min_cut, (BTreeSet::from([&0, &1]), BTreeSet::from([&2])));
+                }
+
+                #[test]
+                #[should_panic]
+                fn edmonds_karp_should_panic() {
+                    let mut g = network();
+                    g.set_edge_attrs(&0, &1, -1.);
+                    EdmondsKarp::from((&g, &0, &5, |x, y| *g.get_edge_attrs(x, y).unwrap())).run();
+                }
+
+                #[test]
+                fn minimum_cost_flow() -> Result<(), Error<i32>> {
+                    // Build an assignment problem with negative costs, i.e. profits.
+                    let mut g = $T::<i32, AttributesMap<i32, (), f64, (f64, f64)>>::from_edges([
+                        (0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)
+                    ]);
+                    for (x, s) in [(0, 1.), (1, 1.), (2, 1.), (3, -1.), (4, -1.), (5, -1.)] {
+                        g.set_vertex_attrs(&x, s);
+                    }
+                    for (x, y, w) in [
+                        (0, 3, -9.), (0, 4, -2.), (0, 5, -7.),
+                        (1, 3, -6.), (1, 4, -4.), (1, 5, -3.),
+                        (2, 3, -5.), (2, 4, -8.), (2, 5, -1.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, (1., w));
+                    }
+                    let mut search = MinimumCostFlow::from((
+                        &g,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().0,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().1,
+                        |x| *g.get_vertex_attrs(x).unwrap(),
+                    ));
+                    search.run()?;
+                    // The optimal assignment is `0 -> 5`, `1 -> 3` and `2 -> 4`.
+                    assert_eq!(search.total_cost, -21.);
+                    assert_eq!(
+                        search.flow.iter().filter(|(_, &f)| f > 0.).map(|(&e, _)| e).collect::<BTreeSet<_>>(),
+                        BTreeSet::from([(&0, &5), (&1, &3), (&2, &4)])
+                    );
+
+                    // Build a transshipment problem with a vertex without attributes.
+                    let mut g = $T::<i32, AttributesMap<i32, (), f64, (f64, f64)>>::from_edges([
+                        (0, 1), (0, 2), (1, 2), (1, 3), (2, 3)
+                    ]);
+                    g.set_vertex_attrs(&0, 4.);
+                    g.set_vertex_attrs(&3, -4.);
+                    for (x, y, c, w) in [(0, 1, 4., 2.), (0, 2, 2., 2.), (1, 2, 2., 1.), (1, 3, 3., 3.), (2, 3, 5., 1.)] {
+                        g.set_edge_attrs(&x, &y, (c, w));
+                    }
+                    let mut search = MinimumCostFlow::from((
+                        &g,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().0,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().1,
+                        |x| g.get_vertex_attrs(x).map_or(0., |s| *s),
+                    ));
+                    search.run()?;
+                    assert_eq!(search.total_cost, 14.);
+                    assert_eq!(search.flow[&(&0, &1)], 2.);
+                    assert_eq!(search.flow[&(&0, &2)], 2.);
+                    assert_eq!(search.flow[&(&1, &2)], 2.);
+                    assert_eq!(search.flow[&(&1, &3)], 0.);
+                    assert_eq!(search.flow[&(&2, &3)], 4.);
+
+                    Ok(())
+                }
+
+                #[test]
+                fn minimum_cost_flow_errors() {
+                    // Build a network with insufficient capacity.
+                    let mut g = $T::<i32, AttributesMap<i32, (), f64, (f64, f64)>>::from_edges([(0, 1), (1, 2)]);
+                    g.set_vertex_attrs(&0, 3.);
+                    g.set_vertex_attrs(&1, 0.);
+                    g.set_vertex_attrs(&2, -3.);
+                    g.set_edge_attrs(&0, &1, (2., 1.));
+                    g.set_edge_attrs(&1, &2, (5., 1.));
+                    let mut search = MinimumCostFlow::from((
+                        &g,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().0,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().1,
+                        |x| *g.get_vertex_attrs(x).unwrap(),
+                    ));
+                    assert_eq!(search.run().err(), Some(Error::InfeasibleFlow(1.)));
+
+                    // Build a network with unbalanced supplies.
+                    g.set_vertex_attrs(&0, 1.);
+                    let mut search = MinimumCostFlow::from((
+                        &g,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().0,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().1,
+                        |x| *g.get_vertex_attrs(x).unwrap(),
+                    ));
+                    assert_eq!(search.run().err(), Some(Error::InfeasibleFlow(2.)));
+
+                    // Build a network with a negative cycle.
+                    let mut g = $T::<i32, AttributesMap<i32, (), f64, (f64, f64)>>::from_edges([(0, 1), (1, 0)]);
+                    g.set_edge_attrs(&0, &1, (1., 1.));
+                    g.set_edge_attrs(&1, &0, (1., -2.));
+                    let mut search = MinimumCostFlow::from((
+                        &g,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().0,
+                        |x, y| g.get_edge_attrs(x, y).unwrap().1,
+                        |_| 0.,
+                    ));
+                    assert!(matches!(search.run(), Err(Error::NegativeCycle(_))));
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{Dinic, EdmondsKarp, MinimumCostFlow};
+        use crate::graphs::attributes::AttributesMap;
+        use crate::graphs::DirectedAdjacencyListGraph;
+        use crate::traits::{From, WithAttributes};
+        use crate::types::Error;
+        use std::collections::{BTreeSet, HashMap};
+
+        generic_tests!(DirectedAdjacencyListGraph, i32);
+    }
+}
@@ -0,0 +1,479 @@
+#[cfg(test)]
+mod directed {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn vf2() -> Result<(), Error<i32>> {
+                    // Build two null graphs.
+                    let (g, h) = ($T::<$U>::null(), $T::<$U>::null());
+                    assert_eq!(VF2::from((&g, &h)).collect::<Vec<_>>(), [HashMap::new()]);
+
+                    // Build a directed cycle, which has a rotation symmetry only.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    assert_eq!(VF2::from((&g, &g)).count(), 3);
+
+                    // Build a directed path and its reverse.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+                    let h = $T::<$U>::from_edges([(2, 1), (1, 0)]);
+                    let mut search = VF2::from((&g, &h));
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &2), (&1, &1), (&2, &0)])));
+                    assert_eq!(search.next(), None);
+
+                    // Build an out-star and an in-star, which have the same underlying graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2)]);
+                    let h = $T::<$U>::from_edges([(1, 0), (2, 0)]);
+                    assert!(!VF2::is_isomorphic(&g, &h, |_, _| true, |_, _| true));
+
+                    // Build graphs with the same degree sequence, differing in a self-edge.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 0), (1, 1), (2, 2)]);
+                    let h = $T::<$U>::from_edges([(0, 1), (1, 0), (0, 0), (2, 2)]);
+                    let mut search = VF2::from((&g, &h));
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &1), (&1, &0), (&2, &2)])));
+                    assert_eq!(search.next(), None);
+
+                    // Build graphs with weighted edges.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    let w = |x: &i32, y: &i32| (x + y) % 3;
+                    let mut search = VF2::from((&g, &g, |_: &i32, _: &i32| true, |(a, b), (c, d)| w(a, b) == w(c, d)));
+                    // Only the identity preserves weights.
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &0), (&1, &1), (&2, &2)])));
+                    assert_eq!(search.next(), None);
+
+                    Ok(())
+                }
+
+                #[test]
+                fn vf2_subgraph() {
+                    // Build a directed path pattern.
+                    let p = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+
+                    // Build a directed cycle, which contains a directed path for each rotation.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    assert_eq!(VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true).count(), 3);
+                    // No directed path is induced, since the endpoints are adjacent.
+                    assert_eq!(VF2::new_induced_subgraph(&p, &g, |_, _| true, |_, _| true).count(), 0);
+
+                    // Build a transitive tournament and a longer directed path.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (0, 2), (2, 3)]);
+                    let mut search = VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true);
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &0), (&1, &1), (&2, &2)])));
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &0), (&1, &2), (&2, &3)])));
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &1), (&1, &2), (&2, &3)])));
+                    assert_eq!(search.next(), None);
+                    let mut search = VF2::new_induced_subgraph(&p, &g, |_, _| true, |_, _| true);
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &0), (&1, &2), (&2, &3)])));
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &1), (&1, &2), (&2, &3)])));
+                    assert_eq!(search.next(), None);
+
+                    // Build a larger pattern, which cannot be matched.
+                    let p = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+                    assert!(VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true).next().is_none());
+                }
+
+                #[test]
+                fn canonical_labeling() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = CanonicalLabeling::from(&g);
+                    search.run();
+                    assert!(search.labeling.is_empty());
+                    assert!(search.canonical.is_empty());
+
+                    // Build a directed path and a relabeled copy of it.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+                    let h = $T::<$U>::from_edges([(4, 3), (3, 5)]);
+                    let (mut search_g, mut search_h) = (CanonicalLabeling::from(&g), CanonicalLabeling::from(&h));
+                    search_g.run();
+                    search_h.run();
+                    assert_eq!(search_g.canonical, search_h.canonical);
+                    // The path has no automorphisms, hence the labeling is unique.
+                    assert_eq!(search_g.labeling[&0], search_h.labeling[&4]);
+                    assert_eq!(search_g.labeling[&1], search_h.labeling[&3]);
+                    assert_eq!(search_g.labeling[&2], search_h.labeling[&5]);
+
+                    // Build an out-star and an in-star, which have the same underlying graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2)]);
+                    let h = $T::<$U>::from_edges([(1, 0), (2, 0)]);
+                    let (mut search_g, mut search_h) = (CanonicalLabeling::from(&g), CanonicalLabeling::from(&h));
+                    search_g.run();
+                    search_h.run();
+                    assert_ne!(search_g.canonical, search_h.canonical);
+
+                    // Build graphs with the same degree sequence, differing in a self-edge.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 0), (1, 1), (2, 2)]);
+                    let h = $T::<$U>::from_edges([(0, 1), (1, 0), (0, 0), (2, 2)]);
+                    let (mut search_g, mut search_h) = (CanonicalLabeling::from(&g), CanonicalLabeling::from(&h));
+                    search_g.run();
+                    search_h.run();
+                    assert_eq!(search_g.canonical, search_h.canonical);
+                    assert_eq!(search_g.labeling[&1], search_h.labeling[&0]);
+
+                    // Build a relabeled directed graph, checking the canonical edge list is the relabeled one.
+                    let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 5)];
+                    let g = $T::<$U>::from_edges(edges);
+                    let h = $T::<$U>::from_edges(edges.map(|(x, y)| ((x * 5 + 1) % 6, (y * 5 + 1) % 6)));
+                    let (mut search_g, mut search_h) = (CanonicalLabeling::from(&g), CanonicalLabeling::from(&h));
+                    search_g.run();
+                    search_h.run();
+                    assert_eq!(search_g.canonical, search_h.canonical);
+                    let l = &search_g.labeling;
+                    assert_eq!(search_g.canonical, E!(g).map(|(x, y)| (l[x], l[y])).collect());
+                }
+
+                #[test]
+                fn weisfeiler_lehman() {
+                    // Build a directed cycle and a relabeled copy of it.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    let h = $T::<$U>::from_edges([(1, 0), (0, 2), (2, 1)]);
+                    let (mut hash_g, mut hash_h) = (WeisfeilerLehman::from(&g), WeisfeilerLehman::from(&h));
+                    assert_eq!(hash_g.run().hash, hash_h.run().hash);
+                    // Every vertex of a cycle has the same label.
+                    assert!(hash_g.labels.values().all(|&l| l == hash_g.labels[&0]));
+
+                    // Build an out-star and an in-star, which have the same underlying graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2)]);
+                    let h = $T::<$U>::from_edges([(1, 0), (2, 0)]);
+                    let (mut hash_g, mut hash_h) = (WeisfeilerLehman::from(&g), WeisfeilerLehman::from(&h));
+                    assert_ne!(hash_g.run().hash, hash_h.run().hash);
+
+                    // Build a directed cycle with labelled edges.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    let e = |x: &i32, y: &i32| (x + y) as u64 % 3;
+                    let mut hash_g = WeisfeilerLehman::from((&g, 1, |_: &i32| 0, e));
+                    hash_g.run();
+                    // Edge labels break the rotation symmetry.
+                    assert_ne!(hash_g.labels[&0], hash_g.labels[&1]);
+                    assert_ne!(hash_g.labels[&1], hash_g.labels[&2]);
+                    // No iterations only take vertex labels into account.
+                    let mut hash_g = WeisfeilerLehman::from((&g, 0, |_: &i32| 0, e));
+                    hash_g.run();
+                    assert_eq!(hash_g.labels[&0], hash_g.labels[&1]);
+
+                    // Labels and hash are stable, i.e. they are FNV-1a digests of the little-endian words.
+                    let g = $T::<$U>::from_edges([(0, 0)]);
+                    let mut hash_g = WeisfeilerLehman::from((&g, 0, |_: &i32| 0, e));
+                    hash_g.run();
+                    assert_eq!(hash_g.labels[&0], 0xa8c7_f832_281a_39c5);
+                    assert_eq!(hash_g.hash, 0xbc9f_dc1b_77fd_5e8c);
+                }
+
+                #[test]
+                fn motif_count() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = MotifCount::from((&g, 3));
+                    search.run();
+                    assert_eq!(search.motifs.len(), 13);
+                    assert!(search.motifs.values().all(|&n| n == 0));
+                    let mut search = MotifCount::from((&g, 4));
+                    search.run();
+                    assert_eq!(search.motifs.len(), 199);
+
+                    // Build a directed cycle, ignoring self-edges.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0), (0, 0)]);
+                    let mut search = MotifCount::from((&g, 3));
+                    search.run();
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (1, 2), (2, 0)])], 1);
+                    assert_eq!(search.motifs.values().sum::<usize>(), 1);
+
+                    // Build a complete directed graph.
+                    let g = $T::<$U>::from_edges((0..4).flat_map(|x| (0..4).filter(move |&y| x != y).map(move |y| (x, y))));
+                    let mut search = MotifCount::from((&g, 3));
+                    search.run();
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)])], 4);
+                    assert_eq!(search.motifs.values().sum::<usize>(), 4);
+                    let mut search = MotifCount::from((&g, 4));
+                    search.run();
+                    assert_eq!(search.motifs.values().sum::<usize>(), 1);
+
+                    // Build an out-star, whose leaves are pairwise non-adjacent.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4)]);
+                    let mut search = MotifCount::from((&g, 3));
+                    search.run();
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2)])], 6);
+                    let mut search = MotifCount::from((&g, 4));
+                    search.run();
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2), (0, 3)])], 4);
+                    assert_eq!(search.motifs.values().sum::<usize>(), 4);
+                }
+
+                #[test]
+                #[should_panic]
+                fn motif_count_should_panic() {
+                    // Build a directed cycle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    // Motifs with two vertices are not supported.
+                    let _ = MotifCount::from((&g, 2));
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{CanonicalLabeling, MotifCount, WeisfeilerLehman, VF2};
+        use crate::graphs::DirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage};
+        use crate::types::{EdgeList, Error};
+        use crate::E;
+        use std::collections::HashMap;
+
+        generic_tests!(DirectedAdjacencyListGraph, i32);
+    }
+}
+
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn vf2() -> Result<(), Error<i32>> {
+                    // Build two null graphs.
+                    let (g, h) = ($T::<$U>::null(), $T::<$U>::null());
+                    assert!(VF2::is_isomorphic(&g, &h, |_, _| true, |_, _| true));
+
+                    // Build graphs with different orders.
+                    let g = $T::<$U>::from_edges([(0, 1)]);
+                    let h = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+                    assert!(!VF2::is_isomorphic(&g, &h, |_, _| true, |_, _| true));
+
+                    // Build an undirected cycle, whose automorphisms form a dihedral group.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+                    assert_eq!(VF2::from((&g, &g)).count(), 10);
+
+                    // Build the Petersen graph, which has 120 automorphisms.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ]);
+                    assert_eq!(VF2::from((&g, &g)).count(), 120);
+
+                    // Build a complete bipartite graph and a prism, which are both 3-regular.
+                    let g = $T::<$U>::from_edges([
+                        (0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)
+                    ]);
+                    let h = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3), (1, 4), (2, 5)
+                    ]);
+                    assert!(!VF2::is_isomorphic(&g, &h, |_, _| true, |_, _| true));
+
+                    // Build a relabeled copy of a graph.
+                    let edges = [(0, 1), (0, 2), (1, 2), (2, 3), (3, 4), (4, 5), (5, 3), (5, 6), (7, 7)];
+                    let f = |x: i32| (x * 5 + 3) % 8;
+                    let g = $T::<$U>::from_edges(edges);
+                    let h = $T::<$U>::from_edges(edges.map(|(x, y)| (f(y), f(x))));
+                    for m in VF2::from((&g, &h)) {
+                        // Each mapping is a bijection that preserves adjacency.
+                        assert_eq!(m.len(), g.order());
+                        assert_eq!(m.values().collect::<HashSet<_>>().len(), h.order());
+                        for x in V!(g) {
+                            for y in V!(g) {
+                                assert_eq!(g.has_edge(x, y)?, h.has_edge(m[x], m[y])?);
+                            }
+                        }
+                    }
+                    assert!(VF2::from((&g, &h)).any(|m| V!(g).all(|x| *m[x] == f(*x))));
+
+                    // Build graphs with labelled vertices.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3)]);
+                    let mut search = VF2::from((&g, &g, |x: &i32, y: &i32| (x < &2) == (y < &2), |_, _| true));
+                    // Only the identity preserves labels.
+                    assert_eq!(search.next(), Some(HashMap::from([(&0, &0), (&1, &1), (&2, &2), (&3, &3)])));
+                    assert_eq!(search.next(), None);
+
+                    Ok(())
+                }
+
+                #[test]
+                fn vf2_subgraph() -> Result<(), Error<i32>> {
+                    // Build a complete graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+
+                    // Every injective map of a triangle is both a subgraph and an induced subgraph.
+                    let p = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    assert_eq!(VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true).count(), 24);
+                    assert_eq!(VF2::new_induced_subgraph(&p, &g, |_, _| true, |_, _| true).count(), 24);
+
+                    // Every injective map of a path is a subgraph, but none is induced.
+                    let p = $T::<$U>::from_edges([(0, 1), (1, 2)]);
+                    assert_eq!(VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true).count(), 24);
+                    assert_eq!(VF2::new_induced_subgraph(&p, &g, |_, _| true, |_, _| true).count(), 0);
+
+                    // Build the Petersen graph, which has girth five.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ]);
+                    let p = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    assert!(VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true).next().is_none());
+                    // Each of the twelve pentagons is found ten times.
+                    let p = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+                    for m in VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true) {
+                        // Each mapping is injective and preserves adjacency.
+                        assert_eq!(m.values().collect::<HashSet<_>>().len(), p.order());
+                        for (x, y) in E!(p) {
+                            assert!(g.has_edge(m[x], m[y])?);
+                        }
+                    }
+                    assert_eq!(VF2::new_subgraph(&p, &g, |_, _| true, |_, _| true).count(), 12 * 10);
+
+                    Ok(())
+                }
+
+                #[test]
+                fn canonical_labeling() {
+                    // Build the Petersen graph and a relabeled copy of it.
+                    let edges = [
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ];
+                    let f = |x: i32| (x * 3 + 7) % 10;
+                    let g = $T::<$U>::from_edges(edges);
+                    let h = $T::<$U>::from_edges(edges.map(|(x, y)| (f(x), f(y))));
+                    let (mut search_g, mut search_h) = (CanonicalLabeling::from(&g), CanonicalLabeling::from(&h));
+                    search_g.run();
+                    search_h.run();
+                    assert_eq!(search_g.canonical, search_h.canonical);
+                    // The canonical labeling is a bijection onto the first labels.
+                    assert_eq!(search_g.labeling.values().copied().collect::<HashSet<_>>(), (0..10).collect());
+                    // The canonical edge list contains the edges in both directions.
+                    assert_eq!(search_g.canonical.len(), 2 * 15);
+
+                    // Build a complete bipartite graph and a prism, which are both 3-regular.
+                    let g = $T::<$U>::from_edges([
+                        (0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)
+                    ]);
+                    let h = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3), (1, 4), (2, 5)
+                    ]);
+                    let (mut search_g, mut search_h) = (CanonicalLabeling::from(&g), CanonicalLabeling::from(&h));
+                    search_g.run();
+                    search_h.run();
+                    assert_ne!(search_g.canonical, search_h.canonical);
+
+                    // Build graphs with many automorphisms.
+                    for g in [
+                        $T::<$U>::new((0..10).collect::<Vec<_>>(), []),
+                        $T::<$U>::from_edges((0..10).flat_map(|x| (0..x).map(move |y| (x, y)))),
+                        $T::<$U>::from_edges((0..6).flat_map(|k| [(3 * k, 3 * k + 1), (3 * k + 1, 3 * k + 2), (3 * k + 2, 3 * k)])),
+                    ] {
+                        let mut search = CanonicalLabeling::from(&g);
+                        search.run();
+                        let l = &search.labeling;
+                        assert_eq!(search.canonical, E!(g).map(|(x, y)| (l[x], l[y])).collect());
+                    }
+                }
+
+                #[test]
+                fn weisfeiler_lehman() {
+                    // Build a null graph and a single vertex.
+                    let g = $T::<$U>::null();
+                    let h = $T::<$U>::new([0], []);
+                    let (mut hash_g, mut hash_h) = (WeisfeilerLehman::from(&g), WeisfeilerLehman::from(&h));
+                    assert_ne!(hash_g.run().hash, hash_h.run().hash);
+
+                    // Build the Petersen graph and a relabeled copy of it.
+                    let edges = [
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ];
+                    let f = |x: i32| (x * 3 + 7) % 10;
+                    let g = $T::<$U>::from_edges(edges);
+                    let h = $T::<$U>::from_edges(edges.map(|(x, y)| (f(x), f(y))));
+                    let (mut hash_g, mut hash_h) = (WeisfeilerLehman::from(&g), WeisfeilerLehman::from(&h));
+                    assert_eq!(hash_g.run().hash, hash_h.run().hash);
+                    // Vertex labels are preserved by the relabeling.
+                    assert!(V!(g).all(|x| hash_g.labels[x] == hash_h.labels[&f(*x)]));
+
+                    // Build a complete bipartite graph and a prism, which cannot be distinguished.
+                    let g = $T::<$U>::from_edges([
+                        (0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)
+                    ]);
+                    let h = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3), (1, 4), (2, 5)
+                    ]);
+                    let (mut hash_g, mut hash_h) = (WeisfeilerLehman::from(&g), WeisfeilerLehman::from(&h));
+                    assert_eq!(hash_g.run().hash, hash_h.run().hash);
+
+                    // Build a path and a star, which have the same order and size.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3)]);
+                    let h = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3)]);
+                    let (mut hash_g, mut hash_h) = (WeisfeilerLehman::from((&g, 1)), WeisfeilerLehman::from((&h, 1)));
+                    assert_ne!(hash_g.run().hash, hash_h.run().hash);
+
+                    // Build a path with labelled vertices.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3)]);
+                    let h = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3)]);
+                    let mut hash_g = WeisfeilerLehman::from((&g, 2, |x: &i32| (*x == 0) as u64, |_: &i32, _: &i32| 0));
+                    let mut hash_h = WeisfeilerLehman::from((&h, 2, |x: &i32| (*x == 3) as u64, |_: &i32, _: &i32| 0));
+                    // The labelled endpoint is reversed.
+                    assert_eq!(hash_g.run().hash, hash_h.run().hash);
+                    let mut hash_h = WeisfeilerLehman::from((&h, 2, |x: &i32| (*x == 1) as u64, |_: &i32, _: &i32| 0));
+                    // The labelled vertex is not an endpoint anymore.
+                    assert_ne!(hash_g.run().hash, hash_h.run().hash);
+                }
+
+                #[test]
+                fn motif_count() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = MotifCount::from((&g, 3));
+                    search.run();
+                    assert_eq!(search.motifs.len(), 2);
+                    let mut search = MotifCount::from((&g, 4));
+                    search.run();
+                    assert_eq!(search.motifs.len(), 6);
+                    assert!(search.motifs.values().all(|&n| n == 0));
+
+                    // Build a complete graph.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+                    let mut search = MotifCount::from((&g, 3));
+                    search.run();
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2), (1, 2)])], 4);
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2)])], 0);
+
+                    // Build a cycle, whose connected induced subgraphs with four vertices are paths.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+                    let mut search = MotifCount::from((&g, 4));
+                    search.run();
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2), (1, 3)])], 5);
+                    assert_eq!(search.motifs.values().sum::<usize>(), 5);
+
+                    // Build the Petersen graph, which is triangle-free and 3-regular.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ]);
+                    let mut search = MotifCount::from((&g, 3));
+                    search.run();
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2)])], 10 * 3);
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2), (1, 2)])], 0);
+                    // Each vertex is the center of a single claw.
+                    let mut search = MotifCount::from((&g, 4));
+                    search.run();
+                    assert_eq!(search.motifs[&EdgeList::from([(0, 1), (0, 2), (0, 3)])], 10);
+                    assert_eq!(search.motifs.values().filter(|&&n| n > 0).count(), 2);
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{CanonicalLabeling, MotifCount, WeisfeilerLehman, VF2};
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage};
+        use crate::types::{EdgeList, Error};
+        use crate::{E, V};
+        use std::collections::{HashMap, HashSet};
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn hopcroft_karp() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = HopcroftKarp::from(&g);
+                    search.run()?;
+                    assert!(search.matching.is_empty());
+                    assert!(search.vertex_cover.is_empty());
+
+                    // Build a complete bipartite graph.
+                    let g = $T::<$U>::from_edges([
+                        (0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)
+                    ]);
+                    let mut search = HopcroftKarp::from(&g);
+                    search.run()?;
+                    assert_eq!(search.matching.len(), 3);
+                    assert_eq!(search.vertex_cover.len(), 3);
+
+                    // Build a bipartite graph where a greedy matching is not maximum.
+                    let g = $T::<$U>::from_edges([
+                        (0, 5), (0, 6), (1, 5), (2, 6), (2, 7), (2, 8), (3, 7), (4, 7), (4, 9)
+                    ]);
+                    let mut search = HopcroftKarp::from(&g);
+                    search.run()?;
+                    assert_eq!(search.matching.len(), 5);
+                    // Each vertex is matched at most once, through an edge of the graph.
+                    for (x, y) in search.matching.iter() {
+                        assert!(g.has_edge(x, y)?);
+                        assert_eq!(search.mate[x], *y);
+                        assert_eq!(search.mate[y], *x);
+                    }
+                    // The vertex cover has the same size of the matching, and covers every edge.
+                    assert_eq!(search.vertex_cover.len(), 5);
+                    assert!(g.edges_iter().all(|(x, y)| search.vertex_cover.contains(x) || search.vertex_cover.contains(y)));
+
+                    // Build a disconnected graph with a star and an isolated vertex.
+                    let mut g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (4, 5)]);
+                    g.add_vertex(6)?;
+                    let mut search = HopcroftKarp::from(&g);
+                    search.run()?;
+                    assert_eq!(search.matching.len(), 2);
+                    assert_eq!(search.vertex_cover, BTreeSet::from([&0, &4]));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn hopcroft_karp_long_path() -> Result<(), Error<i32>> {
+                    // Build a long path `x_0 - y_0 - x_1 - y_1 - ... - x_k - y_k`, where the left vertices
+                    // `x_1, ..., x_k` are labeled first, so that the first phase matches each of them with
+                    // its lower neighbor `y_{i-1}`, and leaves `x_0` and `y_k` free.
+                    let k = 100_000;
+                    let (x, y) = (|i: i32| if i == 0 { 2 * k + 1 } else { i - 1 }, |i: i32| k + i);
+                    let g = $T::<$U>::from_edges(
+                        (0..=k).flat_map(|i| [(x(i), y(i)), (y(i), x(i + 1))]).take(2 * k as usize + 1)
+                    );
+                    // The second phase augments along the whole path, hence the search must not recurse.
+                    let mut search = HopcroftKarp::from(&g);
+                    search.run()?;
+                    assert_eq!(search.matching.len(), k as usize + 1);
+                    assert!((0..=k).all(|i| search.mate[&x(i)] == &y(i)));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn hopcroft_karp_not_bipartite() {
+                    // Build a triangle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 0)]);
+                    let mut search = HopcroftKarp::from(&g);
+                    assert_eq!(search.run().err(), Some(Error::NotBipartite(vec![0, 1, 2, 0])));
+                }
+
+                #[test]
+                fn edmonds_blossom() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = EdmondsBlossom::from(&g);
+                    search.run();
+                    assert!(search.matching.is_empty());
+
+                    // Build an odd cycle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+                    let mut search = EdmondsBlossom::from(&g);
+                    search.run();
+                    assert_eq!(search.matching.len(), 2);
+
+                    // Build an odd cycle with a pendant vertex, where the blossom has to be contracted.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (4, 5)]);
+                    let mut search = EdmondsBlossom::from(&g);
+                    search.run();
+                    assert_eq!(search.matching.len(), 3);
+
+                    // Build the Petersen graph, which has a perfect matching.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ]);
+                    let mut search = EdmondsBlossom::from(&g);
+                    search.run();
+                    assert_eq!(search.matching.len(), 5);
+                    assert_eq!(search.mate.len(), 10);
+                    // Each vertex is matched at most once, through an edge of the graph.
+                    for (x, y) in search.matching.iter() {
+                        assert!(x < y);
+                        assert!(g.has_edge(x, y)?);
+                        assert_eq!(search.mate[x], *y);
+                        assert_eq!(search.mate[y], *x);
+                    }
+
+                    // Build a disconnected graph with a self-edge and an isolated vertex.
+                    let mut g = $T::<$U>::from_edges([(0, 0), (0, 1), (1, 2), (2, 0), (3, 4)]);
+                    g.add_vertex(5)?;
+                    let mut search = EdmondsBlossom::from(&g);
+                    search.run();
+                    assert_eq!(search.matching.len(), 2);
+                    assert!(!search.mate.contains_key(&5));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn maximum_weight_matching() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut search = MaximumWeightMatching::from((&g, |_: &i32, _: &i32| 1.));
+                    search.run();
+                    assert!(search.matching.is_empty());
+                    assert_eq!(search.total_weight, 0.);
+
+                    // Build a weighted path, where the maximum weight matching is not maximum in cardinality.
+                    let w = HashMap::from([((0, 1), 2.), ((1, 2), 5.), ((2, 3), 2.)]);
+                    let g = $T::<$U>::from_edges(w.keys().copied());
+                    let mut search = MaximumWeightMatching::from((&g, |x: &i32, y: &i32| w[&(*x.min(y), *x.max(y))]));
+                    search.run();
+                    assert_eq!(search.matching, BTreeSet::from([(&1, &2)]));
+                    assert_eq!(search.total_weight, 5.);
+
+                    // Build a triangle with a pendant vertex.
+                    let w = HashMap::from([((0, 1), 1.), ((1, 2), 1.), ((0, 2), 1.), ((2, 3), 3.)]);
+                    let g = $T::<$U>::from_edges(w.keys().copied());
+                    let mut search = MaximumWeightMatching::from((&g, |x: &i32, y: &i32| w[&(*x.min(y), *x.max(y))]));
+                    search.run();
+                    assert_eq!(search.matching, BTreeSet::from([(&0, &1), (&2, &3)]));
+                    assert_eq!(search.total_weight, 4.);
+
+                    // Edges with negative weight are never matched.
+                    let w = HashMap::from([((0, 1), -1.), ((1, 2), 0.5)]);
+                    let g = $T::<$U>::from_edges(w.keys().copied());
+                    let mut search = MaximumWeightMatching::from((&g, |x: &i32, y: &i32| w[&(*x.min(y), *x.max(y))]));
+                    search.run();
+                    assert_eq!(search.matching, BTreeSet::from([(&1, &2)]));
+
+                    // Build the Petersen graph, with unit weights the matching is maximum in cardinality.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+                        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+                        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+                    ]);
+                    let mut search = MaximumWeightMatching::from((&g, |_: &i32, _: &i32| 1.));
+                    search.run();
+                    assert_eq!(search.matching.len(), 5);
+                    assert_eq!(search.total_weight, 5.);
+                }
+
+                #[test]
+                fn maximum_weight_matching_brute_force() {
+                    // Build a complete graph with pseudo-random weights.
+                    let edges: Vec<_> = (0..6).flat_map(|x| (x + 1..6).map(move |y| (x, y))).collect();
+                    let w: HashMap<_, _> = edges
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &e)| (e, ((i * 7919) % 23) as f64))
+                        .collect();
+                    let g = $T::<$U>::from_edges(edges.iter().copied());
+                    let mut search = MaximumWeightMatching::from((&g, |x: &i32, y: &i32| w[&(*x.min(y), *x.max(y))]));
+                    search.run();
+                    // Enumerate every subset of edges, keeping the heaviest matching.
+                    let best = (0..1 << edges.len())
+                        .filter_map(|s: usize| {
+                            let e: Vec<_> = edges.iter().enumerate().filter(|(i, _)| s >> i & 1 == 1).map(|(_, e)| e).collect();
+                            let v: BTreeSet<_> = e.iter().flat_map(|&&(x, y)| [x, y]).collect();
+                            (v.len() == 2 * e.len()).then(|| e.iter().map(|e| w[e]).sum::<f64>())
+                        })
+                        .fold(0., f64::max);
+                    assert_eq!(search.total_weight, best);
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{EdmondsBlossom, HopcroftKarp, MaximumWeightMatching};
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage};
+        use crate::types::Error;
+        use std::collections::{BTreeSet, HashMap};
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
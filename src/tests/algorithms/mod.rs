@@ -1,2 +1,14 @@
+pub mod centrality;
+pub mod chordal;
+pub mod cliques;
+pub mod clustering;
+pub mod coloring;
+pub mod components;
+pub mod cycles;
+pub mod flows;
+pub mod isomorphism;
+pub mod matching;
+pub mod shortest_paths;
 pub mod simple_paths;
+pub mod spanning_trees;
 pub mod traversal;
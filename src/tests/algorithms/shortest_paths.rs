@@ -0,0 +1,530 @@
+#[cfg(test)]
+mod directed {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn a_star() {
+                    // Build a weighted graph.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (1, 2), (1, 3), (2, 1), (2, 3), (2, 4), (3, 4), (4, 0), (4, 3)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 10.), (0, 2, 5.), (1, 2, 2.), (1, 3, 1.), (2, 1, 3.),
+                        (2, 3, 9.), (2, 4, 2.), (3, 4, 4.), (4, 0, 7.), (4, 3, 6.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                    }
+                    // Without heuristic, A* is equivalent to Dijkstra.
+                    let mut search = AStar::from((&g, &0, &3, |x, y| *g.get_edge_attrs(x, y).unwrap(), |_| 0.));
+                    assert_eq!(search.run(), Some((vec![&0, &2, &1, &3], 9.)));
+                    assert_eq!(search.predecessor[&3], &1);
+
+                    // Build an admissible heuristic, i.e. the true distances from the target.
+                    let h = [9., 1., 4., 0., 6.];
+                    let mut search = AStar::from((&g, &0, &3, |x, y| *g.get_edge_attrs(x, y).unwrap(), |&x| h[x as usize]));
+                    assert_eq!(search.run(), Some((vec![&0, &2, &1, &3], 9.)));
+                    // Discovered vertices have their distance from the source recorded.
+                    assert_eq!(search.distance.get(&4), Some(&7.));
+
+                    // The source vertex has a trivial path to itself.
+                    let mut search = AStar::from((&g, &0, &0, |x, y| *g.get_edge_attrs(x, y).unwrap(), |_| 0.));
+                    assert_eq!(search.run(), Some((vec![&0], 0.)));
+
+                    // Unreachable vertices have no path.
+                    let g = $T::<$U>::from_edges([(0, 1), (2, 1)]);
+                    let mut search = AStar::from((&g, &0, &2, |_, _| 1., |_| 0.));
+                    assert_eq!(search.run(), None);
+                }
+
+                #[test]
+                #[should_panic]
+                fn a_star_should_panic() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    AStar::from((&g, &0, &1, |_, _| 1., |_| 0.)).run();
+                }
+
+                #[test]
+                fn bellman_ford() -> Result<(), Error<i32>> {
+                    // Build a weighted graph with negative weights.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (1, 2), (1, 3), (1, 4), (3, 1), (3, 2), (4, 3)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, -1.), (0, 2, 4.), (1, 2, 3.), (1, 3, 2.),
+                        (1, 4, 2.), (3, 1, 1.), (3, 2, 5.), (4, 3, -3.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                    }
+                    // Build the search object.
+                    let mut search = BellmanFord::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Run the algorithm.
+                    search.run()?;
+
+                    // Check distances.
+                    assert_eq!(search.distance[&0], 0.);
+                    assert_eq!(search.distance[&1], -1.);
+                    assert_eq!(search.distance[&2], 2.);
+                    assert_eq!(search.distance[&3], -2.);
+                    assert_eq!(search.distance[&4], 1.);
+
+                    // Check predecessors.
+                    assert_eq!(search.predecessor.get(&0), None);
+                    assert_eq!(search.predecessor[&1], &0);
+                    assert_eq!(search.predecessor[&2], &1);
+                    assert_eq!(search.predecessor[&3], &4);
+                    assert_eq!(search.predecessor[&4], &1);
+
+                    // Unreachable vertices have no distance.
+                    let mut search = BellmanFord::from((&g, &3, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    search.run()?;
+                    assert_eq!(search.distance.get(&0), None);
+                    assert_eq!(search.distance[&3], 0.);
+
+                    // Close a negative cycle.
+                    g.set_edge_attrs(&3, &1, -0.5);
+                    let mut search = BellmanFord::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    assert_eq!(search.run().err(), Some(Error::NegativeCycle(vec![1, 4, 3, 1])));
+
+                    // A negative self-loop is a negative cycle.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 1)]);
+                    let mut search = BellmanFord::from((&g, &0, |x, y| if x == y { -1. } else { 1. }));
+                    assert_eq!(search.run().err(), Some(Error::NegativeCycle(vec![1, 1])));
+
+                    // A negative cycle not reachable from the source is not reported.
+                    let g = $T::<$U>::from_edges([(0, 1), (2, 3), (3, 2)]);
+                    let mut search = BellmanFord::from((&g, &0, |_, _| -1.));
+                    search.run()?;
+                    assert_eq!(search.distance[&1], -1.);
+
+                    Ok(())
+                }
+
+                #[test]
+                #[should_panic]
+                fn bellman_ford_should_panic() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    BellmanFord::from((&g, &0, |_, _| 1.)).run().ok();
+                }
+
+                #[test]
+                fn dijkstra() {
+                    // Build a weighted graph.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (1, 2), (1, 3), (2, 1), (2, 3), (2, 4), (3, 4), (4, 0), (4, 3)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 10.), (0, 2, 5.), (1, 2, 2.), (1, 3, 1.), (2, 1, 3.),
+                        (2, 3, 9.), (2, 4, 2.), (3, 4, 4.), (4, 0, 7.), (4, 3, 6.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                    }
+                    // Build the search object.
+                    let mut search = Dijkstra::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Collect the vertices by increasing distance.
+                    let order: Vec<_> = search.by_ref().collect();
+                    // Check visit order.
+                    assert_eq!(order, [&0, &2, &4, &1, &3]);
+
+                    // Check distances.
+                    assert_eq!(search.distance[&0], 0.);
+                    assert_eq!(search.distance[&1], 8.);
+                    assert_eq!(search.distance[&2], 5.);
+                    assert_eq!(search.distance[&3], 9.);
+                    assert_eq!(search.distance[&4], 7.);
+
+                    // Check predecessors.
+                    assert_eq!(search.predecessor.get(&0), None);
+                    assert_eq!(search.predecessor[&1], &2);
+                    assert_eq!(search.predecessor[&2], &0);
+                    assert_eq!(search.predecessor[&3], &1);
+                    assert_eq!(search.predecessor[&4], &2);
+
+                    // Build the search object with a target vertex.
+                    let mut search = Dijkstra::from((&g, &0, &4, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Collect the vertices by increasing distance.
+                    let order: Vec<_> = search.by_ref().collect();
+                    // The search terminates once the target vertex is visited.
+                    assert_eq!(order, [&0, &2, &4]);
+                    assert_eq!(search.distance[&4], 7.);
+                    assert_eq!(search.predecessor[&4], &2);
+
+                    // Unreachable vertices have no distance.
+                    let g = $T::<$U>::from_edges([(0, 1), (2, 1)]);
+                    let mut search = Dijkstra::from((&g, &0, |_, _| 1.));
+                    assert_eq!(search.by_ref().collect::<Vec<_>>(), [&0, &1]);
+                    assert_eq!(search.distance.get(&2), None);
+                }
+
+                #[test]
+                #[should_panic]
+                fn dijkstra_should_panic() {
+                    // Build a graph with a negative weight.
+                    let g = $T::<$U>::from_edges([(0, 1)]);
+                    Dijkstra::from((&g, &0, |_, _| -1.)).count();
+                }
+
+                #[test]
+                fn floyd_warshall() -> Result<(), Error<i32>> {
+                    // Build a weighted graph with negative weights.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (0, 4), (1, 3), (1, 4), (2, 1), (3, 0), (3, 2), (4, 3)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 3.), (0, 2, 8.), (0, 4, -4.), (1, 3, 1.), (1, 4, 7.),
+                        (2, 1, 4.), (3, 0, 2.), (3, 2, -5.), (4, 3, 6.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                    }
+                    // Build the search object.
+                    let mut search = FloydWarshall::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Run the algorithm.
+                    search.run()?;
+
+                    // Check distances.
+                    assert_eq!(
+                        search.distance,
+                        arr2(&[
+                            [0., 1., -3., 2., -4.],
+                            [3., 0., -4., 1., -1.],
+                            [7., 4., 0., 5., 3.],
+                            [2., -1., -5., 0., -2.],
+                            [8., 5., 1., 6., 0.],
+                        ])
+                    );
+                    // Check indices are consistent with the adjacency matrix.
+                    assert_eq!(
+                        search.distance.mapv(|d| d != 0.) & g.dense_adjacency_matrix(),
+                        g.dense_adjacency_matrix()
+                    );
+
+                    // Check paths.
+                    assert_eq!(search.path(&0, &0), Some(vec![&0]));
+                    assert_eq!(search.path(&0, &2), Some(vec![&0, &4, &3, &2]));
+                    assert_eq!(search.path(&2, &0), Some(vec![&2, &1, &3, &0]));
+                    assert_eq!(search.path(&4, &1), Some(vec![&4, &3, &2, &1]));
+
+                    // Unreachable vertices have infinite distance and no path.
+                    let g = $T::<$U>::from_edges([(0, 1), (2, 1)]);
+                    let mut search = FloydWarshall::from((&g, |_, _| 1.));
+                    search.run()?;
+                    assert_eq!(search.distance[(0, 2)], f64::INFINITY);
+                    assert_eq!(search.path(&0, &2), None);
+
+                    // Close a negative cycle.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 1)
+                    ]);
+                    for (x, y, w) in [(0, 1, 1.), (1, 2, 1.), (2, 3, -4.), (3, 1, 2.)] {
+                        g.set_edge_attrs(&x, &y, w);
+                    }
+                    let mut search = FloydWarshall::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    assert_eq!(search.run().err(), Some(Error::NegativeCycle(vec![1, 2, 3, 1])));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn johnson() -> Result<(), Error<i32>> {
+                    // Build a weighted graph with negative weights.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (0, 4), (1, 3), (1, 4), (2, 1), (3, 0), (3, 2), (4, 3)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 3.), (0, 2, 8.), (0, 4, -4.), (1, 3, 1.), (1, 4, 7.),
+                        (2, 1, 4.), (3, 0, 2.), (3, 2, -5.), (4, 3, 6.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                    }
+                    // Build the search object.
+                    let mut search = Johnson::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Run the algorithm.
+                    search.run()?;
+
+                    // Check distances against Floyd-Warshall.
+                    let mut other = FloydWarshall::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    other.run()?;
+                    for (i, x) in g.vertices_iter().enumerate() {
+                        for (j, y) in g.vertices_iter().enumerate() {
+                            assert_eq!(search.distance[x][y], other.distance[(i, j)]);
+                            assert_eq!(search.path(x, y), other.path(x, y));
+                        }
+                    }
+
+                    // Check paths.
+                    assert_eq!(search.path(&0, &0), Some(vec![&0]));
+                    assert_eq!(search.path(&0, &2), Some(vec![&0, &4, &3, &2]));
+                    assert_eq!(search.path(&2, &0), Some(vec![&2, &1, &3, &0]));
+
+                    // Unreachable vertices have no distance and no path.
+                    let g = $T::<$U>::from_edges([(0, 1), (2, 1)]);
+                    let mut search = Johnson::from((&g, |_, _| 1.));
+                    search.run()?;
+                    assert_eq!(search.distance[&0].get(&2), None);
+                    assert_eq!(search.path(&0, &2), None);
+
+                    // Close a negative cycle.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (3, 1)
+                    ]);
+                    for (x, y, w) in [(0, 1, 1.), (1, 2, 1.), (2, 3, -4.), (3, 1, 2.)] {
+                        g.set_edge_attrs(&x, &y, w);
+                    }
+                    let mut search = Johnson::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    assert_eq!(search.run().err(), Some(Error::NegativeCycle(vec![1, 2, 3, 1])));
+
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{AStar, BellmanFord, Dijkstra, FloydWarshall, Johnson};
+        use crate::graphs::attributes::AttributesMap;
+        use crate::graphs::DirectedAdjacencyListGraph;
+        use crate::traits::{Convert, From, Storage, WithAttributes};
+        use crate::types::Error;
+        use ndarray::arr2;
+
+        generic_tests!(DirectedAdjacencyListGraph, i32);
+    }
+}
+
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn a_star() {
+                    // Build a 4x4 grid graph, with a wall between columns one and two.
+                    let g = $T::<$U>::from_edges([
+                        (0, 1), (1, 2), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13), (13, 14), (14, 15),
+                        (0, 4), (4, 8), (8, 12), (1, 5), (5, 9), (9, 13), (2, 6), (6, 10), (10, 14), (3, 7), (7, 11), (11, 15)
+                    ]);
+                    // Set the Manhattan distance between two vertices on the grid.
+                    let h = |x: &i32, y: &i32| ((x % 4 - y % 4).abs() + (x / 4 - y / 4).abs()) as f64;
+                    // Search a path between opposite corners.
+                    let mut search = AStar::from((&g, &12, &3, |_, _| 1., |x| h(x, &3)));
+                    let (path, cost) = search.run().unwrap();
+                    // There are multiple shortest paths, with cost six.
+                    assert_eq!(cost, 6.);
+                    assert_eq!(path.len(), 7);
+                    assert_eq!((path[0], path[6]), (&12, &3));
+
+                    // Search a path around the wall.
+                    let mut search = AStar::from((&g, &5, &6, |_, _| 1., |x| h(x, &6)));
+                    assert_eq!(search.run(), Some((vec![&5, &1, &2, &6], 3.)));
+                }
+
+                #[test]
+                #[should_panic]
+                fn a_star_should_panic() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    AStar::from((&g, &0, &1, |_, _| 1., |_| 0.)).run();
+                }
+
+                #[test]
+                fn bellman_ford() -> Result<(), Error<i32>> {
+                    // Build a weighted graph.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (0, 5), (1, 2), (1, 3), (2, 3), (2, 5), (3, 4), (4, 5)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 7.), (0, 2, 9.), (0, 5, 14.), (1, 2, 10.), (1, 3, 15.),
+                        (2, 3, 11.), (2, 5, 2.), (3, 4, 6.), (4, 5, 9.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                        g.set_edge_attrs(&y, &x, w);
+                    }
+                    // Build the search object.
+                    let mut search = BellmanFord::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Run the algorithm.
+                    search.run()?;
+
+                    // Check distances, which are the same of Dijkstra.
+                    assert_eq!(search.distance[&0], 0.);
+                    assert_eq!(search.distance[&1], 7.);
+                    assert_eq!(search.distance[&2], 9.);
+                    assert_eq!(search.distance[&3], 20.);
+                    assert_eq!(search.distance[&4], 20.);
+                    assert_eq!(search.distance[&5], 11.);
+
+                    // Check predecessors.
+                    assert_eq!(search.predecessor.get(&0), None);
+                    assert_eq!(search.predecessor[&1], &0);
+                    assert_eq!(search.predecessor[&2], &0);
+                    assert_eq!(search.predecessor[&3], &2);
+                    assert_eq!(search.predecessor[&4], &5);
+                    assert_eq!(search.predecessor[&5], &2);
+
+                    // Any negative undirected edge is a negative cycle.
+                    g.set_edge_attrs(&3, &4, -1.);
+                    g.set_edge_attrs(&4, &3, -1.);
+                    let mut search = BellmanFord::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    assert_eq!(search.run().err(), Some(Error::NegativeCycle(vec![3, 4, 3])));
+
+                    Ok(())
+                }
+
+                #[test]
+                #[should_panic]
+                fn bellman_ford_should_panic() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    BellmanFord::from((&g, &0, |_, _| 1.)).run().ok();
+                }
+
+                #[test]
+                fn dijkstra() {
+                    // Build a weighted graph.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (0, 5), (1, 2), (1, 3), (2, 3), (2, 5), (3, 4), (4, 5)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 7.), (0, 2, 9.), (0, 5, 14.), (1, 2, 10.), (1, 3, 15.),
+                        (2, 3, 11.), (2, 5, 2.), (3, 4, 6.), (4, 5, 9.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                        g.set_edge_attrs(&y, &x, w);
+                    }
+                    // Build the search object.
+                    let mut search = Dijkstra::from((&g, &0, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Collect the vertices by increasing distance.
+                    let order: Vec<_> = search.by_ref().collect();
+                    // Check visit order.
+                    assert_eq!(order, [&0, &1, &2, &5, &3, &4]);
+
+                    // Check distances.
+                    assert_eq!(search.distance[&0], 0.);
+                    assert_eq!(search.distance[&1], 7.);
+                    assert_eq!(search.distance[&2], 9.);
+                    assert_eq!(search.distance[&3], 20.);
+                    assert_eq!(search.distance[&4], 20.);
+                    assert_eq!(search.distance[&5], 11.);
+
+                    // Check predecessors.
+                    assert_eq!(search.predecessor.get(&0), None);
+                    assert_eq!(search.predecessor[&1], &0);
+                    assert_eq!(search.predecessor[&2], &0);
+                    assert_eq!(search.predecessor[&3], &2);
+                    assert_eq!(search.predecessor[&4], &5);
+                    assert_eq!(search.predecessor[&5], &2);
+
+                    // Build the search object with a target vertex.
+                    let mut search = Dijkstra::from((&g, &0, &5, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Collect the vertices by increasing distance.
+                    let order: Vec<_> = search.by_ref().collect();
+                    // The search terminates once the target vertex is visited.
+                    assert_eq!(order, [&0, &1, &2, &5]);
+                    assert_eq!(search.distance[&5], 11.);
+                    assert_eq!(search.predecessor[&5], &2);
+                }
+
+                #[test]
+                #[should_panic]
+                fn dijkstra_should_panic() {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    Dijkstra::from((&g, &0, |_, _| 1.)).count();
+                }
+
+                #[test]
+                fn floyd_warshall() -> Result<(), Error<i32>> {
+                    // Build a weighted graph.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (0, 5), (1, 2), (1, 3), (2, 3), (2, 5), (3, 4), (4, 5)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 7.), (0, 2, 9.), (0, 5, 14.), (1, 2, 10.), (1, 3, 15.),
+                        (2, 3, 11.), (2, 5, 2.), (3, 4, 6.), (4, 5, 9.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                        g.set_edge_attrs(&y, &x, w);
+                    }
+                    // Build the search object.
+                    let mut search = FloydWarshall::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Run the algorithm.
+                    search.run()?;
+
+                    // Check distances, which are symmetric.
+                    assert_eq!(
+                        search.distance,
+                        arr2(&[
+                            [0., 7., 9., 20., 20., 11.],
+                            [7., 0., 10., 15., 21., 12.],
+                            [9., 10., 0., 11., 11., 2.],
+                            [20., 15., 11., 0., 6., 13.],
+                            [20., 21., 11., 6., 0., 9.],
+                            [11., 12., 2., 13., 9., 0.],
+                        ])
+                    );
+                    assert_eq!(search.distance, search.distance.t());
+
+                    // Check paths.
+                    assert_eq!(search.path(&0, &4), Some(vec![&0, &2, &5, &4]));
+                    assert_eq!(search.path(&4, &0), Some(vec![&4, &5, &2, &0]));
+
+                    // Any negative undirected edge is a negative cycle.
+                    g.set_edge_attrs(&3, &4, -1.);
+                    g.set_edge_attrs(&4, &3, -1.);
+                    let mut search = FloydWarshall::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    assert_eq!(search.run().err(), Some(Error::NegativeCycle(vec![3, 4, 3])));
+
+                    Ok(())
+                }
+
+                #[test]
+                fn johnson() -> Result<(), Error<i32>> {
+                    // Build a weighted graph.
+                    let mut g = $T::<$U, AttributesMap<$U, (), (), f64>>::from_edges([
+                        (0, 1), (0, 2), (0, 5), (1, 2), (1, 3), (2, 3), (2, 5), (3, 4), (4, 5)
+                    ]);
+                    for (x, y, w) in [
+                        (0, 1, 7.), (0, 2, 9.), (0, 5, 14.), (1, 2, 10.), (1, 3, 15.),
+                        (2, 3, 11.), (2, 5, 2.), (3, 4, 6.), (4, 5, 9.)
+                    ] {
+                        g.set_edge_attrs(&x, &y, w);
+                        g.set_edge_attrs(&y, &x, w);
+                    }
+                    // Build the search object.
+                    let mut search = Johnson::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    // Run the algorithm.
+                    search.run()?;
+
+                    // Check distances against Floyd-Warshall.
+                    let mut other = FloydWarshall::from((&g, |x, y| *g.get_edge_attrs(x, y).unwrap()));
+                    other.run()?;
+                    for (i, x) in g.vertices_iter().enumerate() {
+                        for (j, y) in g.vertices_iter().enumerate() {
+                            assert_eq!(search.distance[x][y], other.distance[(i, j)]);
+                        }
+                    }
+
+                    // Check paths.
+                    assert_eq!(search.path(&0, &4), Some(vec![&0, &2, &5, &4]));
+                    assert_eq!(search.path(&4, &0), Some(vec![&4, &5, &2, &0]));
+
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{AStar, BellmanFord, Dijkstra, FloydWarshall, Johnson};
+        use crate::graphs::attributes::AttributesMap;
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{From, Storage, WithAttributes};
+        use crate::types::Error;
+        use ndarray::arr2;
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
@@ -136,7 +136,6 @@ mod undirected {
         ($T:ident, $U:ident) => {
             paste::item! {
                 #[test]
-                #[ignore]
                 fn all_simple_cycles() {
                     let g = $T::<$U>::from_edges([
                         (1, 2), (2, 3), (3, 4), (3, 5),
@@ -155,6 +154,52 @@ mod undirected {
                     );
                 }
 
+                #[test]
+                fn all_simple_cycles_self_edges() {
+                    // Build a graph with a self-edge on a pendant vertex.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 1)]);
+                    let mut search = AllSimpleCycles::from(&g);
+                    search.run();
+                    // The self-edge is reported once, while the single edge is not a cycle.
+                    assert_eq!(search.simple_cycles, [vec![&1, &1]]);
+                    assert_eq!(search.popularity.get(&0), None);
+                    assert_eq!(search.popularity[&1], 1);
+                }
+
+                #[test]
+                fn all_simple_cycles_trees() {
+                    // Build a tree, which has no cycles.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (1, 3), (1, 4)]);
+                    let mut search = AllSimpleCycles::from(&g);
+                    search.run();
+                    // Single edges are not reported as cycles of length two.
+                    assert!(search.simple_cycles.is_empty());
+                    assert!(search.popularity.is_empty());
+                }
+
+                #[test]
+                fn all_simple_cycles_complete() {
+                    // Build a complete graph on four vertices.
+                    let g = $T::<$U>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+                    let mut search = AllSimpleCycles::from(&g);
+                    search.run();
+                    // Each of the four triangles and three squares is reported once.
+                    assert_eq!(
+                        search.simple_cycles,
+                        [
+                            vec![&0, &1, &2, &0],
+                            vec![&0, &1, &2, &3, &0],
+                            vec![&0, &1, &3, &0],
+                            vec![&0, &1, &3, &2, &0],
+                            vec![&0, &2, &1, &3, &0],
+                            vec![&0, &2, &3, &0],
+                            vec![&1, &2, &3, &1],
+                        ]
+                    );
+                    // Each vertex belongs to three triangles and three squares.
+                    assert!(V!(g).all(|x| search.popularity[x] == 6));
+                }
+
                 #[test]
                 fn all_simple_paths() {
                     let g = $T::<$U>::from_edges([
@@ -192,6 +237,7 @@ mod undirected {
         use crate::algorithms::{AllSimpleCycles, AllSimplePaths};
         use crate::graphs::UndirectedAdjacencyListGraph;
         use crate::traits::{From, Storage};
+        use crate::V;
 
         generic_tests!(UndirectedAdjacencyListGraph, i32);
     }
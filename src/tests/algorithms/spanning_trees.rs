@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod undirected {
+
+    macro_rules! generic_tests {
+        ($T:ident, $U:ident) => {
+            paste::item! {
+                #[test]
+                fn minimum_spanning_trees() -> Result<(), Error<i32>> {
+                    // Build a null graph.
+                    let g = $T::<$U>::null();
+                    let mut kruskal = Kruskal::from(&g);
+                    let mut prim = Prim::from(&g);
+                    let mut boruvka = Boruvka::from(&g);
+                    assert_eq!(kruskal.run().tree, g);
+                    assert_eq!(prim.run().tree, g);
+                    assert_eq!(boruvka.run().tree, g);
+
+                    // Build a cycle with unit weights.
+                    let g = $T::<$U>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+                    let mut kruskal = Kruskal::from(&g);
+                    let mut prim = Prim::from(&g);
+                    let mut boruvka = Boruvka::from(&g);
+                    kruskal.run();
+                    prim.run();
+                    boruvka.run();
+                    for (tree, total_weight) in [
+                        (&kruskal.tree, kruskal.total_weight),
+                        (&prim.tree, prim.total_weight),
+                        (&boruvka.tree, boruvka.total_weight),
+                    ] {
+                        // Ties are broken by endpoints, so the last edge is left out.
+                        assert_eq!(tree, &$T::<$U>::from_edges([(0, 1), (0, 3), (1, 2)]));
+                        assert_eq!(total_weight, 3.);
+                    }
+
+                    // Build a disconnected graph with a self-edge and an isolated vertex.
+                    let w = HashMap::from([((0, 0), -9.), ((0, 1), 3.), ((1, 2), 1.), ((0, 2), 2.), ((3, 4), -1.)]);
+                    let mut g = $T::<$U>::from_edges(w.keys().copied());
+                    g.add_vertex(5)?;
+                    // Weights are only defined on ordered endpoints, which every algorithm must query.
+                    let w = |x: &i32, y: &i32| w[&(*x, *y)];
+                    let mut kruskal = Kruskal::from((&g, w));
+                    let mut prim = Prim::from((&g, w));
+                    let mut boruvka = Boruvka::from((&g, w));
+                    kruskal.run();
+                    prim.run();
+                    boruvka.run();
+                    for (tree, total_weight) in [
+                        (&kruskal.tree, kruskal.total_weight),
+                        (&prim.tree, prim.total_weight),
+                        (&boruvka.tree, boruvka.total_weight),
+                    ] {
+                        // The spanning forest keeps the whole vertex set.
+                        let mut h = $T::<$U>::from_edges([(0, 2), (1, 2), (3, 4)]);
+                        h.add_vertex(5)?;
+                        assert_eq!(tree, &h);
+                        assert_eq!(total_weight, 2.);
+                    }
+
+                    Ok(())
+                }
+
+                #[test]
+                fn minimum_spanning_trees_agree() {
+                    // Build a complete graph with pseudo-random weights, with many ties.
+                    let edges: Vec<_> = (0..8).flat_map(|x| (x + 1..8).map(move |y| (x, y))).collect();
+                    let w: HashMap<_, _> = edges
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &e)| (e, ((i * 7919) % 5) as f64))
+                        .collect();
+                    let g = $T::<$U>::from_edges(edges.iter().copied());
+                    // Weights are only defined on ordered endpoints, which every algorithm must query.
+                    let w = |x: &i32, y: &i32| w[&(*x, *y)];
+                    let mut kruskal = Kruskal::from((&g, w));
+                    let mut prim = Prim::from((&g, w));
+                    let mut boruvka = Boruvka::from((&g, w));
+                    kruskal.run();
+                    prim.run();
+                    boruvka.run();
+                    // The minimum spanning tree is unique, given the tie-breaking rule.
+                    assert_eq!(kruskal.tree, prim.tree);
+                    assert_eq!(kruskal.tree, boruvka.tree);
+                    assert_eq!(kruskal.total_weight, prim.total_weight);
+                    assert_eq!(kruskal.total_weight, boruvka.total_weight);
+                    // The tree is spanning and acyclic.
+                    assert_eq!(kruskal.tree.order(), 8);
+                    assert_eq!(kruskal.tree.size(), 7);
+                    assert!(kruskal.tree.is_connected());
+                    // Each edge of the tree is an edge of the graph.
+                    assert!(kruskal.tree.edges_iter().all(|(x, y)| g.has_edge(x, y).unwrap()));
+                }
+            }
+        };
+    }
+
+    mod adjacency_list_graph {
+        use crate::algorithms::{Boruvka, Kruskal, Prim};
+        use crate::graphs::UndirectedAdjacencyListGraph;
+        use crate::traits::{Connectivity, From, Storage};
+        use crate::types::Error;
+        use std::collections::HashMap;
+
+        generic_tests!(UndirectedAdjacencyListGraph, i32);
+    }
+}
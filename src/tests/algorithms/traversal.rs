@@ -999,6 +999,13 @@ mod undirected {
 
                     assert_eq!(search.next(), None);
                     assert_eq!(search.partitions, Q::from_iter([]));
+
+                    // Build a graph with isolated vertices.
+                    let g = $T::<$U>::new([0, 1, 2], [(1, 2)]);
+                    let search = LexBFS::from(&g);
+                    // Each vertex is visited exactly once,
+                    // even if its partition is left empty.
+                    assert_eq!(search.collect::<Vec<_>>(), [&0, &1, &2]);
                 }
 
                 #[test]
@@ -80,6 +80,10 @@ mod directed {
         let g = T::from_edges([(0, 1), (1, 2)]);
         assert_true!(g.is_connected());
 
+        // Test weakly connected graph.
+        let g = T::from_edges([(1, 0), (1, 2)]);
+        assert_true!(g.is_connected());
+
         // Test disconnected graph.
         let mut g = T::from_edges([(0, 1), (1, 2)]);
         g.add_vertex(3)?;
@@ -97,6 +101,44 @@ mod directed {
         Ok(())
     }
 
+    #[test]
+    fn is_strongly_connected<T>() -> Result<(), Error<i32>>
+    where
+        T: Connectivity<Vertex = i32> + From,
+    {
+        // Test null graph.
+        let g = T::null();
+        assert_true!(g.is_strongly_connected());
+
+        // Test single vertex graph.
+        let g = T::from_vertices([0]);
+        assert_true!(g.is_strongly_connected());
+
+        // Test single edge graph.
+        let g = T::from_edges([(0, 1)]);
+        assert_false!(g.is_strongly_connected());
+
+        // Test connected cyclic graph.
+        let g = T::from_edges([(0, 1), (1, 2), (2, 0)]);
+        assert_true!(g.is_strongly_connected());
+
+        // Test weakly connected graph with two cycles.
+        let g = T::from_edges([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)]);
+        assert_false!(g.is_strongly_connected());
+
+        // Test strongly connected graph with two cycles.
+        let mut g = T::from_edges([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)]);
+        g.add_edge(&3, &0)?;
+        assert_true!(g.is_strongly_connected());
+
+        // Test disconnected cyclic graph.
+        let mut g = T::from_edges([(0, 1), (1, 2), (2, 0)]);
+        g.add_vertex(3)?;
+        assert_false!(g.is_strongly_connected());
+
+        Ok(())
+    }
+
     #[test]
     fn is_acyclic<T>()
     where
@@ -116,6 +158,39 @@ mod directed {
 
         let g = T::from_edges([(0, 1), (1, 2), (2, 0)]);
         assert_false!(g.is_acyclic());
+
+        let g = T::from_edges([(0, 0)]);
+        assert_false!(g.is_acyclic());
+    }
+
+    #[test]
+    fn find_cycle<T>()
+    where
+        T: Connectivity<Vertex = i32> + From,
+    {
+        let g = T::null();
+        assert_eq!(g.find_cycle(), None);
+
+        let g = T::from_edges([(0, 1), (0, 2), (1, 2), (2, 3)]);
+        assert_eq!(g.find_cycle(), None);
+
+        // Test self edge.
+        let g = T::from_edges([(0, 1), (1, 1)]);
+        assert_eq!(g.find_cycle(), Some(vec![&1, &1]));
+
+        // Test directed cycle.
+        let g = T::from_edges([(0, 1), (1, 2), (2, 3), (3, 1)]);
+        assert_eq!(g.find_cycle(), Some(vec![&1, &2, &3, &1]));
+
+        // Test disconnected cycle.
+        let g = T::from_edges([(0, 1), (2, 3), (3, 4), (4, 2)]);
+        assert_eq!(g.find_cycle(), Some(vec![&2, &3, &4, &2]));
+
+        // Test any found cycle is a directed cycle.
+        let g = T::from_edges([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 5), (5, 2)]);
+        let cycle = g.find_cycle().unwrap();
+        assert_eq!(cycle.first(), cycle.last());
+        assert_true!(cycle.windows(2).all(|e| g.has_edge(e[0], e[1]).unwrap()));
     }
 
     #[instantiate_tests(<DirectedAdjacencyListGraph<i32>>)]
@@ -223,7 +298,27 @@ mod undirected {
     }
 
     #[test]
-    #[ignore]
+    fn is_strongly_connected<T>() -> Result<(), Error<i32>>
+    where
+        T: Connectivity<Vertex = i32> + From,
+    {
+        // Test null graph.
+        let g = T::null();
+        assert_true!(g.is_strongly_connected());
+
+        // Test single edge graph.
+        let g = T::from_edges([(0, 1)]);
+        assert_true!(g.is_strongly_connected());
+
+        // Test disconnected graph.
+        let mut g = T::from_edges([(0, 1), (1, 2)]);
+        g.add_vertex(3)?;
+        assert_false!(g.is_strongly_connected());
+
+        Ok(())
+    }
+
+    #[test]
     fn is_acyclic<T>()
     where
         T: Connectivity<Vertex = i32> + From,
@@ -242,6 +337,50 @@ mod undirected {
 
         let g = T::from_edges([(0, 1), (1, 2), (2, 0)]);
         assert_false!(g.is_acyclic());
+
+        // Test forest.
+        let g = T::from_edges([(0, 1), (0, 2), (3, 4), (4, 5), (4, 6)]);
+        assert_true!(g.is_acyclic());
+
+        // Test self edge.
+        let g = T::from_edges([(0, 0)]);
+        assert_false!(g.is_acyclic());
+
+        let g = T::from_edges([(0, 1), (1, 2), (2, 2)]);
+        assert_false!(g.is_acyclic());
+    }
+
+    #[test]
+    fn find_cycle<T>()
+    where
+        T: Connectivity<Vertex = i32> + From,
+    {
+        let g = T::null();
+        assert_eq!(g.find_cycle(), None);
+
+        // Test forest.
+        let g = T::from_edges([(0, 1), (0, 2), (3, 4), (4, 5), (4, 6)]);
+        assert_eq!(g.find_cycle(), None);
+
+        // Test self edge.
+        let g = T::from_edges([(0, 1), (1, 1)]);
+        assert_eq!(g.find_cycle(), Some(vec![&1, &1]));
+
+        // Test triangle.
+        let g = T::from_edges([(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(g.find_cycle(), Some(vec![&0, &2, &1, &0]));
+
+        // Test disconnected cycle.
+        let g = T::from_edges([(0, 1), (2, 3), (3, 4), (4, 5), (5, 2)]);
+        assert_eq!(g.find_cycle(), Some(vec![&2, &5, &4, &3, &2]));
+
+        // Test any found cycle is a simple cycle.
+        let g = T::from_edges([(0, 1), (0, 2), (1, 3), (2, 4), (3, 5), (4, 5), (5, 6)]);
+        let cycle = g.find_cycle().unwrap();
+        assert_eq!(cycle.len(), 7);
+        assert_eq!(cycle.first(), cycle.last());
+        assert_true!(cycle.windows(2).all(|e| g.has_edge(e[0], e[1]).unwrap()));
+        assert_eq!(cycle[1..].iter().collect::<std::collections::HashSet<_>>().len(), 6);
     }
 
     #[instantiate_tests(<UndirectedAdjacencyListGraph<i32>>)]
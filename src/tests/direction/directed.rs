@@ -2,10 +2,11 @@
 #[generic_tests::define]
 mod tests {
     use crate::graphs::DirectedAdjacencyListGraph;
-    use crate::traits::Directed;
+    use crate::traits::{Directed, From};
     use crate::types::Error;
     use crate::{An, Ch, De, Pa};
     use all_asserts::*;
+    use std::collections::BTreeSet;
 
     // TODO: Replace with is_sorted method on iterators once stable.
     fn is_sorted<I>(data: I) -> bool
@@ -198,6 +199,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn weakly_connected_components<T>() -> Result<(), Error<i32>>
+    where
+        T: Directed<Vertex = i32> + From,
+    {
+        // Test null graph.
+        let g = T::null();
+        assert_true!(g.weakly_connected_components().is_empty());
+
+        // Test graph with isolated vertices and a self-edge.
+        let mut g = T::from_edges([(4, 2), (0, 3), (3, 3), (5, 2)]);
+        g.add_vertex(1)?;
+        assert_eq!(
+            g.weakly_connected_components(),
+            [
+                BTreeSet::from([0, 3]),
+                BTreeSet::from([1]),
+                BTreeSet::from([2, 4, 5])
+            ]
+        );
+
+        // Test weakly but not strongly connected graph.
+        let g = T::from_edges([(1, 0), (1, 2), (3, 2)]);
+        assert_eq!(g.weakly_connected_components(), [BTreeSet::from([0, 1, 2, 3])]);
+
+        Ok(())
+    }
+
     #[test]
     fn in_degree_of<T>() -> Result<(), Error<i32>>
     where
@@ -2,10 +2,12 @@
 #[generic_tests::define]
 mod tests {
     use crate::graphs::UndirectedAdjacencyListGraph;
-    use crate::traits::Undirected;
-    use crate::types::Error;
+    use crate::traits::{Undirected, From};
+    use crate::types::{Cotree, Error};
     use crate::Ne;
     use all_asserts::*;
+    use itertools::Itertools;
+    use std::collections::BTreeSet;
 
     // TODO: Replace with is_sorted method on iterators once stable.
     fn is_sorted<I>(data: I) -> bool
@@ -135,6 +137,209 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn connected_components<T>() -> Result<(), Error<i32>>
+    where
+        T: Undirected<Vertex = i32> + From,
+    {
+        // Test null graph.
+        let g = T::null();
+        assert_true!(g.connected_components().is_empty());
+
+        // Test graph with isolated vertices and a self-edge.
+        let mut g = T::from_edges([(4, 2), (0, 3), (3, 3), (2, 5)]);
+        g.add_vertex(1)?;
+        assert_eq!(
+            g.connected_components(),
+            [
+                BTreeSet::from([0, 3]),
+                BTreeSet::from([1]),
+                BTreeSet::from([2, 4, 5])
+            ]
+        );
+
+        // Test connected graph.
+        let g = T::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+        assert_eq!(g.connected_components(), [BTreeSet::from([0, 1, 2, 3])]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_bipartite<T>() -> Result<(), Error<i32>>
+    where
+        T: Undirected<Vertex = i32> + From,
+    {
+        // Test null graph.
+        let g = T::null();
+        assert_eq!(g.is_bipartite(), Ok(Default::default()));
+
+        // Test even cycle with an isolated vertex.
+        let mut g = T::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+        g.add_vertex(4)?;
+        let c = g.is_bipartite().unwrap();
+        assert_eq!(c.len(), 5);
+        assert_true!(g.edges_iter().all(|(x, y)| c[x] != c[y]));
+
+        // Test odd cycle.
+        let g = T::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (4, 5)]);
+        assert_eq!(g.is_bipartite(), Err(vec![&0, &1, &2, &3, &4, &0]));
+
+        // Test odd cycle not containing the root of the search.
+        let g = T::from_edges([(0, 1), (1, 2), (1, 3), (2, 3)]);
+        assert_eq!(g.is_bipartite(), Err(vec![&1, &2, &3, &1]));
+
+        // Test self-edge.
+        let g = T::from_edges([(0, 1), (1, 1)]);
+        assert_eq!(g.is_bipartite(), Err(vec![&1, &1]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn perfect_elimination_ordering<T>() -> Result<(), Error<i32>>
+    where
+        T: Undirected<Vertex = i32> + From,
+    {
+        // Test null graph.
+        let g = T::null();
+        assert_eq!(g.perfect_elimination_ordering(), Some(vec![]));
+        assert_true!(g.is_chordal());
+
+        // Test trees and isolated vertices.
+        let mut g = T::from_edges([(0, 1), (0, 2), (2, 3), (2, 4)]);
+        g.add_vertex(5)?;
+        g.add_vertex(6)?;
+        assert_eq!(g.perfect_elimination_ordering().map(|o| o.len()), Some(7));
+        assert_true!(g.is_chordal());
+
+        // Test chordless cycles.
+        let g = T::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+        assert_eq!(g.perfect_elimination_ordering(), None);
+        assert_false!(g.is_chordal());
+        let g = T::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (0, 2), (2, 4), (4, 0)]);
+        assert_true!(g.is_chordal());
+        let g = T::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (0, 3)]);
+        assert_false!(g.is_chordal());
+
+        // Test each vertex and its later neighbors form a clique.
+        let g = T::from_edges([
+            (0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (2, 4), (3, 4), (3, 5), (4, 5), (5, 5)
+        ]);
+        let order = g.perfect_elimination_ordering().unwrap();
+        for (i, x) in order.iter().enumerate() {
+            let later: Vec<_> = Ne!(g, x).filter(|y| order[i + 1..].contains(y)).collect();
+            for y in later.iter() {
+                for z in later.iter().filter(|z| *z != y) {
+                    assert_true!(g.has_edge(y, z)?);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Edges represented by a cotree, i.e. pairs of vertices in different children of a join node.
+    fn cotree_edges(t: &Cotree<&i32>) -> BTreeSet<(i32, i32)> {
+        match t {
+            Cotree::Leaf(_) => Default::default(),
+            Cotree::Union(c) => c.iter().flat_map(cotree_edges).collect(),
+            Cotree::Join(c) => {
+                let mut edges: BTreeSet<_> = c.iter().flat_map(cotree_edges).collect();
+                for (i, a) in c.iter().enumerate() {
+                    for b in c[i + 1..].iter() {
+                        for x in a.leaves() {
+                            for y in b.leaves() {
+                                edges.insert((**x.min(y), **x.max(y)));
+                            }
+                        }
+                    }
+                }
+                edges
+            }
+        }
+    }
+
+    #[test]
+    fn is_cograph<T>() -> Result<(), Error<i32>>
+    where
+        T: Undirected<Vertex = i32> + From,
+    {
+        // Test null graph, which is the empty union.
+        let g = T::null();
+        let t = g.is_cograph().unwrap();
+        assert_eq!(t, Cotree::Union(vec![]));
+        assert!(t.leaves().is_empty());
+
+        // Test single vertex.
+        let g = T::empty([0]);
+        assert_eq!(g.is_cograph(), Some(Cotree::Leaf(&0)));
+
+        // Test complete graph.
+        let g = T::from_edges([(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(
+            g.is_cograph(),
+            Some(Cotree::Join(vec![Cotree::Leaf(&0), Cotree::Leaf(&1), Cotree::Leaf(&2)]))
+        );
+
+        // Test disconnected graph.
+        let mut g = T::from_edges([(0, 1), (2, 3), (2, 4)]);
+        g.add_vertex(5)?;
+        assert_eq!(
+            g.is_cograph(),
+            Some(Cotree::Union(vec![
+                Cotree::Join(vec![Cotree::Leaf(&0), Cotree::Leaf(&1)]),
+                Cotree::Join(vec![
+                    Cotree::Leaf(&2),
+                    Cotree::Union(vec![Cotree::Leaf(&3), Cotree::Leaf(&4)])
+                ]),
+                Cotree::Leaf(&5),
+            ]))
+        );
+
+        // Test path on four vertices, also as induced subgraph.
+        let g = T::from_edges([(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(g.is_cograph(), None);
+        let g = T::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+        assert_eq!(g.is_cograph(), None);
+
+        // Test cographs and non-cographs against brute force search of induced paths on four vertices.
+        for seed in 0..64_usize {
+            let edges: Vec<_> = (0..6)
+                .flat_map(|x| (x + 1..6).map(move |y| (x, y)))
+                .enumerate()
+                .filter(|(i, _)| (i * 7 + seed * 13 + i * i * seed) % 11 < 5)
+                .map(|(_, e)| e)
+                .collect();
+            let g = T::new(0..6, edges.iter().copied());
+            // Search for an induced path on four vertices.
+            let has_p4 = (0..6).permutations(4).any(|p| {
+                let a = |i: usize, j: usize| g.has_edge(&p[i], &p[j]).unwrap();
+                a(0, 1) && a(1, 2) && a(2, 3) && !a(0, 2) && !a(0, 3) && !a(1, 3)
+            });
+            match g.is_cograph() {
+                // The cotree represents the whole graph.
+                Some(t) => {
+                    assert_false!(has_p4);
+                    assert_eq!(t.leaves().len(), 6);
+                    assert_eq!(cotree_edges(&t), edges.iter().copied().collect());
+                }
+                None => assert_true!(has_p4),
+            }
+        }
+
+        // Test a threshold graph, where vertices are alternately added as isolated or dominating,
+        // hence its cotree is as deep as its order.
+        let n = 300;
+        let edges: Vec<_> = (1..n).step_by(2).flat_map(|y| (0..y).map(move |x| (x, y))).collect();
+        let g = T::new(0..n, edges.iter().copied());
+        let t = g.is_cograph().unwrap();
+        assert_eq!(t.leaves().len(), n as usize);
+        assert_eq!(cotree_edges(&t), edges.iter().copied().collect());
+
+        Ok(())
+    }
+
     #[instantiate_tests(<UndirectedAdjacencyListGraph<i32>>)]
     mod adjacency_list_graph {}
 }
@@ -22,9 +22,66 @@ pub trait Connectivity: Storage {
     ///
     fn is_connected(&self) -> bool;
 
+    /// Checks global strong connectivity.
+    ///
+    /// Checks whether the graph is strongly connected, that means testing whether
+    /// there is a directed path between any ordered pair of vertices.
+    ///
+    /// For undirected graph this procedure is equivalent to check if the graph is connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a directed path.
+    /// let mut g = DiGraph::from_edges([(0, 1), (1, 2)]);
+    ///
+    /// // A directed path is not strongly connected.
+    /// assert!(!g.is_strongly_connected());
+    ///
+    /// // Close the path into a cycle.
+    /// g.add_edge(&2, &0).unwrap();
+    ///
+    /// // A directed cycle is strongly connected.
+    /// assert!(g.is_strongly_connected());
+    /// ```
+    ///
+    fn is_strongly_connected(&self) -> bool;
+
     /// Checks global acyclicity.
     ///
     /// Checks whether the graph contains at least one cycle.
     ///
+    /// For undirected graph this procedure is equivalent to check if the graph
+    /// is a *forest*, where self-edges are cycles of length one.
+    ///
     fn is_acyclic(&self) -> bool;
+
+    /// Finds a cycle.
+    ///
+    /// Returns a cycle as a sequence of vertices, starting and ending with the same vertex,
+    /// if the graph contains at least one, i.e. a witness that the graph is not acyclic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build an undirected tree.
+    /// let mut g = Graph::from_edges([(0, 1), (0, 2), (2, 3)]);
+    ///
+    /// // A tree contains no cycle.
+    /// assert!(g.is_acyclic());
+    /// assert_eq!(g.find_cycle(), None);
+    ///
+    /// // Close a cycle.
+    /// g.add_edge(&1, &3).unwrap();
+    ///
+    /// // The cycle is returned as a witness.
+    /// assert!(!g.is_acyclic());
+    /// assert_eq!(g.find_cycle(), Some(vec![&0, &2, &3, &1, &0]));
+    /// ```
+    ///
+    fn find_cycle(&self) -> Option<Vec<&Self::Vertex>>;
 }
@@ -90,6 +90,40 @@ pub trait Directed: Capacity + Connectivity + Convert + Extend + Operators + Sto
     ///
     fn add_directed_edge(&mut self, x: &Self::Vertex, y: &Self::Vertex) -> Result<(), Error<Self::Vertex>>;
 
+    /// Weakly connected components.
+    ///
+    /// Returns the weakly connected components of the graph, sorted by their lowest vertex,
+    /// i.e. the connected components of the graph obtained by ignoring the direction of the edges.
+    ///
+    /// See [`ConnectedComponents`](crate::algorithms::ConnectedComponents) for the per-vertex component label map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a directed graph.
+    /// let g = DiGraph::from_edges([(1, 0), (2, 3), (1, 4)]);
+    ///
+    /// // Get the weakly connected components.
+    /// assert_eq!(
+    ///     g.weakly_connected_components(),
+    ///     [BTreeSet::from([0, 1, 4]), BTreeSet::from([2, 3])]
+    /// );
+    /// ```
+    ///
+    fn weakly_connected_components(&self) -> Vec<BTreeSet<Self::Vertex>> {
+        // Compute the weakly connected components ...
+        crate::algorithms::ConnectedComponents::new(self)
+            .run()
+            .components
+            .iter()
+            // ... and clone their vertices.
+            .map(|c| c.iter().map(|&x| x.clone()).collect())
+            .collect()
+    }
+
     /// In-degree of a given vertex.
     ///
     /// Computes the in-degree of a given vertex, i.e. $|Pa(G, X)|$.
@@ -200,14 +234,77 @@ macro_rules! impl_directed {
             }
 
             fn is_connected(&self) -> bool {
-                // Check if search object reaches any vertex in the graph.
-                $crate::algorithms::BFS::from(self).count() == self.order()
+                // Check if there is at most one weakly connected component.
+                $crate::algorithms::ConnectedComponents::from(self).run().components.len() <= 1
+            }
+
+            fn is_strongly_connected(&self) -> bool {
+                // Check if there is at most one strongly connected component.
+                $crate::algorithms::SCC::from(self).run().components.len() <= 1
             }
 
             fn is_acyclic(&self) -> bool {
                 // If a topological order exists, then the graph is acyclic.
                 $crate::algorithms::TopologicalSort::from(self).all(|x| x.is_ok())
             }
+
+            fn find_cycle(&self) -> Option<Vec<&Self::Vertex>> {
+                // Initialize the predecessor map.
+                let mut predecessor = std::collections::HashMap::new();
+                // Initialize the visit map, where `false` is GRAY and `true` is BLACK.
+                let mut visited = std::collections::HashMap::new();
+                // For each vertex in the graph ...
+                for x in self.vertices_iter() {
+                    // ... that was not visited before (as WHITE) ...
+                    if visited.contains_key(x) {
+                        continue;
+                    }
+                    // ... set it as GRAY ...
+                    visited.insert(x, false);
+                    // ... and visit its descendants in depth-first order.
+                    let mut stack = vec![(x, self.children_iter(x))];
+                    while let Some((x, iter)) = stack.last_mut() {
+                        // Get the current vertex.
+                        let x = *x;
+                        // Get the next child of the current vertex.
+                        match iter.next() {
+                            // If the child is GRAY, then a back edge has been found.
+                            Some(y) if visited.get(y) == Some(&false) => {
+                                // Initialize the cycle with the current vertex.
+                                let mut cycle = vec![x];
+                                // Follow the predecessors until the child is reached.
+                                while cycle[cycle.len() - 1] != y {
+                                    cycle.push(predecessor[cycle[cycle.len() - 1]]);
+                                }
+                                // Reverse the cycle to follow the edges direction.
+                                cycle.reverse();
+                                // Close the cycle.
+                                cycle.push(y);
+
+                                return Some(cycle);
+                            }
+                            // If the child is WHITE, then visit it.
+                            Some(y) if !visited.contains_key(y) => {
+                                // Set it as GRAY.
+                                visited.insert(y, false);
+                                // Set its predecessor.
+                                predecessor.insert(y, x);
+                                // Push it onto the stack.
+                                stack.push((y, self.children_iter(y)));
+                            }
+                            // If the child is BLACK, then skip it.
+                            Some(_) => {}
+                            // If there are no more children, set the current vertex as BLACK.
+                            None => {
+                                visited.insert(x, true);
+                                stack.pop();
+                            }
+                        }
+                    }
+                }
+
+                None
+            }
         }
 
         impl<T, U> $crate::traits::Convert for $graph<T, U>
@@ -1,6 +1,8 @@
 use crate::traits::{Capacity, Connectivity, Convert, Extend, Operators, Storage};
-use crate::types::Error;
 use crate::types::VertexIterator;
+use crate::types::{Cotree, Error};
+use crate::utils::DisjointSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 /// Undirected graph trait.
 pub trait Undirected: Capacity + Connectivity + Convert + Extend + Operators + Storage {
@@ -24,6 +26,504 @@ pub trait Undirected: Capacity + Connectivity + Convert + Extend + Operators + S
     /// or the undirected edge identifier already exists in the graph.
     ///
     fn add_undirected_edge(&mut self, x: &Self::Vertex, y: &Self::Vertex) -> Result<(), Error<Self::Vertex>>;
+
+    /// Connected components.
+    ///
+    /// Returns the connected components of the graph, sorted by their lowest vertex.
+    ///
+    /// See [`ConnectedComponents`](crate::algorithms::ConnectedComponents) for the per-vertex component label map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build an undirected graph.
+    /// let g = Graph::from_edges([(0, 1), (2, 3), (1, 4)]);
+    ///
+    /// // Get the connected components.
+    /// assert_eq!(
+    ///     g.connected_components(),
+    ///     [BTreeSet::from([0, 1, 4]), BTreeSet::from([2, 3])]
+    /// );
+    /// ```
+    ///
+    fn connected_components(&self) -> Vec<BTreeSet<Self::Vertex>> {
+        // Compute the connected components ...
+        crate::algorithms::ConnectedComponents::new(self)
+            .run()
+            .components
+            .iter()
+            // ... and clone their vertices.
+            .map(|c| c.iter().map(|&x| x.clone()).collect())
+            .collect()
+    }
+
+    /// Checks bipartiteness.
+    ///
+    /// Checks whether the graph is bipartite, i.e. its vertices can be colored with two colors
+    /// such that no edge joins two vertices of the same color.
+    ///
+    /// Returns the 2-coloring, with colors `0` and `1`, if the graph is bipartite.
+    /// Otherwise, returns an odd cycle as a witness, starting and ending with the same vertex,
+    /// where self-edges are cycles of length one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use grathe::prelude::*;
+    ///
+    /// // Build an undirected path.
+    /// let mut g = Graph::from_edges([(0, 1), (1, 2)]);
+    ///
+    /// // A path is bipartite.
+    /// assert_eq!(
+    ///     g.is_bipartite(),
+    ///     Ok(HashMap::from([(&0, 0), (&1, 1), (&2, 0)]))
+    /// );
+    ///
+    /// // Close the path into a triangle.
+    /// g.add_edge(&2, &0).unwrap();
+    ///
+    /// // A triangle is an odd cycle.
+    /// assert_eq!(g.is_bipartite(), Err(vec![&0, &1, &2, &0]));
+    /// ```
+    ///
+    fn is_bipartite(&self) -> Result<HashMap<&Self::Vertex, usize>, Vec<&Self::Vertex>> {
+        // Initialize the color map.
+        let mut color = HashMap::with_capacity(self.order());
+        // Initialize the predecessor map.
+        let mut predecessor = HashMap::new();
+        // For each vertex in the graph ...
+        for x in self.vertices_iter() {
+            // ... that was not colored before ...
+            if color.contains_key(x) {
+                continue;
+            }
+            // ... color its component in breadth-first order.
+            color.insert(x, 0);
+            let mut queue = VecDeque::from([x]);
+            while let Some(y) = queue.pop_front() {
+                for z in self.neighbors_iter(y) {
+                    match color.get(z) {
+                        // If the neighbor was not colored before, give it the opposite color.
+                        None => {
+                            color.insert(z, 1 - color[y]);
+                            predecessor.insert(z, y);
+                            queue.push_back(z);
+                        }
+                        // If the neighbor has the same color, then an odd cycle is found.
+                        Some(c) if *c == color[y] => {
+                            // Build the branches from both endpoints to the root of the tree.
+                            let (mut p, mut q) = (vec![y], vec![z]);
+                            while let Some(&w) = predecessor.get(p[p.len() - 1]) {
+                                p.push(w);
+                            }
+                            while let Some(&w) = predecessor.get(q[q.len() - 1]) {
+                                q.push(w);
+                            }
+                            // Remove the common ancestors, except the lowest one.
+                            while p.len() > 1 && q.len() > 1 && p[p.len() - 2] == q[q.len() - 2] {
+                                p.pop();
+                                q.pop();
+                            }
+                            // Join the branches through the lowest common ancestor.
+                            q.pop();
+                            p.reverse();
+                            p.extend(q);
+                            // Close the cycle.
+                            p.push(p[0]);
+
+                            return Err(p);
+                        }
+                        // Otherwise, the edge is consistent with the coloring.
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        Ok(color)
+    }
+
+    /// Perfect elimination ordering.
+    ///
+    /// Returns a perfect elimination ordering of the graph, if any, i.e. an ordering of the vertices
+    /// such that each vertex and its neighbors that come later in the ordering form a clique.
+    ///
+    /// The candidate ordering is the reverse of a [`LexBFS`](crate::algorithms::LexBFS) ordering,
+    /// which is then verified following Rose, Tarjan and Lueker[^1].
+    ///
+    /// [^1]: [Rose, D. J., Tarjan, R. E., & Lueker, G. S. (1976). Algorithmic aspects of vertex elimination on graphs. SIAM Journal on Computing, 5(2), 266-283.](https://scholar.google.com/scholar?q=Algorithmic+aspects+of+vertex+elimination+on+graphs)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a triangle with a pendant vertex.
+    /// let g = Graph::from_edges([(0, 1), (1, 2), (2, 0), (2, 3)]);
+    ///
+    /// // Eliminating the vertices in the given order never requires a fill-in edge.
+    /// assert_eq!(g.perfect_elimination_ordering(), Some(vec![&3, &2, &1, &0]));
+    ///
+    /// // Build a square.
+    /// let g = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+    ///
+    /// // A chordless cycle has no perfect elimination ordering.
+    /// assert_eq!(g.perfect_elimination_ordering(), None);
+    /// ```
+    ///
+    fn perfect_elimination_ordering(&self) -> Option<Vec<&Self::Vertex>> {
+        // Compute the reverse of a LexBFS ordering.
+        let mut order: Vec<_> = crate::algorithms::LexBFS::new(self, None).collect();
+        order.reverse();
+        // Map each vertex to its position in the ordering.
+        let position: HashMap<_, _> = order.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // For each vertex in the ordering ...
+        for (i, &x) in order.iter().enumerate() {
+            // ... get its neighbors that come later in the ordering, skipping self-edges.
+            let later: Vec<_> = self.neighbors_iter(x).filter(|y| position[y] > i).collect();
+            // Get the earliest of them, if any.
+            let y = match later.iter().min_by_key(|y| position[*y]) {
+                Some(&y) => y,
+                None => continue,
+            };
+            // The remaining ones must be adjacent to it.
+            let adjacents: HashSet<_> = self.neighbors_iter(y).collect();
+            if later.iter().any(|z| *z != y && !adjacents.contains(z)) {
+                return None;
+            }
+        }
+
+        Some(order)
+    }
+
+    /// Checks chordality.
+    ///
+    /// Checks whether the graph is chordal, i.e. every cycle of length at least four has a chord,
+    /// or, equivalently, whether it has a [perfect elimination ordering](Undirected::perfect_elimination_ordering).
+    ///
+    /// See [`MinimalTriangulation`](crate::algorithms::MinimalTriangulation) to make a graph chordal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a square.
+    /// let mut g = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+    ///
+    /// // A chordless cycle is not chordal.
+    /// assert!(!g.is_chordal());
+    ///
+    /// // Add a chord.
+    /// g.add_edge(&0, &2).unwrap();
+    ///
+    /// // Now the cycle has a chord.
+    /// assert!(g.is_chordal());
+    /// ```
+    ///
+    fn is_chordal(&self) -> bool {
+        self.perfect_elimination_ordering().is_some()
+    }
+
+    /// Checks whether the graph is a cograph.
+    ///
+    /// Checks whether the graph is a cograph, i.e. it has no induced path on four vertices or, equivalently,
+    /// every induced subgraph with at least two vertices is either disconnected or has a disconnected complement.
+    ///
+    /// Returns the [`Cotree`] of the graph if it is a cograph, where the children of each node are sorted
+    /// by their lowest vertex. The null graph is a cograph, whose cotree is the empty union.
+    ///
+    /// The recognition follows Bretscher, Corneil, Habib and Paul[^1], with two sweeps: a
+    /// [`LexBFS`](crate::algorithms::LexBFS) ordering of the graph and a LexBFS⁻ ordering, i.e. a LexBFS ordering
+    /// of its complement, where ties are broken in favor of the vertex that comes first in the former. The slice
+    /// of a vertex is the set of vertices sharing its label when it is selected, which splits into the vertex,
+    /// the slice of its neighbors and the slices of its non-neighbors. The graph is a cograph if, in both orderings,
+    /// the neighborhoods of the neighbors within the non-neighbors of each slice are nested, i.e. the neighbourhood
+    /// subset property holds, and the slices of the non-neighbors are pairwise non-adjacent, where adjacency is taken
+    /// in the complement for the LexBFS⁻ ordering. Then, the cotree is built bottom-up from the slices of the LexBFS
+    /// ordering, where the non-neighbors of a vertex are unions, and its neighbors are joins grouped by the number
+    /// of non-neighbors they are adjacent to.
+    ///
+    /// Each edge is checked in the smallest slice containing both its endpoints, hence, besides the sweeps,
+    /// the recognition takes $O((|V| + |E|) \cdot \alpha(|V|))$ time, where $\alpha$ is the inverse Ackermann function.
+    ///
+    /// [^1]: [Bretscher, A., Corneil, D., Habib, M., & Paul, C. (2008). A simple linear time LexBFS cograph recognition algorithm. SIAM Journal on Discrete Mathematics, 22(4), 1277-1296.](https://scholar.google.com/scholar?q=A+simple+linear+time+LexBFS+cograph+recognition+algorithm)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grathe::prelude::*;
+    ///
+    /// // Build a square.
+    /// let mut g = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+    ///
+    /// // A square is the join of two pairs of non-adjacent vertices.
+    /// assert_eq!(
+    ///     g.is_cograph(),
+    ///     Some(Cotree::Join(vec![
+    ///         Cotree::Union(vec![Cotree::Leaf(&0), Cotree::Leaf(&2)]),
+    ///         Cotree::Union(vec![Cotree::Leaf(&1), Cotree::Leaf(&3)]),
+    ///     ]))
+    /// );
+    ///
+    /// // Remove an edge to obtain a path on four vertices.
+    /// g.del_edge(&3, &0).unwrap();
+    ///
+    /// // A path on four vertices is not a cograph.
+    /// assert_eq!(g.is_cograph(), None);
+    ///
+    /// // The null graph is the empty union.
+    /// assert_eq!(Graph::null().is_cograph(), Some(Cotree::Union(vec![])));
+    /// ```
+    ///
+    fn is_cograph(&self) -> Option<Cotree<&Self::Vertex>> {
+        // Get the vertex set.
+        let vertices: Vec<_> = self.vertices_iter().collect();
+        let n = vertices.len();
+        // The null graph is the empty union.
+        if n == 0 {
+            return Some(Cotree::Union(vec![]));
+        }
+        // Map each vertex to its index.
+        let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+        // Compute the sorted neighbors of each vertex, skipping self-edges.
+        let neighbors: Vec<Vec<usize>> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| self.neighbors_iter(x).map(|y| index[y]).filter(|&j| j != i).collect())
+            .collect();
+        // Compute the LexBFS ordering, together with the size of the slice of each vertex,
+        // i.e. of the partition it is selected from.
+        let (mut order, mut size) = (Vec::with_capacity(n), vec![0; n]);
+        let mut search = crate::algorithms::LexBFS::new(self, None);
+        while let Some(k) = search.partitions.front().map(VecDeque::len) {
+            let x = index[search.next().unwrap()];
+            size[x] = k;
+            order.push(x);
+        }
+        // Compute the LexBFS⁻ ordering, where each partition is split into non-neighbors followed by neighbors,
+        // starting from a single partition sorted by the LexBFS ordering to break ties.
+        let (mut order_c, mut size_c) = (Vec::with_capacity(n), vec![0; n]);
+        let (mut visited, mut adjacent) = (vec![false; n], vec![usize::MAX; n]);
+        let mut partitions = VecDeque::from([order.iter().copied().collect::<VecDeque<_>>()]);
+        while let Some(p) = partitions.front_mut() {
+            // Select the first vertex from the first partition.
+            let k = p.len();
+            let x = p.pop_front().unwrap();
+            if p.is_empty() {
+                partitions.pop_front();
+            }
+            visited[x] = true;
+            size_c[x] = k;
+            order_c.push(x);
+            // Mark the neighbors still in a partition.
+            let mut count = 0;
+            for &y in neighbors[x].iter().filter(|&&y| !visited[y]) {
+                adjacent[y] = x;
+                count += 1;
+            }
+            // Split the partitions until every neighbor has been moved after its non-neighbors.
+            let mut iter = std::mem::take(&mut partitions).into_iter();
+            while count > 0 {
+                let (p0, p1): (VecDeque<_>, VecDeque<_>) =
+                    iter.next().unwrap().into_iter().partition(|&y| adjacent[y] != x);
+                count -= p1.len();
+                partitions.extend([p0, p1].into_iter().filter(|p| !p.is_empty()));
+            }
+            partitions.extend(iter);
+        }
+        // Check the neighbourhood subset property of an ordering, given the size of the slice of each vertex,
+        // where the complement flag swaps neighbors and non-neighbors.
+        let check = |order: &[usize], size: &[usize], complement: bool| {
+            // Map each vertex to its position, and compute the last position of its slice.
+            let mut position = vec![0; n];
+            order.iter().enumerate().for_each(|(i, &x)| position[x] = i);
+            let last: Vec<_> = (0..n).map(|x| position[x] + size[x] - 1).collect();
+            // Compute the last position of the slice of the neighbors of each vertex, which is the slice
+            // of the following vertex, if it is a neighbor, or its own position otherwise.
+            let split: Vec<_> = (0..n)
+                .map(|x| {
+                    let i = position[x];
+                    match i < last[x] && neighbors[x].binary_search(&order[i + 1]).is_ok() != complement {
+                        true => last[order[i + 1]],
+                        false => i,
+                    }
+                })
+                .collect();
+            // Initialize the slices of the non-neighbors of each vertex.
+            let mut classes = vec![Vec::new(); n];
+            // Initialize the count and the extreme position of the edges from each neighbor to the non-neighbors
+            // of each vertex, and the count of the edges between the slices of the non-neighbors of each vertex.
+            let (mut levels, mut across) = (HashMap::new(), vec![0; n]);
+            // Find the smallest slice containing the endpoints of each edge with Tarjan's offline algorithm,
+            // since the slices form a tree whose preorder is the ordering itself.
+            let (mut sets, mut ancestor, mut stack) = (DisjointSet::new(n), (0..n).collect::<Vec<_>>(), Vec::new());
+            for (i, &y) in order.iter().enumerate() {
+                // Merge the slices ending before the current vertex into their parent, which always exists,
+                // since the first slice contains every vertex.
+                while let Some(&x) = stack.last() {
+                    if last[x] >= i {
+                        break;
+                    }
+                    stack.pop();
+                    let p = *stack.last().unwrap();
+                    sets.union(x, p);
+                    ancestor[sets.find(p)] = p;
+                }
+                // Store the slice of non-neighbors of its parent.
+                if let Some(&p) = stack.last() {
+                    if i > split[p] {
+                        classes[p].push(y);
+                    }
+                }
+                stack.push(y);
+                // For each edge to a previous vertex ...
+                for &z in neighbors[y].iter().filter(|&&z| position[z] < i) {
+                    // ... get the first vertex of the smallest slice containing both endpoints.
+                    let x = ancestor[sets.find(z)];
+                    match (x == z, position[z] <= split[x], complement) {
+                        // The edge is incident to the first vertex.
+                        (true, _, _) => {}
+                        // The edge joins a neighbor to a non-neighbor, keeping the last position,
+                        // or the first one in the complement.
+                        (false, true, false) => {
+                            let l = levels.entry((z, x)).or_insert((0, 0));
+                            *l = (l.0 + 1, l.1.max(i));
+                        }
+                        (false, true, true) => {
+                            let l = levels.entry((z, x)).or_insert((0, n));
+                            *l = (l.0 + 1, l.1.min(i));
+                        }
+                        // The edge joins two slices of non-neighbors.
+                        (false, false, false) => return None,
+                        (false, false, true) => across[x] += 1,
+                    }
+                }
+            }
+            // The neighborhoods are nested if the neighbors of each vertex within the non-neighbors
+            // are the first ones, or the last ones in the complement.
+            let nested = levels.iter().all(|(&(_, x), &(k, j))| match complement {
+                false => j == split[x] + k,
+                true => j + k == last[x] + 1,
+            });
+            // In the complement, the slices of the non-neighbors are pairwise adjacent if the edges
+            // between them are as many as the pairs of their vertices.
+            let separated = !complement
+                || (0..n).all(|x| {
+                    let k = last[x] - split[x];
+                    2 * across[x] + classes[x].iter().map(|&y| size[y] * size[y]).sum::<usize>() == k * k
+                });
+
+            (nested && separated).then_some((position, last, split, classes, levels))
+        };
+        // Check both orderings, keeping the slices of the LexBFS ordering.
+        let (position, last, split, classes, levels) = check(&order, &size, false)?;
+        check(&order_c, &size_c, true)?;
+        // Initialize the nodes of the cotree with the leaves, where each node is given by whether it is a join
+        // and its children, together with any of its leaves.
+        let (mut nodes, mut leaf): (Vec<(bool, Vec<usize>)>, Vec<_>) = (vec![(false, vec![]); n], (0..n).collect());
+        // Build the cotree of each slice, bottom-up.
+        let mut root = vec![0; n];
+        for &x in order.iter().rev() {
+            let i = position[x];
+            // Get the number of non-neighbors in the slice.
+            let k = last[x] - split[x];
+            // Get the level of a node, i.e. the number of non-neighbors it is adjacent to.
+            let level = |t: usize| levels.get(&(leaf[t], x)).map_or(0, |l: &(usize, usize)| l.0);
+            // Group the co-components of the neighbors by level, keeping those at the top level in place,
+            // since the others are moved below a union and will not be moved again.
+            let (mut top, mut groups) = (None, BTreeMap::<_, Vec<_>>::new());
+            if split[x] > i {
+                let t = root[order[i + 1]];
+                match nodes[t].0 {
+                    true => {
+                        if k > 0 {
+                            for c in std::mem::take(&mut nodes[t].1) {
+                                match level(c) {
+                                    l if l == k => nodes[t].1.push(c),
+                                    l => groups.entry(l).or_default().push(c),
+                                }
+                            }
+                        }
+                        if !nodes[t].1.is_empty() {
+                            top = Some(t);
+                        }
+                    }
+                    false => groups.entry(level(t)).or_default().push(t),
+                }
+            }
+            // Starting from the leaf, alternate the joins with the neighbors at each level
+            // and the unions with the slices of the non-neighbors.
+            let (mut node, mut j) = (x, 0);
+            for &y in classes[x].iter() {
+                if let Some(mut c) = groups.remove(&j) {
+                    c.push(node);
+                    leaf.push(leaf[node]);
+                    nodes.push((true, c));
+                    node = nodes.len() - 1;
+                }
+                let t = root[y];
+                match t >= n && !nodes[t].0 {
+                    // Extend the union of the slice.
+                    true => {
+                        nodes[t].1.push(node);
+                        node = t;
+                    }
+                    false => {
+                        leaf.push(leaf[node]);
+                        nodes.push((false, vec![node, t]));
+                        node = nodes.len() - 1;
+                    }
+                }
+                j += size[y];
+            }
+            // Add the neighbors adjacent to every non-neighbor.
+            if let Some(mut c) = groups.remove(&j) {
+                c.push(node);
+                leaf.push(leaf[node]);
+                nodes.push((true, c));
+                node = nodes.len() - 1;
+            }
+            if let Some(t) = top {
+                nodes[t].1.push(node);
+                node = t;
+            }
+            root[x] = node;
+        }
+        // Assemble the cotree from its root, sorting the children of each node by their lowest vertex.
+        let mut lowest: Vec<_> = (0..nodes.len()).collect();
+        let mut trees: Vec<Option<Cotree<&Self::Vertex>>> = Vec::with_capacity(nodes.len());
+        trees.resize_with(nodes.len(), || None);
+        let mut stack = vec![(root[order[0]], false)];
+        while let Some((t, expanded)) = stack.pop() {
+            match (t < n, expanded) {
+                // A leaf stores its vertex.
+                (true, _) => trees[t] = Some(Cotree::Leaf(vertices[t])),
+                // Visit the children before the node.
+                (false, false) => {
+                    stack.push((t, true));
+                    stack.extend(nodes[t].1.iter().map(|&c| (c, false)));
+                }
+                (false, true) => {
+                    let (join, mut children) = std::mem::take(&mut nodes[t]);
+                    children.sort_unstable_by_key(|&c| lowest[c]);
+                    lowest[t] = lowest[children[0]];
+                    let children = children.into_iter().map(|c| trees[c].take().unwrap()).collect();
+                    trees[t] = Some(match join {
+                        true => Cotree::Join(children),
+                        false => Cotree::Union(children),
+                    });
+                }
+            }
+        }
+
+        trees[root[order[0]]].take()
+    }
 }
 
 /// Neighborhood iterator.
@@ -136,9 +636,75 @@ macro_rules! impl_undirected {
                 $crate::algorithms::BFS::from(self).count() == self.order()
             }
 
+            fn is_strongly_connected(&self) -> bool {
+                // Strong connectivity is equivalent to connectivity for undirected graphs.
+                self.is_connected()
+            }
+
             fn is_acyclic(&self) -> bool {
-                // TODO: Find DFS edge such that Back(_, _).
-                todo!()
+                // If no cycle exists, then the graph is acyclic.
+                self.find_cycle().is_none()
+            }
+
+            fn find_cycle(&self) -> Option<Vec<&Self::Vertex>> {
+                // Initialize the predecessor map.
+                let mut predecessor = std::collections::HashMap::new();
+                // Initialize the visited set.
+                let mut visited = std::collections::HashSet::new();
+                // For each vertex in the graph ...
+                for x in self.vertices_iter() {
+                    // ... that was not visited before ...
+                    if !visited.insert(x) {
+                        continue;
+                    }
+                    // ... visit its connected component.
+                    let mut stack = vec![x];
+                    while let Some(x) = stack.pop() {
+                        // Iterate over the neighbors of the popped vertex.
+                        for y in self.neighbors_iter(x) {
+                            // If the neighbor is the vertex itself, then a self-edge has been found.
+                            if x == y {
+                                return Some(vec![x, x]);
+                            }
+                            // If the neighbor was never seen before ...
+                            if visited.insert(y) {
+                                // ... set its predecessor ...
+                                predecessor.insert(y, x);
+                                // ... and push it onto the stack.
+                                stack.push(y);
+                            // Otherwise, if the neighbor is not the predecessor of the popped vertex,
+                            // then the edge is not in the spanning tree and closes a cycle.
+                            } else if predecessor.get(x) != Some(&y) {
+                                // Get the path from the popped vertex to the root.
+                                let mut a = vec![x];
+                                while let Some(&z) = predecessor.get(a[a.len() - 1]) {
+                                    a.push(z);
+                                }
+                                // Get the path from the neighbor to the lowest common ancestor.
+                                let mut b = vec![y];
+                                while !a.contains(&b[b.len() - 1]) {
+                                    b.push(predecessor[b[b.len() - 1]]);
+                                }
+                                // Get the lowest common ancestor.
+                                let z = b.pop().unwrap();
+                                // Truncate the path to the lowest common ancestor.
+                                a.truncate(a.iter().position(|&w| w == z).unwrap());
+                                // Initialize the cycle from the lowest common ancestor ...
+                                let mut cycle = vec![z];
+                                // ... down to the popped vertex ...
+                                cycle.extend(a.into_iter().rev());
+                                // ... then up from the neighbor ...
+                                cycle.extend(b);
+                                // ... back to the lowest common ancestor.
+                                cycle.push(z);
+
+                                return Some(cycle);
+                            }
+                        }
+                    }
+                }
+
+                None
             }
         }
 
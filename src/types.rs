@@ -76,6 +76,34 @@ pub type EdgeList<T> = BTreeSet<(T, T)>;
 /// Adjacency list type.
 pub type AdjacencyList<T> = BTreeMap<T, BTreeSet<T>>;
 
+/// Cotree type.
+///
+/// A cotree represents a cograph as a rooted tree, where each leaf is a vertex and each internal node
+/// is either the disjoint union or the join of the cographs represented by its children.
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Cotree<T> {
+    /// Single vertex.
+    Leaf(T),
+    /// Disjoint union of the children, i.e. no edge joins vertices of different children.
+    Union(Vec<Cotree<T>>),
+    /// Join of the children, i.e. every vertex is adjacent to the vertices of the other children.
+    Join(Vec<Cotree<T>>),
+}
+
+impl<T> Cotree<T> {
+    /// Leaves of the cotree.
+    ///
+    /// Returns the vertices of the cotree, in depth-first order.
+    ///
+    pub fn leaves(&self) -> Vec<&T> {
+        match self {
+            Cotree::Leaf(x) => vec![x],
+            Cotree::Union(c) | Cotree::Join(c) => c.iter().flat_map(|c| c.leaves()).collect(),
+        }
+    }
+}
+
 /// Error enumerator.
 #[derive(Error, PartialEq, Debug)]
 pub enum Error<T> {
@@ -100,6 +128,21 @@ pub enum Error<T> {
     /// Edge attribute not defined error type.
     #[error("edge `({0:?}, {1:?})` has no attribute defined")]
     EdgeAttributesNotDefined(T, T),
+    /// Negative cycle error type.
+    #[error("negative cycle `{0:?}` found")]
+    NegativeCycle(Vec<T>),
+    /// Infeasible flow error type.
+    #[error("infeasible flow, `{0}` units of supply cannot be routed")]
+    InfeasibleFlow(f64),
+    /// Not bipartite graph error type.
+    #[error("graph is not bipartite, odd cycle `{0:?}` found")]
+    NotBipartite(Vec<T>),
+    /// Not chordal graph error type.
+    #[error("graph is not chordal")]
+    NotChordal(),
+    /// Not converged iterative method error type.
+    #[error("iterative method failed to converge within `{0}` iterations")]
+    NotConverged(usize),
     /// Parsing error type.
     #[error("failed to parse graph")]
     ParseFailed(String),
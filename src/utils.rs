@@ -14,3 +14,96 @@ macro_rules! partial_cmp_sets {
 }
 
 pub(crate) use partial_cmp_sets;
+
+/// Min-priority queue entry.
+///
+/// Reverses the (partial) ordering of the priority, so that a [`BinaryHeap`](std::collections::BinaryHeap)
+/// pops the entry with the lowest priority first. Incomparable priorities (e.g. `NaN`) are considered equal.
+///
+#[derive(Debug)]
+pub(crate) struct MinPriority<P, T>(pub P, pub T);
+
+impl<P, T> PartialEq for MinPriority<P, T>
+where
+    P: PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl<P, T> Eq for MinPriority<P, T> where P: PartialOrd {}
+
+impl<P, T> PartialOrd for MinPriority<P, T>
+where
+    P: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P, T> Ord for MinPriority<P, T>
+where
+    P: PartialOrd,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Reverse the ordering to obtain a min-heap.
+        other.0.partial_cmp(&self.0).unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
+
+/// Disjoint-set forest.
+///
+/// Partitions the indices in $[0, n)$ into disjoint sets, using both path compression and union by size.
+///
+#[derive(Debug)]
+pub(crate) struct DisjointSet {
+    /// Parent of each index.
+    parent: Vec<usize>,
+    /// Size of the set rooted at each index.
+    size: Vec<usize>,
+}
+
+impl DisjointSet {
+    /// Builds a disjoint-set forest of singletons.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    /// Finds the root of the set containing a given index.
+    pub fn find(&mut self, x: usize) -> usize {
+        // Find the root of the set.
+        let mut y = x;
+        while self.parent[y] != y {
+            y = self.parent[y];
+        }
+        // Compress the path to the root.
+        let mut x = x;
+        while self.parent[x] != y {
+            x = std::mem::replace(&mut self.parent[x], y);
+        }
+
+        y
+    }
+
+    /// Merges the sets containing two given indices, returning whether they were disjoint.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find(x), self.find(y));
+        // Check if the sets are already merged.
+        if x == y {
+            return false;
+        }
+        // Attach the smaller set to the larger one.
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+
+        true
+    }
+}